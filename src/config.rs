@@ -0,0 +1,764 @@
+use crate::error::{AppError, AppResult};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+/// Default location of the configuration file, next to the database.
+pub const CONFIG_PATH: &str = "data/config.txt";
+
+/// Location of the auto-saved edit form draft.
+pub const DRAFT_PATH: &str = "data/draft.txt";
+
+/// Where the "Export CSV" command writes the job list.
+pub const CSV_EXPORT_PATH: &str = "data/jobs.csv";
+
+/// Number of rows a bulk delete may remove without asking for confirmation.
+pub const DEFAULT_DELETE_CONFIRM_THRESHOLD: usize = 5;
+
+/// Window size and position persisted between sessions.
+///
+/// Sizes and coordinates are in logical pixels, as reported by iced.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowGeometry {
+    pub width: f32,
+    pub height: f32,
+    pub position: Option<(f32, f32)>,
+}
+
+impl Default for WindowGeometry {
+    fn default() -> Self {
+        Self {
+            width: 1024.0,
+            height: 768.0,
+            position: None,
+        }
+    }
+}
+
+impl WindowGeometry {
+    /// Clamps a saved window position so the window lies on the given screen.
+    ///
+    /// Positions left of or above the screen are moved to the edge, and
+    /// positions that would push the window past the right or bottom edge are
+    /// pulled back. A window larger than the screen is anchored at the origin.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The saved top-left corner of the window
+    /// * `window` - The window's size as `(width, height)`
+    /// * `screen` - The screen's size as `(width, height)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::config::WindowGeometry;
+    /// let clamped = WindowGeometry::clamp_position((5000.0, -40.0), (800.0, 600.0), (1920.0, 1080.0));
+    /// assert_eq!(clamped, (1120.0, 0.0));
+    /// ```
+    #[must_use]
+    pub fn clamp_position(
+        position: (f32, f32),
+        window: (f32, f32),
+        screen: (f32, f32),
+    ) -> (f32, f32) {
+        let max_x = (screen.0 - window.0).max(0.0);
+        let max_y = (screen.1 - window.1).max(0.0);
+        (position.0.clamp(0.0, max_x), position.1.clamp(0.0, max_y))
+    }
+}
+
+/// Accent color used for selected rows and primary buttons.
+///
+/// Stored in the config file as a `#rrggbb` hex triple.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccentColor {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Default for AccentColor {
+    /// The blue of the built-in iced palettes.
+    fn default() -> Self {
+        Self::new(0x5E, 0x7C, 0xE2)
+    }
+}
+
+impl AccentColor {
+    /// Creates an accent color from its red, green and blue components.
+    #[must_use]
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+}
+
+impl fmt::Display for AccentColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+impl std::str::FromStr for AccentColor {
+    type Err = AppError;
+
+    /// Parses a `#rrggbb` hex triple; the leading `#` is optional.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::config::AccentColor;
+    /// assert_eq!("#ff8000".parse::<AccentColor>().unwrap(), AccentColor::new(255, 128, 0));
+    /// assert!("orange".parse::<AccentColor>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.strip_prefix('#').unwrap_or(s);
+        let component = |range: std::ops::Range<usize>| {
+            hex.get(range)
+                .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+        };
+        match (hex.len(), component(0..2), component(2..4), component(4..6)) {
+            (6, Some(r), Some(g), Some(b)) => Ok(Self::new(r, g, b)),
+            _ => Err(AppError::Configuration(format!(
+                "invalid color `{s}`, expected `#rrggbb`"
+            ))),
+        }
+    }
+}
+
+/// Locale used to format and parse salaries and other amounts.
+///
+/// Only the digit grouping and decimal mark differ between locales.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NumberLocale {
+    /// `1,000.5`
+    #[default]
+    EnUs,
+    /// `1.000,5`
+    DeDe,
+    /// `1 000,5`
+    FrFr,
+}
+
+impl NumberLocale {
+    /// Every supported locale, in the order offered to the user.
+    pub const ALL: [Self; 3] = [Self::EnUs, Self::DeDe, Self::FrFr];
+
+    /// Returns the BCP 47 tag stored in the config file, e.g. `en-US`.
+    #[must_use]
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::EnUs => "en-US",
+            Self::DeDe => "de-DE",
+            Self::FrFr => "fr-FR",
+        }
+    }
+
+    /// Returns the character placed between groups of three digits.
+    #[must_use]
+    pub const fn thousands_separator(self) -> char {
+        match self {
+            Self::EnUs => ',',
+            Self::DeDe => '.',
+            Self::FrFr => ' ',
+        }
+    }
+
+    /// Returns the character separating the integer and fractional parts.
+    #[must_use]
+    pub const fn decimal_mark(self) -> char {
+        match self {
+            Self::EnUs => '.',
+            Self::DeDe | Self::FrFr => ',',
+        }
+    }
+
+    /// Formats a whole number with thousands separators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::config::NumberLocale;
+    /// assert_eq!(NumberLocale::EnUs.format_integer(1_234_567), "1,234,567");
+    /// assert_eq!(NumberLocale::DeDe.format_integer(-1_000), "-1.000");
+    /// ```
+    #[must_use]
+    pub fn format_integer(self, value: i64) -> String {
+        let digits = value.unsigned_abs().to_string();
+        let mut formatted = String::with_capacity(digits.len() + digits.len() / 3 + 1);
+        if value < 0 {
+            formatted.push('-');
+        }
+        for (index, digit) in digits.chars().enumerate() {
+            if index > 0 && (digits.len() - index).is_multiple_of(3) {
+                formatted.push(self.thousands_separator());
+            }
+            formatted.push(digit);
+        }
+        formatted
+    }
+
+    /// Formats a number with thousands separators and `decimals` fractional
+    /// digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::config::NumberLocale;
+    /// assert_eq!(NumberLocale::DeDe.format_decimal(1234.5, 2), "1.234,50");
+    /// ```
+    #[must_use]
+    pub fn format_decimal(self, value: f64, decimals: usize) -> String {
+        let fixed = format!("{:.*}", decimals, value.abs());
+        let (integer, fraction) = fixed.split_once('.').unwrap_or((&fixed, ""));
+        let mut formatted = if value < 0.0 && fixed.bytes().any(|b| b.is_ascii_digit() && b != b'0')
+        {
+            "-".to_string()
+        } else {
+            String::new()
+        };
+        formatted.push_str(&self.format_integer(integer.parse().unwrap_or(0)));
+        if !fraction.is_empty() {
+            formatted.push(self.decimal_mark());
+            formatted.push_str(fraction);
+        }
+        formatted
+    }
+
+    /// Parses a whole number, accepting this locale's thousands separators.
+    ///
+    /// Surrounding whitespace is ignored. In `fr-FR` any space, including a
+    /// non-breaking one, counts as a separator.
+    ///
+    /// # Errors
+    ///
+    /// Returns the target type's parse error if what remains after removing
+    /// separators is not a valid number, e.g. when another locale's decimal
+    /// mark is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::config::NumberLocale;
+    /// assert_eq!(NumberLocale::DeDe.parse_integer::<u32>("80.000"), Ok(80_000));
+    /// assert!(NumberLocale::EnUs.parse_integer::<u32>("80.000").is_err());
+    /// ```
+    pub fn parse_integer<T: std::str::FromStr>(self, input: &str) -> Result<T, T::Err> {
+        let separator = self.thousands_separator();
+        let digits: String = input
+            .trim()
+            .chars()
+            .filter(|&c| c != separator && !(separator == ' ' && c.is_whitespace()))
+            .collect();
+        digits.parse()
+    }
+}
+
+impl fmt::Display for NumberLocale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl std::str::FromStr for NumberLocale {
+    type Err = AppError;
+
+    /// Parses a locale tag such as `de-DE`, ignoring case and accepting `_`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tag = s.replace('_', "-");
+        Self::ALL
+            .into_iter()
+            .find(|locale| locale.code().eq_ignore_ascii_case(&tag))
+            .ok_or_else(|| AppError::Configuration(format!("unsupported number locale `{s}`")))
+    }
+}
+
+/// Exchange rates used to convert amounts into a single base currency.
+///
+/// Each rate is the value of one unit of the currency in the base currency.
+/// Currency codes are compared case-insensitively and stored upper-case.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurrencyRates {
+    base: String,
+    rates: BTreeMap<String, f64>,
+}
+
+impl Default for CurrencyRates {
+    fn default() -> Self {
+        Self::new("USD")
+    }
+}
+
+impl CurrencyRates {
+    /// Creates an empty rate table for the given base currency.
+    #[must_use]
+    pub fn new(base: &str) -> Self {
+        Self {
+            base: base.to_uppercase(),
+            rates: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the base currency code.
+    #[must_use]
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    /// Sets the rate for `currency`, replacing any previous rate.
+    #[must_use]
+    pub fn with_rate(mut self, currency: &str, rate: f64) -> Self {
+        self.rates.insert(currency.to_uppercase(), rate);
+        self
+    }
+
+    /// Converts `amount` in `currency` into the base currency.
+    ///
+    /// Amounts already in the base currency are returned unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AppError::Configuration` if no rate is configured for
+    /// `currency`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::config::CurrencyRates;
+    /// let rates = CurrencyRates::new("EUR").with_rate("USD", 0.5);
+    /// assert_eq!(rates.convert(100.0, "usd").unwrap(), 50.0);
+    /// assert!(rates.convert(100.0, "GBP").is_err());
+    /// ```
+    pub fn convert(&self, amount: f64, currency: &str) -> AppResult<f64> {
+        let currency = currency.to_uppercase();
+        if currency == self.base {
+            return Ok(amount);
+        }
+        self.rates
+            .get(&currency)
+            .map(|rate| amount * rate)
+            .ok_or_else(|| {
+                AppError::Configuration(format!(
+                    "no exchange rate from {currency} to {} is configured",
+                    self.base
+                ))
+            })
+    }
+
+    /// Converts every `(amount, currency)` pair into the base currency, so
+    /// that summary statistics can be computed over a single unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AppError::Configuration` for the first currency without a
+    /// configured rate.
+    pub fn convert_all(&self, amounts: &[(f64, &str)]) -> AppResult<Vec<f64>> {
+        amounts
+            .iter()
+            .map(|(amount, currency)| self.convert(*amount, currency))
+            .collect()
+    }
+}
+
+/// Application configuration persisted to a plain-text file.
+///
+/// The file holds one `key = value` pair per line. Blank lines, lines starting
+/// with `#`, and unknown keys are ignored so older and newer versions of the
+/// application can share a file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub window: WindowGeometry,
+    pub currency: CurrencyRates,
+    /// Whether the in-progress edit form is saved to `DRAFT_PATH` and
+    /// offered for restore on the next start.
+    pub autosave_draft: bool,
+    pub accent: AccentColor,
+    /// Locale used to format and parse salaries and amounts.
+    pub number_locale: NumberLocale,
+    /// Bulk deletes affecting more rows than this ask for confirmation first.
+    pub delete_confirm_threshold: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            window: WindowGeometry::default(),
+            currency: CurrencyRates::default(),
+            autosave_draft: true,
+            accent: AccentColor::default(),
+            number_locale: NumberLocale::default(),
+            delete_confirm_threshold: DEFAULT_DELETE_CONFIRM_THRESHOLD,
+        }
+    }
+}
+
+impl Config {
+    /// Returns whether deleting `count` rows at once needs confirmation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::config::Config;
+    /// let config = Config::default();
+    /// assert!(!config.requires_delete_confirmation(5));
+    /// assert!(config.requires_delete_confirmation(6));
+    /// ```
+    #[must_use]
+    pub const fn requires_delete_confirmation(&self, count: usize) -> bool {
+        count > self.delete_confirm_threshold
+    }
+
+    /// Loads the configuration from `path`.
+    ///
+    /// A missing file is not an error and yields the default configuration.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The file exists but cannot be read (`AppError::FileSystem`)
+    /// - A line is malformed or holds an invalid value (`AppError::Configuration`)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::config::{Config, CONFIG_PATH};
+    /// let config = Config::load(CONFIG_PATH).unwrap_or_default();
+    /// ```
+    pub fn load(path: impl AsRef<Path>) -> AppResult<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Saves the configuration to `path`, creating parent directories as needed.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the directory or file cannot be
+    /// written (`AppError::FileSystem`).
+    pub fn save(&self, path: impl AsRef<Path>) -> AppResult<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, self.to_string())?;
+        Ok(())
+    }
+
+    /// Parses configuration file contents.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AppError::Configuration` naming the offending line if a line
+    /// has no `=`, a known key has an invalid value, or an exchange rate is
+    /// not a positive number.
+    pub fn parse(contents: &str) -> AppResult<Self> {
+        let mut config = Self::default();
+        let mut x = None;
+        let mut y = None;
+
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let line_number = index + 1;
+            let (key, value) = line.split_once('=').ok_or_else(|| {
+                AppError::Configuration(format!("line {line_number}: expected `key = value`"))
+            })?;
+            let (key, value) = (key.trim(), value.trim());
+
+            match key {
+                "window.width" => config.window.width = parse_value(key, value, line_number)?,
+                "window.height" => config.window.height = parse_value(key, value, line_number)?,
+                "window.x" => x = Some(parse_value(key, value, line_number)?),
+                "window.y" => y = Some(parse_value(key, value, line_number)?),
+                "currency.base" => config.currency.base = value.to_uppercase(),
+                "draft.autosave" => config.autosave_draft = parse_value(key, value, line_number)?,
+                "theme.accent" => config.accent = parse_value(key, value, line_number)?,
+                "number.locale" => config.number_locale = parse_value(key, value, line_number)?,
+                "delete.confirm_threshold" => {
+                    config.delete_confirm_threshold = parse_value(key, value, line_number)?;
+                }
+                _ => {
+                    if let Some(currency) = key.strip_prefix("currency.rate.") {
+                        let rate: f64 = parse_value(key, value, line_number)?;
+                        if !rate.is_finite() || rate <= 0.0 {
+                            return Err(AppError::Configuration(format!(
+                                "line {line_number}: exchange rate for {currency} must be positive"
+                            )));
+                        }
+                        config.currency.rates.insert(currency.to_uppercase(), rate);
+                    }
+                }
+            }
+        }
+
+        config.window.position = x.zip(y);
+        Ok(config)
+    }
+}
+
+impl fmt::Display for Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "window.width = {}", self.window.width)?;
+        writeln!(f, "window.height = {}", self.window.height)?;
+        if let Some((x, y)) = self.window.position {
+            writeln!(f, "window.x = {x}")?;
+            writeln!(f, "window.y = {y}")?;
+        }
+        writeln!(f, "draft.autosave = {}", self.autosave_draft)?;
+        writeln!(f, "theme.accent = {}", self.accent)?;
+        writeln!(f, "number.locale = {}", self.number_locale)?;
+        writeln!(
+            f,
+            "delete.confirm_threshold = {}",
+            self.delete_confirm_threshold
+        )?;
+        writeln!(f, "currency.base = {}", self.currency.base)?;
+        for (currency, rate) in &self.currency.rates {
+            writeln!(f, "currency.rate.{currency} = {rate}")?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(key: &str, value: &str, line_number: usize) -> AppResult<T> {
+    value.parse().map_err(|_| {
+        AppError::Configuration(format!(
+            "line {line_number}: invalid value for {key}: {value}"
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn test_clamp_position_inside_screen_unchanged() {
+        let clamped =
+            WindowGeometry::clamp_position((100.0, 200.0), (800.0, 600.0), (1920.0, 1080.0));
+        assert_eq!(clamped, (100.0, 200.0));
+    }
+
+    #[test]
+    fn test_clamp_position_outside_screen() {
+        let window = (800.0, 600.0);
+        let screen = (1920.0, 1080.0);
+
+        assert_eq!(
+            WindowGeometry::clamp_position((3000.0, 2000.0), window, screen),
+            (1120.0, 480.0)
+        );
+        assert_eq!(
+            WindowGeometry::clamp_position((-500.0, -20.0), window, screen),
+            (0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_clamp_position_window_larger_than_screen() {
+        let clamped =
+            WindowGeometry::clamp_position((50.0, 50.0), (2560.0, 1440.0), (1920.0, 1080.0));
+        assert_eq!(clamped, (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_config_round_trip() {
+        let config = Config {
+            window: WindowGeometry {
+                width: 1280.0,
+                height: 720.5,
+                position: Some((40.0, -10.0)),
+            },
+            currency: CurrencyRates::new("EUR")
+                .with_rate("USD", 0.92)
+                .with_rate("GBP", 1.17),
+            autosave_draft: false,
+            accent: AccentColor::new(0x2E, 0x9E, 0x5B),
+            number_locale: NumberLocale::FrFr,
+            delete_confirm_threshold: 20,
+        };
+
+        assert_eq!(Config::parse(&config.to_string()).unwrap(), config);
+    }
+
+    #[rstest]
+    #[case(0, 5, false)]
+    #[case(4, 5, false)]
+    #[case(5, 5, false)]
+    #[case(6, 5, true)]
+    #[case(12, 5, true)]
+    #[case(1, 0, true)]
+    #[case(0, 0, false)]
+    fn test_requires_delete_confirmation(
+        #[case] count: usize,
+        #[case] threshold: usize,
+        #[case] expected: bool,
+    ) {
+        let config = Config {
+            delete_confirm_threshold: threshold,
+            ..Config::default()
+        };
+        assert_eq!(config.requires_delete_confirmation(count), expected);
+    }
+
+    #[test]
+    fn test_config_delete_confirm_threshold() {
+        assert_eq!(Config::default().delete_confirm_threshold, 5);
+        let config = Config::parse("delete.confirm_threshold = 12\n").unwrap();
+        assert_eq!(config.delete_confirm_threshold, 12);
+        assert!(Config::parse("delete.confirm_threshold = -1\n").is_err());
+    }
+
+    #[test]
+    fn test_number_locale_formatting() {
+        assert_eq!(NumberLocale::EnUs.format_integer(0), "0");
+        assert_eq!(NumberLocale::EnUs.format_integer(999), "999");
+        assert_eq!(NumberLocale::EnUs.format_integer(1_000), "1,000");
+        assert_eq!(NumberLocale::EnUs.format_integer(120_000), "120,000");
+        assert_eq!(NumberLocale::EnUs.format_integer(-1_234_567), "-1,234,567");
+        assert_eq!(NumberLocale::DeDe.format_integer(1_000), "1.000");
+        assert_eq!(NumberLocale::DeDe.format_integer(120_000), "120.000");
+
+        assert_eq!(NumberLocale::EnUs.format_decimal(1_234.5, 1), "1,234.5");
+        assert_eq!(NumberLocale::DeDe.format_decimal(1_234.5, 2), "1.234,50");
+        assert_eq!(NumberLocale::DeDe.format_decimal(-1_234.5, 0), "-1.234");
+        assert_eq!(NumberLocale::DeDe.format_decimal(-0.001, 1), "0,0");
+    }
+
+    #[test]
+    fn test_number_locale_parsing() {
+        assert_eq!(
+            NumberLocale::EnUs.parse_integer::<u32>(" 80,000 "),
+            Ok(80_000)
+        );
+        assert_eq!(NumberLocale::EnUs.parse_integer::<u32>("80000"), Ok(80_000));
+        assert!(NumberLocale::EnUs.parse_integer::<u32>("80.000").is_err());
+        assert_eq!(
+            NumberLocale::DeDe.parse_integer::<i32>("-1.500"),
+            Ok(-1_500)
+        );
+        assert_eq!(NumberLocale::DeDe.parse_integer::<u32>("80000"), Ok(80_000));
+        assert!(NumberLocale::DeDe.parse_integer::<u32>("80,000").is_err());
+        assert_eq!(
+            NumberLocale::FrFr.parse_integer::<u32>("80\u{a0}000"),
+            Ok(80_000)
+        );
+
+        let locale = NumberLocale::DeDe;
+        assert_eq!(
+            locale.parse_integer::<i64>(&locale.format_integer(9_876_543)),
+            Ok(9_876_543)
+        );
+    }
+
+    #[test]
+    fn test_config_number_locale() {
+        assert_eq!(Config::default().number_locale, NumberLocale::EnUs);
+        let config = Config::parse("number.locale = de_de\n").unwrap();
+        assert_eq!(config.number_locale, NumberLocale::DeDe);
+        assert!(config.to_string().contains("number.locale = de-DE"));
+        assert!(matches!(
+            Config::parse("number.locale = xx-XX"),
+            Err(AppError::Configuration(_))
+        ));
+    }
+
+    #[test]
+    fn test_config_accent_color() {
+        assert_eq!(Config::default().accent, AccentColor::default());
+
+        let config = Config::parse("theme.accent = #E67E22\n").unwrap();
+        assert_eq!(config.accent, AccentColor::new(0xE6, 0x7E, 0x22));
+        assert!(config.to_string().contains("theme.accent = #e67e22"));
+        assert_eq!(Config::parse(&config.to_string()).unwrap(), config);
+
+        for invalid in ["#e67e2", "#e67e22ff", "#zz7e22", "blue"] {
+            assert!(
+                matches!(
+                    Config::parse(&format!("theme.accent = {invalid}")),
+                    Err(AppError::Configuration(_))
+                ),
+                "{invalid} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_config_parse_ignores_comments_and_unknown_keys() {
+        let config = Config::parse("# saved\n\nwindow.width = 900\nfuture.key = 1\n").unwrap();
+        let expected = WindowGeometry {
+            width: 900.0,
+            ..WindowGeometry::default()
+        };
+        assert_eq!(config.window, expected);
+    }
+
+    #[test]
+    fn test_config_parse_errors() {
+        assert!(matches!(
+            Config::parse("window.width"),
+            Err(AppError::Configuration(_))
+        ));
+        assert!(matches!(
+            Config::parse("window.height = tall"),
+            Err(AppError::Configuration(_))
+        ));
+    }
+
+    #[test]
+    fn test_currency_conversion() {
+        let rates = CurrencyRates::new("eur")
+            .with_rate("USD", 0.9)
+            .with_rate("GBP", 1.2);
+        assert_eq!(rates.base(), "EUR");
+
+        let converted = rates
+            .convert_all(&[(100_000.0, "USD"), (50_000.0, "gbp"), (70_000.0, "EUR")])
+            .unwrap();
+        let expected = [90_000.0, 60_000.0, 70_000.0];
+        assert!(
+            converted
+                .iter()
+                .zip(expected)
+                .all(|(actual, expected)| (actual - expected).abs() < 1e-6),
+            "unexpected conversion {converted:?}"
+        );
+    }
+
+    #[test]
+    fn test_currency_missing_rate_errors() {
+        let rates = CurrencyRates::new("EUR").with_rate("USD", 0.9);
+        let err = rates
+            .convert_all(&[(1.0, "USD"), (1.0, "JPY")])
+            .unwrap_err();
+        assert!(matches!(err, AppError::Configuration(ref msg) if msg.contains("JPY")));
+    }
+
+    #[test]
+    fn test_config_parse_currency_rates() {
+        let config = Config::parse("currency.base = eur\ncurrency.rate.usd = 0.5\n").unwrap();
+        assert_eq!(
+            config.currency,
+            CurrencyRates::new("EUR").with_rate("USD", 0.5)
+        );
+
+        assert!(matches!(
+            Config::parse("currency.rate.USD = -1"),
+            Err(AppError::Configuration(_))
+        ));
+    }
+
+    #[test]
+    fn test_config_load_missing_file_is_default() {
+        let config = Config::load("does/not/exist/config.txt").unwrap();
+        assert_eq!(config, Config::default());
+    }
+}
@@ -1,25 +1,240 @@
-use crate::model::{JobApplication, SalaryRange, Status};
+use crate::config::CurrencyRates;
+use crate::model::{
+    ChecklistItem, JobApplication, Priority, Reminder, SalaryRange, Status, canonicalize_company,
+};
+use crate::ui::StatusSelection;
 use sqlx::{
     Row,
-    sqlite::{SqliteConnectOptions, SqlitePool},
+    sqlite::{
+        SqliteConnectOptions, SqliteConnection, SqliteJournalMode, SqlitePool, SqlitePoolOptions,
+    },
 };
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use time::Date;
+use std::sync::{Arc, RwLock};
+use time::{
+    Date, Duration, OffsetDateTime, PrimitiveDateTime, UtcOffset,
+    format_description::BorrowedFormatItem, macros::format_description,
+};
+
+/// Storage format for timestamp columns, always in UTC.
+///
+/// Matches `SQLite`'s `CURRENT_TIMESTAMP` layout so stored values compare lexically.
+const TIMESTAMP_FORMAT: &[BorrowedFormatItem<'static>] =
+    format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
+
+/// Layout of the `updated_at` column, written by `SQLite` with millisecond
+/// precision via `strftime('%Y-%m-%d %H:%M:%f', 'now')` (UTC).
+const UPDATED_AT_FORMAT: &[BorrowedFormatItem<'static>] =
+    format_description!("[year]-[month]-[day] [hour]:[minute]:[second].[subsecond digits:3]");
+
+/// iCalendar UTC date-time layout (RFC 5545 §3.3.5, form #2).
+const ICS_TIMESTAMP_FORMAT: &[BorrowedFormatItem<'static>] =
+    format_description!("[year][month][day]T[hour][minute][second]Z");
+
+/// Columns written by `Database::export_csv`, in order.
+const CSV_HEADER: &str =
+    "company,position,location,status,salary_min,salary_max,salary_currency,date,cv_path,archived";
+
+/// Version written in the `{"version": …, "jobs": […]}` envelope of
+/// `Database::export_json`. Bare-array files from before the envelope are
+/// read as version 0.
+#[cfg(feature = "serde")]
+pub const JSON_EXPORT_VERSION: u64 = 1;
+
+/// Maximum octets per iCalendar content line before it must be folded.
+const ICS_LINE_LIMIT: usize = 75;
+
+/// Columns added after the initial schema, applied to databases that predate them.
+const MIGRATIONS: &[(&str, &str)] = &[
+    ("interview_at", "TEXT"),
+    ("updated_at", "TEXT"),
+    ("company_canonical", "TEXT"),
+    ("rejection_reason", "TEXT"),
+    ("pinned", "INTEGER NOT NULL DEFAULT 0"),
+    ("offer_deadline", "TEXT"),
+    ("notes", "TEXT"),
+    ("salary_currency", "TEXT NOT NULL DEFAULT 'USD'"),
+    ("contact_name", "TEXT"),
+    ("contact_email", "TEXT"),
+    ("url", "TEXT"),
+    ("archived", "INTEGER NOT NULL DEFAULT 0"),
+    ("priority", "TEXT"),
+    ("follow_up", "TEXT"),
+];
+
+/// Text columns matched by `Database::search_all`.
+const SEARCH_COLUMNS: &[&str] = &[
+    "company",
+    "position",
+    "location",
+    "cv_path",
+    "company_canonical",
+    "rejection_reason",
+    "notes",
+    "contact_name",
+    "contact_email",
+    "url",
+];
+
+/// Probability that an application in each status turns into a job, keyed by
+/// the status prefix stored in the database.
+///
+/// Used by `Database::expected_pipeline_value` to weight salary midpoints.
+/// Statuses missing from this table are weighted at zero.
+pub const PIPELINE_WEIGHTS: &[(&str, f64)] = &[
+    ("applied", 0.05),
+    ("interview", 0.2),
+    ("offer", 0.8),
+    ("accepted", 1.0),
+    ("rejected", 0.0),
+    ("withdrawn", 0.0),
+];
+
+/// Orderings supported by `Database::get_jobs`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortColumn {
+    /// Most recently added first.
+    #[default]
+    CreatedAt,
+    /// By funnel rank (see `Status::funnel_rank`), then newest application
+    /// date first. Jobs without a date come last within their status.
+    Pipeline,
+}
+
+/// Columns `Database::get_jobs_sorted` can order by.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortBy {
+    /// When the job was added.
+    #[default]
+    CreatedAt,
+    /// Company name, ignoring ASCII case.
+    Company,
+    /// Position title, ignoring ASCII case.
+    Position,
+    /// Upper bound of the salary range.
+    SalaryMax,
+    /// Application date. Jobs without a date sort as the smallest value.
+    Date,
+}
+
+impl SortBy {
+    /// The `ORDER BY` expression for this column. Only these fixed strings
+    /// are ever put into the SQL, never caller input.
+    const fn order_expression(self) -> &'static str {
+        match self {
+            Self::CreatedAt => "created_at",
+            Self::Company => "company COLLATE NOCASE",
+            Self::Position => "position COLLATE NOCASE",
+            Self::SalaryMax => "salary_max",
+            Self::Date => "date",
+        }
+    }
+}
+
+/// Direction of a `Database::get_jobs_sorted` ordering.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Smallest first.
+    Asc,
+    /// Largest first.
+    #[default]
+    Desc,
+}
+
+impl SortOrder {
+    const fn keyword(self) -> &'static str {
+        match self {
+            Self::Asc => "ASC",
+            Self::Desc => "DESC",
+        }
+    }
+}
+
+/// How `Database::import_jobs` handles an imported job whose company and
+/// position match an existing job (compared case-insensitively).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ImportStrategy {
+    /// Keep the existing job and drop the imported one.
+    #[default]
+    Skip,
+    /// Replace the existing job's fields with the imported ones.
+    Overwrite,
+    /// Insert the imported job alongside the existing one.
+    Duplicate,
+}
+
+/// Counts of what `Database::import_jobs` did with each imported job.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ImportSummary {
+    pub inserted: usize,
+    pub updated: usize,
+    pub skipped: usize,
+}
+
+/// Summary of all job applications, computed by `Database::stats`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JobStats {
+    pub total: usize,
+    /// Job counts keyed by status prefix, e.g. `"interview"` for every round.
+    pub by_status: HashMap<String, usize>,
+    /// Average `salary_max` in the base currency of the rates passed to
+    /// `Database::stats`, or 0 when there are no jobs.
+    pub avg_salary_max: f64,
+    pub offer_count: usize,
+}
 
 #[derive(Debug, thiserror::Error)]
 pub enum DbError {
     #[error("Database connection error: {0}")]
     Connection(#[from] sqlx::Error),
+    #[error("Invalid search pattern: {0}")]
+    InvalidPattern(String),
     #[error("Invalid status format: {0}")]
     InvalidStatus(String),
+    #[error("Invalid date: {0}")]
+    InvalidDate(String),
+    #[error("Invalid priority: {0}")]
+    InvalidPriority(String),
     #[error("Job application not found with id: {0}")]
     NotFound(i64),
+    #[error("Cannot update a job application without an ID")]
+    MissingId,
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+    #[error("Job application {0} was modified by someone else; reload it and try again")]
+    Conflict(i64),
+    #[error("File error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Import failed on line {line}: {reason}")]
+    ImportParse { line: usize, reason: String },
+    #[cfg(feature = "serde")]
+    #[error("Invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Unsupported export version: {0}")]
+    UnsupportedVersion(u64),
+    #[error("No exchange rate configured for currency: {0}")]
+    MissingExchangeRate(String),
 }
 
+/// Pool size used by `Database::new`.
+pub const DEFAULT_MAX_CONNECTIONS: u32 = 5;
+
 #[derive(Debug, Clone)]
 pub struct Database {
     pool: SqlitePool,
+    cache: Option<Arc<RwLock<JobCache>>>,
+}
+
+/// The `get_all_jobs_cached` result kept by `Database::with_cache`.
+#[derive(Debug, Default)]
+struct JobCache {
+    jobs: Option<Vec<JobApplication>>,
+    /// Bumped by every invalidation, so a read that raced a write can tell
+    /// its rows are stale and skip storing them.
+    generation: u64,
 }
 
 impl Database {
@@ -50,17 +265,78 @@ impl Database {
     /// # }
     /// ```
     pub async fn new(database_url: &str) -> Result<Self, DbError> {
+        Self::new_with_options(database_url, DEFAULT_MAX_CONNECTIONS).await
+    }
+
+    /// Creates a new database connection with a pool of at most
+    /// `max_connections` connections, and initializes the schema.
+    ///
+    /// The database is opened in WAL mode so reads don't wait for writers.
+    ///
+    /// # Arguments
+    ///
+    /// * `database_url` - The `SQLite` database URL (e.g., `sqlite:jobs.db` or `sqlite::memory:`)
+    /// * `max_connections` - The maximum number of pooled connections
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection cannot be established
+    /// - The parent directory cannot be created
+    /// - The database schema creation fails
+    /// - The database URL is malformed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new_with_options("sqlite:jobs.db", 10).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn new_with_options(
+        database_url: &str,
+        max_connections: u32,
+    ) -> Result<Self, DbError> {
         Self::ensure_database_directory(database_url)?;
 
-        let connection_options =
-            SqliteConnectOptions::from_str(database_url)?.create_if_missing(true);
+        let connection_options = SqliteConnectOptions::from_str(database_url)?
+            .create_if_missing(true)
+            .journal_mode(SqliteJournalMode::Wal);
 
-        let pool = SqlitePool::connect_with(connection_options).await?;
-        let db = Self { pool };
+        let pool = SqlitePoolOptions::new()
+            .max_connections(max_connections)
+            .connect_with(connection_options)
+            .await?;
+        let db = Self { pool, cache: None };
         db.create_schema().await?;
         Ok(db)
     }
 
+    /// Enables the in-memory cache for `get_all_jobs_cached`.
+    ///
+    /// Once enabled, `get_all_jobs_cached` serves repeated reads from memory
+    /// until a write through this `Database` (or any of its clones)
+    /// invalidates it. Changes made to the database file by other processes
+    /// are not observed while the cache is populated. `get_all_jobs` always
+    /// queries the database.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite:jobs.db").await?.with_cache();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_cache(mut self) -> Self {
+        self.cache = Some(Arc::new(RwLock::new(JobCache::default())));
+        self
+    }
+
     fn ensure_database_directory(database_url: &str) -> Result<(), DbError> {
         if database_url == "sqlite::memory:" {
             return Ok(());
@@ -83,8 +359,9 @@ impl Database {
 
     /// Creates the database schema for job applications.
     ///
-    /// This function creates the `job_applications` table with all required
-    /// columns if it doesn't already exist.
+    /// This function creates the `job_applications`, `reminders`,
+    /// `checklist_items`, `tags`, and `job_tags` tables with all required
+    /// columns if they don't already exist.
     ///
     /// # Errors
     ///
@@ -92,6 +369,10 @@ impl Database {
     /// - The SQL execution fails
     /// - The database connection is lost
     async fn create_schema(&self) -> Result<(), DbError> {
+        // Run every schema statement on one connection so no other pooled
+        // connection caches the table layout from before a migration.
+        let mut conn = self.pool.acquire().await?;
+
         sqlx::query(
             r"
             CREATE TABLE IF NOT EXISTS job_applications (
@@ -104,15 +385,125 @@ impl Database {
                 location TEXT NOT NULL,
                 salary_min INTEGER NOT NULL DEFAULT 0,
                 salary_max INTEGER NOT NULL DEFAULT 0,
-                created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+                interview_at TEXT,
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                updated_at TEXT,
+                company_canonical TEXT,
+                rejection_reason TEXT,
+                pinned INTEGER NOT NULL DEFAULT 0,
+                offer_deadline TEXT,
+                notes TEXT,
+                salary_currency TEXT NOT NULL DEFAULT 'USD',
+                contact_name TEXT,
+                contact_email TEXT,
+                url TEXT,
+                archived INTEGER NOT NULL DEFAULT 0,
+                priority TEXT,
+                follow_up TEXT
             )
             ",
         )
-        .execute(&self.pool)
+        .execute(&mut *conn)
+        .await?;
+        Self::migrate_schema(&mut conn).await?;
+
+        sqlx::query(
+            r"
+            CREATE TABLE IF NOT EXISTS reminders (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                job_id INTEGER NOT NULL REFERENCES job_applications(id) ON DELETE CASCADE,
+                remind_at TEXT NOT NULL,
+                message TEXT NOT NULL
+            );
+            CREATE TABLE IF NOT EXISTS checklist_items (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                job_id INTEGER NOT NULL REFERENCES job_applications(id) ON DELETE CASCADE,
+                text TEXT NOT NULL,
+                done INTEGER NOT NULL DEFAULT 0
+            );
+            CREATE TABLE IF NOT EXISTS tags (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL UNIQUE
+            );
+            CREATE TABLE IF NOT EXISTS job_tags (
+                job_id INTEGER NOT NULL REFERENCES job_applications(id) ON DELETE CASCADE,
+                tag_id INTEGER NOT NULL REFERENCES tags(id) ON DELETE CASCADE,
+                PRIMARY KEY (job_id, tag_id)
+            );
+            CREATE TABLE IF NOT EXISTS app_settings (
+                key TEXT PRIMARY KEY,
+                value TEXT
+            );
+            ",
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        // Triggers record every status a job is stored with, whichever write
+        // path set it, so the history cannot drift from the jobs table. Jobs
+        // stored before the history existed start with their current status.
+        sqlx::query(
+            r"
+            CREATE TABLE IF NOT EXISTS status_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                job_id INTEGER NOT NULL REFERENCES job_applications(id) ON DELETE CASCADE,
+                status TEXT NOT NULL,
+                changed_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now'))
+            );
+            CREATE TRIGGER IF NOT EXISTS status_history_on_insert
+            AFTER INSERT ON job_applications
+            BEGIN
+                INSERT INTO status_history (job_id, status) VALUES (NEW.id, NEW.status);
+            END;
+            CREATE TRIGGER IF NOT EXISTS status_history_on_update
+            AFTER UPDATE OF status ON job_applications
+            WHEN NEW.status IS NOT OLD.status
+            BEGIN
+                INSERT INTO status_history (job_id, status) VALUES (NEW.id, NEW.status);
+            END;
+            INSERT INTO status_history (job_id, status, changed_at)
+            SELECT id, status, COALESCE(updated_at, created_at, strftime('%Y-%m-%d %H:%M:%f', 'now'))
+            FROM job_applications
+            WHERE id NOT IN (SELECT job_id FROM status_history);
+            ",
+        )
+        .execute(&mut *conn)
         .await?;
         Ok(())
     }
 
+    /// Adds any columns from `MIGRATIONS` that are missing from an existing table.
+    ///
+    /// This keeps databases created by older versions of the application usable
+    /// without manual intervention.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The table information cannot be read
+    /// - An `ALTER TABLE` statement fails
+    async fn migrate_schema(conn: &mut SqliteConnection) -> Result<(), DbError> {
+        let existing: Vec<String> =
+            sqlx::query("SELECT name FROM pragma_table_info('job_applications')")
+                .fetch_all(&mut *conn)
+                .await?
+                .iter()
+                .map(|row| row.get("name"))
+                .collect();
+
+        for (column, definition) in MIGRATIONS {
+            if !existing.iter().any(|name| name == column) {
+                sqlx::query(&format!(
+                    "ALTER TABLE job_applications ADD COLUMN {column} {definition}"
+                ))
+                .execute(&mut *conn)
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Inserts a new job application into the database.
     ///
     /// # Arguments
@@ -147,82 +538,78 @@ impl Database {
     /// # }
     /// ```
     pub async fn insert_job(&self, job: &JobApplication) -> Result<i64, DbError> {
-        let date_str = job.date.map(|d| d.to_string());
-        let cv_path_str = job.cv.as_ref().map(|p| p.to_string_lossy().to_string());
-        let status_str = job.status.to_db_string();
-
-        let result = sqlx::query(
-            r"
-            INSERT INTO job_applications (date, cv_path, company, position, status, location, salary_min, salary_max)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
-            ",
-        )
-        .bind(date_str)
-        .bind(cv_path_str)
-        .bind(&job.company)
-        .bind(&job.position)
-        .bind(status_str)
-        .bind(&job.location)
-        .bind(i64::from(job.salary.min))
-        .bind(i64::from(job.salary.max))
-        .execute(&self.pool)
-        .await?;
-
-        Ok(result.last_insert_rowid())
+        let id = Self::insert_row(&self.pool, job, None).await?;
+        self.invalidate_cache();
+        Ok(id)
     }
 
-    /// Retrieves all job applications from the database.
+    /// Inserts several job applications in a single transaction.
     ///
-    /// Returns job applications ordered by creation date (most recent first).
+    /// Like `insert_job`, ids on the given jobs are ignored. If any insert
+    /// fails, the transaction is rolled back and none of the jobs are stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `jobs` - The job applications to insert
+    ///
+    /// # Returns
+    ///
+    /// The generated IDs, in the same order as `jobs`.
     ///
     /// # Errors
     ///
     /// This function will return an error if:
     /// - The database connection is lost
-    /// - The SQL query fails
-    /// - A stored status string cannot be parsed
-    /// - A stored date cannot be parsed
-    /// - Database corruption prevents reading
+    /// - The SQL execution fails
+    /// - Database constraints are violated
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use job_tracker::db::Database;
+    /// # use job_tracker::model::JobApplication;
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let db = Database::new("sqlite::memory:").await?;
-    /// let jobs = db.get_all_jobs().await?;
-    /// println!("Found {} jobs", jobs.len());
+    /// let jobs = vec![
+    ///     JobApplication::new().company("TechCorp").position("Developer"),
+    ///     JobApplication::new().company("DataCorp").position("Analyst"),
+    /// ];
+    /// let ids = db.insert_jobs(&jobs).await?;
+    /// assert_eq!(ids.len(), 2);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_all_jobs(&self) -> Result<Vec<JobApplication>, DbError> {
-        let rows = sqlx::query("SELECT * FROM job_applications ORDER BY created_at DESC")
-            .fetch_all(&self.pool)
-            .await?;
-
-        let mut jobs = Vec::new();
-        for row in rows {
-            let job = Self::row_to_job_application(&row)?;
-            jobs.push(job);
+    pub async fn insert_jobs(&self, jobs: &[JobApplication]) -> Result<Vec<i64>, DbError> {
+        let mut tx = self.pool.begin().await?;
+        let mut ids = Vec::with_capacity(jobs.len());
+        for job in jobs {
+            ids.push(Self::insert_row(&mut *tx, job, None).await?);
         }
-
-        Ok(jobs)
+        tx.commit().await?;
+        self.invalidate_cache();
+        Ok(ids)
     }
 
-    /// Retrieves a specific job application by ID.
+    /// Stores a copy of a job application as a new, freshly applied job.
+    ///
+    /// Every field is copied except the status, which is reset to
+    /// `Status::Applied`, the archived flag, which is cleared, and the
+    /// database-managed ID and timestamps.
     ///
     /// # Arguments
     ///
-    /// * `id` - The ID of the job application to retrieve
+    /// * `id` - The ID of the job application to copy
+    ///
+    /// # Returns
+    ///
+    /// The ID of the new job application.
     ///
     /// # Errors
     ///
     /// This function will return an error if:
     /// - No job application exists with the given ID (`DbError::NotFound`)
     /// - The database connection is lost
-    /// - The SQL query fails
-    /// - A stored status string cannot be parsed
-    /// - A stored date cannot be parsed
+    /// - The SQL execution fails
     ///
     /// # Examples
     ///
@@ -230,131 +617,181 @@ impl Database {
     /// # use job_tracker::db::Database;
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let db = Database::new("sqlite::memory:").await?;
-    /// match db.get_job_by_id(1).await {
-    ///     Ok(job) => println!("Found job: {}", job.company),
-    ///     Err(e) => println!("Job not found: {}", e),
-    /// }
+    /// let copy_id = db.duplicate_job(1).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_job_by_id(&self, id: i64) -> Result<JobApplication, DbError> {
-        let row = sqlx::query("SELECT * FROM job_applications WHERE id = ?")
-            .bind(id)
-            .fetch_optional(&self.pool)
-            .await?;
-
-        row.map_or_else(
-            || Err(DbError::NotFound(id)),
-            |r| Self::row_to_job_application(&r),
-        )
+    pub async fn duplicate_job(&self, id: i64) -> Result<i64, DbError> {
+        let job = self.get_job_by_id(id).await?;
+        let copy = JobApplication {
+            id: None,
+            status: Status::Applied,
+            archived: false,
+            updated_at: None,
+            created_at: None,
+            ..job
+        };
+        self.insert_job(&copy).await
     }
 
-    /// Updates an existing job application in the database.
+    /// Replaces every job application with `jobs` in a single transaction.
+    ///
+    /// Jobs that carry an id keep it; jobs without one are assigned a new id.
+    /// A job whose id is already stored is updated in place, so its status
+    /// history, tags, reminders, and checklist are kept. Stored jobs missing
+    /// from `jobs` are deleted together with that related data. If any write
+    /// fails, the transaction is rolled back and the existing data is left
+    /// untouched.
     ///
     /// # Arguments
     ///
-    /// * `job` - The job application to update (must have a valid ID)
+    /// * `jobs` - The complete set of job applications to store
     ///
     /// # Errors
     ///
     /// This function will return an error if:
-    /// - The job application doesn't have an ID (`DbError::NotFound`)
-    /// - No job application exists with the given ID (`DbError::NotFound`)
+    /// - Two jobs share the same id (`DbError::InvalidArgument`)
     /// - The database connection is lost
     /// - The SQL execution fails
-    /// - Database constraints are violated
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use job_tracker::db::Database;
-    /// # use job_tracker::model::Status;
+    /// # use job_tracker::model::JobApplication;
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let db = Database::new("sqlite::memory:").await?;
-    /// let mut job = db.get_job_by_id(1).await?;
-    /// job.status = Status::Interview(2);
-    /// db.update_job(&job).await?;
+    /// let remote = vec![JobApplication::new().company("TechCorp")];
+    /// db.replace_all(&remote).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn update_job(&self, job: &JobApplication) -> Result<(), DbError> {
-        let id = job.id.ok_or(DbError::NotFound(0))?;
-        let date_str = job.date.map(|d| d.to_string());
-        let cv_path_str = job.cv.as_ref().map(|p| p.to_string_lossy().to_string());
-        let status_str = job.status.to_db_string();
-
-        let result = sqlx::query(
-            r"
-            UPDATE job_applications
-            SET date = ?, cv_path = ?, company = ?, position = ?, status = ?, location = ?, salary_min = ?, salary_max = ?
-            WHERE id = ?
-            ",
-        )
-        .bind(date_str)
-        .bind(cv_path_str)
-        .bind(&job.company)
-        .bind(&job.position)
-        .bind(status_str)
-        .bind(&job.location)
-        .bind(i64::from(job.salary.min))
-        .bind(i64::from(job.salary.max))
-        .bind(id)
-        .execute(&self.pool)
-        .await?;
-
-        if result.rows_affected() == 0 {
-            return Err(DbError::NotFound(id));
+    pub async fn replace_all(&self, jobs: &[JobApplication]) -> Result<(), DbError> {
+        let mut kept = HashSet::new();
+        if let Some(id) = jobs
+            .iter()
+            .filter_map(|job| job.id)
+            .find(|id| !kept.insert(*id))
+        {
+            return Err(DbError::InvalidArgument(format!(
+                "job id {id} appears more than once"
+            )));
         }
 
+        let mut tx = self.pool.begin().await?;
+        let stored: HashSet<i64> = sqlx::query_scalar("SELECT id FROM job_applications")
+            .fetch_all(&mut *tx)
+            .await?
+            .into_iter()
+            .collect();
+        for id in stored.difference(&kept) {
+            sqlx::query("DELETE FROM job_applications WHERE id = ?")
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        // Jobs with an id go first so a fresh job cannot be assigned one of them.
+        for job in jobs {
+            let Some(id) = job.id else { continue };
+            if stored.contains(&id) {
+                Self::update_row(&mut *tx, job, id, None).await?;
+            } else {
+                Self::insert_row(&mut *tx, job, Some(id)).await?;
+            }
+        }
+        for job in jobs.iter().filter(|job| job.id.is_none()) {
+            Self::insert_row(&mut *tx, job, None).await?;
+        }
+        tx.commit().await?;
+        self.invalidate_cache();
         Ok(())
     }
 
-    /// Deletes a job application from the database.
+    /// Imports job applications, resolving company and position collisions
+    /// with `strategy`.
+    ///
+    /// Imported ids are ignored. The whole import runs in one transaction, so
+    /// a failure leaves the database unchanged.
     ///
     /// # Arguments
     ///
-    /// * `id` - The ID of the job application to delete
+    /// * `jobs` - The job applications to import
+    /// * `strategy` - What to do when a job matches an existing one
     ///
     /// # Errors
     ///
     /// This function will return an error if:
-    /// - No job application exists with the given ID (`DbError::NotFound`)
     /// - The database connection is lost
     /// - The SQL execution fails
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use job_tracker::db::Database;
+    /// # use job_tracker::db::{Database, ImportStrategy};
+    /// # use job_tracker::model::JobApplication;
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let db = Database::new("sqlite::memory:").await?;
-    /// db.delete_job(1).await?;
+    /// let jobs = vec![JobApplication::new().company("TechCorp").position("Engineer")];
+    /// let summary = db.import_jobs(&jobs, ImportStrategy::Skip).await?;
+    /// println!("{} inserted, {} skipped", summary.inserted, summary.skipped);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn delete_job(&self, id: i64) -> Result<(), DbError> {
-        let result = sqlx::query("DELETE FROM job_applications WHERE id = ?")
-            .bind(id)
-            .execute(&self.pool)
+    pub async fn import_jobs(
+        &self,
+        jobs: &[JobApplication],
+        strategy: ImportStrategy,
+    ) -> Result<ImportSummary, DbError> {
+        let mut summary = ImportSummary::default();
+        let mut tx = self.pool.begin().await?;
+
+        for job in jobs {
+            let existing: Option<i64> = sqlx::query_scalar(
+                r"
+                SELECT id FROM job_applications
+                WHERE company = ? COLLATE NOCASE AND position = ? COLLATE NOCASE
+                ORDER BY id
+                LIMIT 1
+                ",
+            )
+            .bind(&job.company)
+            .bind(&job.position)
+            .fetch_optional(&mut *tx)
             .await?;
 
-        if result.rows_affected() == 0 {
-            return Err(DbError::NotFound(id));
+            match (existing, strategy) {
+                (Some(_), ImportStrategy::Skip) => summary.skipped += 1,
+                (Some(id), ImportStrategy::Overwrite) => {
+                    Self::update_row(&mut *tx, job, id, None).await?;
+                    summary.updated += 1;
+                }
+                (None, _) | (Some(_), ImportStrategy::Duplicate) => {
+                    Self::insert_row(&mut *tx, job, None).await?;
+                    summary.inserted += 1;
+                }
+            }
         }
 
-        Ok(())
+        tx.commit().await?;
+        self.invalidate_cache();
+        Ok(summary)
     }
 
-    /// Clears all job applications from the database.
+    /// Retrieves all job applications from the database, except archived ones.
     ///
-    /// This operation is irreversible and will remove all stored job applications.
+    /// Pinned jobs come first, then the rest by creation date (most recent
+    /// first). Archived jobs are listed by `get_archived_jobs` instead. Every
+    /// call queries the database; use `get_all_jobs_cached` to read through
+    /// the cache.
     ///
     /// # Errors
     ///
     /// This function will return an error if:
     /// - The database connection is lost
-    /// - The SQL execution fails
-    /// - Database constraints prevent deletion
+    /// - The SQL query fails
+    /// - A stored status string cannot be parsed
+    /// - A stored date cannot be parsed
+    /// - Database corruption prevents reading
     ///
     /// # Examples
     ///
@@ -362,450 +799,7042 @@ impl Database {
     /// # use job_tracker::db::Database;
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let db = Database::new("sqlite::memory:").await?;
-    /// db.clear_all().await?;
+    /// let jobs = db.get_all_jobs().await?;
+    /// println!("Found {} jobs", jobs.len());
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn clear_all(&self) -> Result<(), DbError> {
-        sqlx::query("DELETE FROM job_applications")
-            .execute(&self.pool)
-            .await?;
-        Ok(())
+    pub async fn get_all_jobs(&self) -> Result<Vec<JobApplication>, DbError> {
+        let rows = sqlx::query(
+            "SELECT * FROM job_applications WHERE archived = 0 ORDER BY pinned DESC, created_at DESC, id DESC",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Self::rows_to_job_applications(&rows)
     }
 
-    /// Closes the database connection pool.
+    /// Retrieves all job applications, except archived ones, through the
+    /// cache enabled by `with_cache`.
     ///
-    /// This gracefully closes all database connections in the pool.
-    /// After calling this method, no further database operations should
-    /// be performed on this instance.
-    ///
-    /// # Errors
-    ///
-    /// This function will return an error if there are issues closing
-    /// the connection pool, though in practice this is rare.
-    pub async fn close(&self) -> Result<(), DbError> {
-        self.pool.close().await;
-        Ok(())
-    }
-
-    /// Converts a database row to a `JobApplication` struct.
-    ///
-    /// # Arguments
-    ///
-    /// * `row` - The `SQLite` row to convert
+    /// Repeated calls are served from memory until a write through this
+    /// `Database` invalidates the cache. Without `with_cache`, every call
+    /// queries the database like `get_all_jobs`.
     ///
     /// # Errors
     ///
     /// This function will return an error if:
-    /// - A required column is missing from the row
-    /// - A date string cannot be parsed into a valid date
-    /// - A status string cannot be parsed into a valid status
-    /// - Column data types are unexpected
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored status string cannot be parsed
+    /// - A stored date cannot be parsed
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// This function may panic if:
-    /// - Required columns are missing (this indicates a schema mismatch)
-    /// - Column types don't match expected types
-    fn row_to_job_application(row: &sqlx::sqlite::SqliteRow) -> Result<JobApplication, DbError> {
-        let id: i64 = row.get("id");
-        let date_str: Option<String> = row.get("date");
-        let cv_path_str: Option<String> = row.get("cv_path");
-        let company: String = row.get("company");
-        let position: String = row.get("position");
-        let status_str: String = row.get("status");
-        let location: String = row.get("location");
-        let salary_min: i64 = row.get("salary_min");
-        let salary_max: i64 = row.get("salary_max");
-
-        let date = if let Some(date_str) = date_str {
-            Some(
-                Date::parse(
-                    &date_str,
-                    &time::format_description::well_known::Iso8601::DATE,
-                )
-                .map_err(|_| DbError::InvalidStatus(format!("Invalid date format: {date_str}")))?,
-            )
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite:jobs.db").await?.with_cache();
+    /// let jobs = db.get_all_jobs_cached().await?;
+    /// println!("Found {} jobs", jobs.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_jobs_cached(&self) -> Result<Vec<JobApplication>, DbError> {
+        let generation = if let Some(cache) = &self.cache
+            && let Ok(cached) = cache.read()
+        {
+            if let Some(jobs) = &cached.jobs {
+                return Ok(jobs.clone());
+            }
+            Some(cached.generation)
         } else {
             None
         };
 
-        let cv = cv_path_str.map(PathBuf::from);
-        let status = Status::from_db_string(&status_str).map_err(DbError::InvalidStatus)?;
-        let salary = SalaryRange::new(
-            u32::try_from(salary_min).unwrap_or(0),
-            u32::try_from(salary_max).unwrap_or(0),
-        );
+        let jobs = self.get_all_jobs().await?;
 
-        Ok(JobApplication {
-            id: Some(id),
-            date,
-            cv,
-            company,
-            position,
-            status,
-            location,
-            salary,
-        })
+        if let Some(generation) = generation {
+            self.store_cache(generation, &jobs);
+        }
+
+        Ok(jobs)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::*;
-    use std::fs;
-    use std::thread;
+    /// Retrieves one page of job applications.
+    ///
+    /// Pages follow the same order as [`Database::get_all_jobs`], so reading
+    /// every page in turn yields the same list. An `offset` past the last row
+    /// returns an empty vector. Use [`Database::count_jobs`] to compute the
+    /// number of pages.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - The maximum number of jobs to return
+    /// * `offset` - The number of jobs to skip
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - `limit` or `offset` is negative (`DbError::InvalidArgument`)
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored status string cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let page_size = 25;
+    /// let pages = (db.count_jobs().await? + page_size - 1) / page_size;
+    /// let second_page = db.get_jobs_paginated(page_size, page_size).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_jobs_paginated(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<JobApplication>, DbError> {
+        if limit < 0 || offset < 0 {
+            return Err(DbError::InvalidArgument(format!(
+                "limit and offset must not be negative, got {limit} and {offset}"
+            )));
+        }
 
-    async fn create_test_db() -> Database {
-        Database::new("sqlite::memory:").await.unwrap()
-    }
+        let rows = sqlx::query(
+            r"
+            SELECT * FROM job_applications
+            WHERE archived = 0
+            ORDER BY pinned DESC, created_at DESC, id DESC
+            LIMIT ? OFFSET ?
+            ",
+        )
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
 
-    fn create_test_job() -> JobApplication {
-        JobApplication::new()
-            .company("Test Corp")
-            .position("Software Engineer")
-            .location("Remote")
-            .salary(SalaryRange::new(80_000, 120_000))
-            .status(Status::Applied)
-            .date(2024, 1, 15)
+        Self::rows_to_job_applications(&rows)
     }
 
-    fn create_job_with_params(
-        company: &str,
-        position: &str,
-        location: &str,
-        salary_min: u32,
-        salary_max: u32,
-        status: Status,
-    ) -> JobApplication {
-        JobApplication::new()
-            .company(company)
-            .position(position)
-            .location(location)
-            .salary(SalaryRange::new(salary_min, salary_max))
-            .status(status)
-            .date(2024, 1, 15)
+    /// Retrieves all job applications in the given order.
+    ///
+    /// # Arguments
+    ///
+    /// * `sort` - The ordering to apply
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored status string cannot be parsed
+    /// - A stored date cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::{Database, SortColumn};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let jobs = db.get_jobs(SortColumn::Pipeline).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_jobs(&self, sort: SortColumn) -> Result<Vec<JobApplication>, DbError> {
+        let mut jobs = self.get_all_jobs().await?;
+        match sort {
+            SortColumn::CreatedAt => {}
+            SortColumn::Pipeline => {
+                jobs.sort_by_key(|job| {
+                    (
+                        !job.pinned,
+                        job.status.funnel_rank(),
+                        std::cmp::Reverse(job.date),
+                    )
+                });
+            }
+        }
+        Ok(jobs)
     }
 
-    async fn cleanup_test_files(test_dir: &str) {
-        let _ = fs::remove_dir_all(test_dir);
-        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    /// Retrieves all job applications ordered by `by` in direction `order`.
+    ///
+    /// Pinned jobs still come first. Jobs that compare equal keep the most
+    /// recently added first, except when sorting by `CreatedAt` itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `by` - The column to sort by
+    /// * `order` - Ascending or descending
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored status string cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::{Database, SortBy, SortOrder};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let best_paid = db.get_jobs_sorted(SortBy::SalaryMax, SortOrder::Desc).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_jobs_sorted(
+        &self,
+        by: SortBy,
+        order: SortOrder,
+    ) -> Result<Vec<JobApplication>, DbError> {
+        let direction = order.keyword();
+        // Rows added in the same second share a created_at; id keeps their
+        // insertion order in the requested direction.
+        let tiebreak = if by == SortBy::CreatedAt {
+            format!("id {direction}")
+        } else {
+            "created_at DESC, id DESC".to_string()
+        };
+        let sql = format!(
+            "SELECT * FROM job_applications WHERE archived = 0 ORDER BY pinned DESC, {} {direction}, {tiebreak}",
+            by.order_expression(),
+        );
+        let rows = sqlx::query(&sql).fetch_all(&self.pool).await?;
+
+        Self::rows_to_job_applications(&rows)
     }
 
-    fn get_unique_test_dir(test_name: &str) -> String {
-        format!("test_{}_{:?}", test_name, thread::current().id())
+    /// Retrieves a specific job application by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the job application to retrieve
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - No job application exists with the given ID (`DbError::NotFound`)
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored status string cannot be parsed
+    /// - A stored date cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// match db.get_job_by_id(1).await {
+    ///     Ok(job) => println!("Found job: {}", job.company),
+    ///     Err(e) => println!("Job not found: {}", e),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_job_by_id(&self, id: i64) -> Result<JobApplication, DbError> {
+        let row = sqlx::query("SELECT * FROM job_applications WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        row.map_or_else(
+            || Err(DbError::NotFound(id)),
+            |r| Self::row_to_job_application(&r),
+        )
     }
 
-    async fn create_test_db_at_path(test_name: &str) -> (Database, String) {
-        let test_dir = get_unique_test_dir(test_name);
-        cleanup_test_files(&test_dir).await;
-        let db_path = format!("sqlite:{test_dir}/test.db");
-        let db = Database::new(&db_path).await.unwrap();
-        (db, test_dir)
+    /// Retrieves job applications whose status matches any of the given selections.
+    ///
+    /// Selections match by status kind, so `StatusSelection::Interview` matches
+    /// every interview round and `StatusSelection::Offer` every offer amount.
+    /// Pinned jobs come first, then the rest by creation date (most recent
+    /// first). An empty slice matches nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `statuses` - The status kinds to include
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored row cannot be converted to a `JobApplication`
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # use job_tracker::ui::StatusSelection;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let active = db
+    ///     .get_jobs_by_statuses(&[StatusSelection::Interview, StatusSelection::Offer])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_jobs_by_statuses(
+        &self,
+        statuses: &[StatusSelection],
+    ) -> Result<Vec<JobApplication>, DbError> {
+        if statuses.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let conditions = vec!["status = ? OR status LIKE ?"; statuses.len()].join(" OR ");
+        let sql = format!(
+            "SELECT * FROM job_applications WHERE archived = 0 AND ({conditions}) ORDER BY pinned DESC, created_at DESC, id DESC"
+        );
+
+        let mut query = sqlx::query(&sql);
+        for status in statuses {
+            let prefix = status.db_prefix();
+            query = query.bind(prefix).bind(format!("{prefix}:%"));
+        }
+        let rows = query.fetch_all(&self.pool).await?;
+
+        Self::rows_to_job_applications(&rows)
     }
 
-    fn assert_job_equals_ignoring_id(actual: &JobApplication, expected: &JobApplication) {
-        assert_eq!(actual.company, expected.company);
-        assert_eq!(actual.position, expected.position);
-        assert_eq!(actual.location, expected.location);
-        assert_eq!(actual.salary, expected.salary);
-        assert_eq!(actual.status, expected.status);
-        assert_eq!(actual.date, expected.date);
-        assert_eq!(actual.cv, expected.cv);
+    /// Retrieves job applications with the given status.
+    ///
+    /// Statuses match exactly on their stored form, so `Offer(90_000)` only
+    /// matches offers of that amount. The exception is `Interview`: any
+    /// round matches every interview round (`status LIKE 'interview:%'`), so
+    /// `Interview(1)` also returns jobs in their third round. To match every
+    /// offer amount, use [`Database::get_jobs_by_statuses`].
+    ///
+    /// Pinned jobs come first, then the rest by creation date (most recent
+    /// first).
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The status to match
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored row cannot be converted to a `JobApplication`
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # use job_tracker::model::Status;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let interviewing = db.get_jobs_by_status(&Status::Interview(1)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_jobs_by_status(
+        &self,
+        status: &Status,
+    ) -> Result<Vec<JobApplication>, DbError> {
+        let (condition, value) = match status {
+            Status::Interview(_) => ("status LIKE ?", "interview:%".to_string()),
+            _ => ("status = ?", status.to_db_string()),
+        };
+        let sql = format!(
+            "SELECT * FROM job_applications WHERE archived = 0 AND {condition} ORDER BY pinned DESC, created_at DESC, id DESC"
+        );
+
+        let rows = sqlx::query(&sql).bind(value).fetch_all(&self.pool).await?;
+
+        Self::rows_to_job_applications(&rows)
+    }
+
+    /// Retrieves job applications dated between `start` and `end`, inclusive.
+    ///
+    /// Jobs without a date are never returned. Results are ordered by date
+    /// (earliest first).
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The first date to include
+    /// * `end` - The last date to include
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored row cannot be converted to a `JobApplication`
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # use time::macros::date;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let march = db
+    ///     .get_jobs_between(date!(2024 - 03 - 01), date!(2024 - 03 - 31))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_jobs_between(
+        &self,
+        start: Date,
+        end: Date,
+    ) -> Result<Vec<JobApplication>, DbError> {
+        // Dates are stored as ISO-8601 text, so lexical order is date order.
+        let rows = sqlx::query(
+            "SELECT * FROM job_applications WHERE archived = 0 AND date >= ? AND date <= ? ORDER BY date, id",
+        )
+        .bind(start.to_string())
+        .bind(end.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Self::rows_to_job_applications(&rows)
+    }
+
+    /// Retrieves the `n` most recently created job applications.
+    ///
+    /// Like `get_all_jobs`, archived jobs are left out. Results are ordered
+    /// by creation date (most recent first).
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The maximum number of jobs to return
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - `n` is negative (`DbError::InvalidArgument`)
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored row cannot be converted to a `JobApplication`
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let latest = db.get_recent_jobs(5).await?;
+    /// assert!(latest.len() <= 5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_recent_jobs(&self, n: i64) -> Result<Vec<JobApplication>, DbError> {
+        if n < 0 {
+            return Err(DbError::InvalidArgument(format!(
+                "job count must not be negative, got {n}"
+            )));
+        }
+        if n == 0 {
+            return Ok(Vec::new());
+        }
+
+        let rows = sqlx::query(
+            "SELECT * FROM job_applications WHERE archived = 0 ORDER BY created_at DESC, id DESC LIMIT ?",
+        )
+        .bind(n)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Self::rows_to_job_applications(&rows)
+    }
+
+    /// Retrieves the statuses a job application has been stored with.
+    ///
+    /// A row is recorded whenever a job is inserted and whenever its status
+    /// changes, through any write path. Saving the same status again does not
+    /// add a row.
+    ///
+    /// # Arguments
+    ///
+    /// * `job_id` - The ID of the job application
+    ///
+    /// # Returns
+    ///
+    /// Each status with the UTC date it was set, oldest first. Unknown ids
+    /// return an empty list.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored status or date cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// for (status, date) in db.get_status_history(1).await? {
+    ///     println!("{date}: {status:?}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_status_history(&self, job_id: i64) -> Result<Vec<(Status, Date)>, DbError> {
+        let rows = sqlx::query(
+            r"
+            SELECT status, date(changed_at) AS day
+            FROM status_history
+            WHERE job_id = ?
+            ORDER BY changed_at, id
+            ",
+        )
+        .bind(job_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter()
+            .map(|row| {
+                let status: String = row.get("status");
+                let day: String = row.get("day");
+                Ok((
+                    Status::from_db_string(&status).map_err(DbError::InvalidStatus)?,
+                    parse_date(&day)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Retrieves other job applications whose salary band overlaps the given job's.
+    ///
+    /// Two bands overlap when each one's minimum is at most the other's
+    /// maximum, so bands that merely touch at an endpoint are included.
+    /// The base job itself is never part of the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `job_id` - The ID of the job application to compare against
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - No job application exists with the given ID (`DbError::NotFound`)
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored row cannot be converted to a `JobApplication`
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let comparable = db.get_jobs_with_overlapping_salary(1).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_jobs_with_overlapping_salary(
+        &self,
+        job_id: i64,
+    ) -> Result<Vec<JobApplication>, DbError> {
+        let base = self.get_job_by_id(job_id).await?;
+
+        let rows = sqlx::query(
+            r"
+            SELECT * FROM job_applications
+            WHERE archived = 0 AND id != ? AND salary_min <= ? AND salary_max >= ?
+            ORDER BY created_at DESC
+            ",
+        )
+        .bind(job_id)
+        .bind(i64::from(base.salary.max))
+        .bind(i64::from(base.salary.min))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Self::rows_to_job_applications(&rows)
+    }
+
+    /// Updates an existing job application in the database.
+    ///
+    /// `job.updated_at` is the version the caller last read. When it is set,
+    /// the update only applies if the stored row still has that timestamp, so
+    /// a stale copy cannot silently overwrite a newer edit. Jobs with no
+    /// `updated_at` are written unconditionally.
+    ///
+    /// # Arguments
+    ///
+    /// * `job` - The job application to update (must have a valid ID)
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The job application doesn't have an ID (`DbError::MissingId`)
+    /// - No job application exists with the given ID (`DbError::NotFound`)
+    /// - The stored row was modified after `job.updated_at` (`DbError::Conflict`)
+    /// - The database connection is lost
+    /// - The SQL execution fails
+    /// - Database constraints are violated
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # use job_tracker::model::Status;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let mut job = db.get_job_by_id(1).await?;
+    /// job.status = Status::Interview(2);
+    /// db.update_job(&job).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_job(&self, job: &JobApplication) -> Result<(), DbError> {
+        let id = job.id.ok_or(DbError::MissingId)?;
+        let expected = job.updated_at.map(format_updated_at);
+        let rows_affected = Self::update_row(&self.pool, job, id, expected.as_deref()).await?;
+        self.invalidate_cache();
+
+        if rows_affected == 0 {
+            let exists: bool =
+                sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM job_applications WHERE id = ?)")
+                    .bind(id)
+                    .fetch_one(&self.pool)
+                    .await?;
+            return Err(if exists {
+                DbError::Conflict(id)
+            } else {
+                DbError::NotFound(id)
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Updates only the status of a job application.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the job application to update
+    /// * `status` - The new status
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - No job application exists with the given ID (`DbError::NotFound`)
+    /// - The database connection is lost
+    /// - The SQL execution fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # use job_tracker::model::Status;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// db.update_status(1, &Status::Interview(1)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_status(&self, id: i64, status: &Status) -> Result<(), DbError> {
+        let result = sqlx::query(
+            "UPDATE job_applications SET status = ?, updated_at = strftime('%Y-%m-%d %H:%M:%f', 'now') WHERE id = ?",
+        )
+        .bind(status.to_db_string())
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        self.invalidate_cache();
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound(id));
+        }
+
+        Ok(())
+    }
+
+    /// Sets the status of several job applications in a single transaction.
+    ///
+    /// Either every job is updated or none is: if any ID does not exist the
+    /// transaction is rolled back and the error is returned. An ID listed
+    /// more than once is updated and counted once.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the job applications to update
+    /// * `status` - The new status
+    ///
+    /// # Returns
+    ///
+    /// The number of distinct rows changed.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - No job application exists with one of the IDs (`DbError::NotFound`)
+    /// - The database connection is lost
+    /// - The SQL execution fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # use job_tracker::model::Status;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let changed = db.bulk_update_status(&[1, 2, 3], &Status::Rejected).await?;
+    /// assert_eq!(changed, 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn bulk_update_status(&self, ids: &[i64], status: &Status) -> Result<usize, DbError> {
+        let mut seen = HashSet::new();
+        let mut tx = self.pool.begin().await?;
+        let mut changed = 0;
+        for &id in ids.iter().filter(|id| seen.insert(**id)) {
+            let result = sqlx::query(
+                "UPDATE job_applications SET status = ?, updated_at = strftime('%Y-%m-%d %H:%M:%f', 'now') WHERE id = ?",
+            )
+            .bind(status.to_db_string())
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+            if result.rows_affected() == 0 {
+                return Err(DbError::NotFound(id));
+            }
+            changed += 1;
+        }
+        tx.commit().await?;
+        self.invalidate_cache();
+
+        Ok(changed)
+    }
+
+    /// Archives a job application without deleting it.
+    ///
+    /// Archived jobs are left out of every listing, search, count and
+    /// statistic. Only `get_job_by_id` and `get_archived_jobs` return them,
+    /// and `export_csv` and `export_json` write them so backups keep them.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the job application to archive
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - No job application exists with the given ID (`DbError::NotFound`)
+    /// - The database connection is lost
+    /// - The SQL execution fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// db.archive_job(1).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn archive_job(&self, id: i64) -> Result<(), DbError> {
+        self.set_archived(id, true).await
+    }
+
+    /// Restores an archived job application to `get_all_jobs`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the job application to restore
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - No job application exists with the given ID (`DbError::NotFound`)
+    /// - The database connection is lost
+    /// - The SQL execution fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// db.unarchive_job(1).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unarchive_job(&self, id: i64) -> Result<(), DbError> {
+        self.set_archived(id, false).await
+    }
+
+    async fn set_archived(&self, id: i64, archived: bool) -> Result<(), DbError> {
+        let result = sqlx::query(
+            "UPDATE job_applications SET archived = ?, updated_at = strftime('%Y-%m-%d %H:%M:%f', 'now') WHERE id = ?",
+        )
+        .bind(archived)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        self.invalidate_cache();
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound(id));
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves the archived job applications.
+    ///
+    /// Returns job applications ordered by creation date (most recent first).
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored row cannot be converted to a `JobApplication`
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let archived = db.get_archived_jobs().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_archived_jobs(&self) -> Result<Vec<JobApplication>, DbError> {
+        let rows = sqlx::query(
+            "SELECT * FROM job_applications WHERE archived = 1 ORDER BY created_at DESC, id DESC",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Self::rows_to_job_applications(&rows)
+    }
+
+    /// Retrieves every job application, archived ones included, in
+    /// `get_all_jobs` order. Backups use this so that restoring one does not
+    /// drop the archive.
+    async fn get_every_job(&self) -> Result<Vec<JobApplication>, DbError> {
+        let rows = sqlx::query(
+            "SELECT * FROM job_applications ORDER BY pinned DESC, created_at DESC, id DESC",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Self::rows_to_job_applications(&rows)
+    }
+
+    /// Pins a job application to the top of listings, or unpins it.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the job application to update
+    /// * `pinned` - Whether the job should be pinned
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - No job application exists with the given ID (`DbError::NotFound`)
+    /// - The database connection is lost
+    /// - The SQL execution fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// db.set_pinned(1, true).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_pinned(&self, id: i64, pinned: bool) -> Result<(), DbError> {
+        let result = sqlx::query(
+            "UPDATE job_applications SET pinned = ?, updated_at = strftime('%Y-%m-%d %H:%M:%f', 'now') WHERE id = ?",
+        )
+        .bind(pinned)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        self.invalidate_cache();
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound(id));
+        }
+
+        Ok(())
+    }
+
+    /// Marks a job application as sent on `today`.
+    ///
+    /// Only the application date is changed. `Applied` is the earliest
+    /// status, so every status already counts as at least applied and is
+    /// left as it is.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the job application to update
+    /// * `today` - The date to record as the application date
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - No job application exists with the given ID (`DbError::NotFound`)
+    /// - The database connection is lost
+    /// - The SQL execution fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// db.mark_applied(1, time::OffsetDateTime::now_utc().date()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn mark_applied(&self, id: i64, today: Date) -> Result<(), DbError> {
+        let result = sqlx::query(
+            "UPDATE job_applications SET date = ?, updated_at = strftime('%Y-%m-%d %H:%M:%f', 'now') WHERE id = ?",
+        )
+        .bind(today.to_string())
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        self.invalidate_cache();
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound(id));
+        }
+
+        Ok(())
+    }
+
+    /// Deletes a job application from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the job application to delete
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - No job application exists with the given ID (`DbError::NotFound`)
+    /// - The database connection is lost
+    /// - The SQL execution fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// db.delete_job(1).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_job(&self, id: i64) -> Result<(), DbError> {
+        let result = sqlx::query("DELETE FROM job_applications WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        self.invalidate_cache();
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound(id));
+        }
+
+        Ok(())
+    }
+
+    /// Counts interviews scheduled within the next `within_days` days.
+    ///
+    /// Only jobs with an `Interview` status and an `interview_at` in the
+    /// future (up to and including the end of the window) are counted.
+    ///
+    /// # Arguments
+    ///
+    /// * `within_days` - The size of the look-ahead window in days
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let this_week = db.count_upcoming_interviews(7).await?;
+    /// println!("{this_week} interviews this week");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn count_upcoming_interviews(&self, within_days: u32) -> Result<i64, DbError> {
+        let now = OffsetDateTime::now_utc();
+        let window_end = now + Duration::days(i64::from(within_days));
+
+        let count: i64 = sqlx::query_scalar(
+            r"
+            SELECT COUNT(*) FROM job_applications
+            WHERE archived = 0
+                AND status LIKE 'interview:%' AND interview_at > ? AND interview_at <= ?
+            ",
+        )
+        .bind(format_timestamp(now))
+        .bind(format_timestamp(window_end))
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count)
+    }
+
+    /// Counts the job applications that are not archived.
+    ///
+    /// Together with [`Database::get_jobs_paginated`] this gives the number
+    /// of pages without loading every row.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    pub async fn count_jobs(&self) -> Result<i64, DbError> {
+        let count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM job_applications WHERE archived = 0")
+                .fetch_one(&self.pool)
+                .await?;
+        Ok(count)
+    }
+
+    /// Counts every job application, archived ones included.
+    ///
+    /// This is the number of rows [`Database::clear_all`] deletes.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    pub async fn count_jobs_including_archived(&self) -> Result<i64, DbError> {
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM job_applications")
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(count)
+    }
+
+    /// Computes the expected value of the current pipeline.
+    ///
+    /// Each job contributes the midpoint of its salary range multiplied by the
+    /// probability for its status from `PIPELINE_WEIGHTS`, converted into the
+    /// base currency of `rates`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A job's salary currency has no rate in `rates`
+    ///   (`DbError::MissingExchangeRate`)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::config::CurrencyRates;
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let value = db.expected_pipeline_value(&CurrencyRates::default()).await?;
+    /// println!("Pipeline is worth {value:.0}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn expected_pipeline_value(&self, rates: &CurrencyRates) -> Result<f64, DbError> {
+        let rows = sqlx::query(
+            "SELECT status, salary_min, salary_max, salary_currency FROM job_applications WHERE archived = 0",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter()
+            .map(|row| {
+                let status: String = row.get("status");
+                let salary_min: i64 = row.get("salary_min");
+                let salary_max: i64 = row.get("salary_max");
+                let prefix = status_prefix(&status);
+                let weight = PIPELINE_WEIGHTS
+                    .iter()
+                    .find(|(name, _)| *name == prefix)
+                    .map_or(0.0, |(_, weight)| *weight);
+                let midpoint = f64::from(u32::try_from(salary_min).unwrap_or(0))
+                    .midpoint(f64::from(u32::try_from(salary_max).unwrap_or(0)));
+                let currency: String = row.get("salary_currency");
+                Ok(convert_to_base(rates, midpoint, &currency)? * weight)
+            })
+            .sum()
+    }
+
+    /// Averages the highest interview round reached by job applications that
+    /// became offers.
+    ///
+    /// Uses the status history, so a job that has since been rejected still
+    /// counts if it ever had an offer. A job that went straight to an offer
+    /// counts as zero rounds.
+    ///
+    /// # Returns
+    ///
+    /// The average number of rounds, or `None` if no job ever had an offer.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// if let Some(rounds) = db.average_interview_rounds_for_offers().await? {
+    ///     println!("Offers took {rounds:.1} rounds on average");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn average_interview_rounds_for_offers(&self) -> Result<Option<f64>, DbError> {
+        let average = sqlx::query_scalar(
+            r"
+            SELECT AVG(rounds) FROM (
+                SELECT MAX(
+                    CASE WHEN status LIKE 'interview:%'
+                        THEN CAST(substr(status, length('interview:') + 1) AS INTEGER)
+                        ELSE 0
+                    END
+                ) AS rounds
+                FROM status_history
+                WHERE job_id IN (SELECT id FROM job_applications WHERE archived = 0)
+                GROUP BY job_id
+                HAVING SUM(status LIKE 'offer:%') > 0
+            )
+            ",
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(average)
+    }
+
+    /// Computes the share of job applications that got any response.
+    ///
+    /// A job still in `Applied` has had no response yet; every other status
+    /// counts as one. An empty database has a response rate of 0.
+    ///
+    /// # Returns
+    ///
+    /// The fraction of responded jobs, between 0.0 and 1.0.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let rate = db.response_rate().await?;
+    /// println!("{:.0}% of applications got a response", rate * 100.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn response_rate(&self) -> Result<f64, DbError> {
+        let rate: Option<f64> = sqlx::query_scalar(
+            r"
+            SELECT CAST(SUM(status != 'applied') AS REAL) / COUNT(*)
+            FROM job_applications
+            WHERE archived = 0
+            ",
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(rate.unwrap_or(0.0))
+    }
+
+    /// Summarizes all job applications for a dashboard.
+    ///
+    /// Statuses are grouped by the prefix stored in the database, so every
+    /// interview round and offer amount counts toward `"interview"` and
+    /// `"offer"`. Salaries are averaged in the base currency of `rates`. An
+    /// empty database returns `JobStats::default()`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A job's salary currency has no rate in `rates`
+    ///   (`DbError::MissingExchangeRate`)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::config::CurrencyRates;
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let stats = db.stats(&CurrencyRates::default()).await?;
+    /// println!("{} jobs, {} offers", stats.total, stats.offer_count);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stats(&self, rates: &CurrencyRates) -> Result<JobStats, DbError> {
+        let salaries: Vec<(i64, String)> = sqlx::query_as(
+            "SELECT salary_max, salary_currency FROM job_applications WHERE archived = 0",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut salary_sum = 0.0;
+        for (salary_max, currency) in &salaries {
+            let amount = f64::from(u32::try_from(*salary_max).unwrap_or(0));
+            salary_sum += convert_to_base(rates, amount, currency)?;
+        }
+        let total = salaries.len();
+        let avg_salary_max = if total == 0 {
+            0.0
+        } else {
+            salary_sum / f64::from(u32::try_from(total).unwrap_or(u32::MAX))
+        };
+
+        let rows = sqlx::query(
+            r"
+            SELECT
+                CASE WHEN instr(status, ':') > 0
+                    THEN substr(status, 1, instr(status, ':') - 1)
+                    ELSE status
+                END AS prefix,
+                COUNT(*) AS count
+            FROM job_applications
+            WHERE archived = 0
+            GROUP BY prefix
+            ",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let by_status: HashMap<String, usize> = rows
+            .iter()
+            .map(|row| {
+                let count: i64 = row.get("count");
+                (row.get("prefix"), usize::try_from(count).unwrap_or(0))
+            })
+            .collect();
+
+        Ok(JobStats {
+            total,
+            offer_count: by_status.get("offer").copied().unwrap_or(0),
+            by_status,
+            avg_salary_max,
+        })
+    }
+
+    /// Retrieves offers whose amount is below the advertised salary range.
+    ///
+    /// The amount is taken from the `Offer` status, so only job applications
+    /// with an offer are considered. Returns job applications ordered by
+    /// creation date (most recent first).
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored status string cannot be parsed
+    /// - A stored date cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// for job in db.get_lowball_offers().await? {
+    ///     println!("{} offered below {}", job.company, job.salary.min);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_lowball_offers(&self) -> Result<Vec<JobApplication>, DbError> {
+        let rows = sqlx::query(
+            "SELECT * FROM job_applications WHERE archived = 0 AND status LIKE 'offer:%' ORDER BY created_at DESC, id DESC",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut jobs = Self::rows_to_job_applications(&rows)?;
+        jobs.retain(|job| matches!(job.status, Status::Offer(amount) if amount < job.salary.min));
+        Ok(jobs)
+    }
+
+    /// Averages the salary-range midpoint of the job applications in each
+    /// status.
+    ///
+    /// Averages are in the base currency of `rates`. Statuses without any job
+    /// applications are absent from the map.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A job's salary currency has no rate in `rates`
+    ///   (`DbError::MissingExchangeRate`)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::config::CurrencyRates;
+    /// # use job_tracker::db::Database;
+    /// # use job_tracker::ui::StatusSelection;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let averages = db.average_salary_by_status(&CurrencyRates::default()).await?;
+    /// if let Some(average) = averages.get(&StatusSelection::Interview) {
+    ///     println!("Interviews average {average:.0}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn average_salary_by_status(
+        &self,
+        rates: &CurrencyRates,
+    ) -> Result<HashMap<StatusSelection, f64>, DbError> {
+        let rows = sqlx::query(
+            "SELECT status, salary_min, salary_max, salary_currency FROM job_applications WHERE archived = 0",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut totals: HashMap<StatusSelection, (f64, u32)> = HashMap::new();
+        for row in &rows {
+            let status: String = row.get("status");
+            let prefix = status_prefix(&status);
+            let Some(selection) = StatusSelection::ALL
+                .into_iter()
+                .find(|selection| selection.db_prefix() == prefix)
+            else {
+                continue;
+            };
+            let salary_min: i64 = row.get("salary_min");
+            let salary_max: i64 = row.get("salary_max");
+            let midpoint = f64::from(u32::try_from(salary_min).unwrap_or(0))
+                .midpoint(f64::from(u32::try_from(salary_max).unwrap_or(0)));
+            let currency: String = row.get("salary_currency");
+
+            let entry = totals.entry(selection).or_default();
+            entry.0 += convert_to_base(rates, midpoint, &currency)?;
+            entry.1 += 1;
+        }
+
+        Ok(totals
+            .into_iter()
+            .map(|(selection, (sum, count))| (selection, sum / f64::from(count)))
+            .collect())
+    }
+
+    /// Counts job applications per canonical company name.
+    ///
+    /// Jobs are grouped by `JobApplication::canonical_company`, so "Google"
+    /// and "Google Inc." count together. Results are sorted by count
+    /// (highest first), then by name.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// for (company, count) in db.count_by_canonical_company().await? {
+    ///     println!("{company}: {count}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn count_by_canonical_company(&self) -> Result<Vec<(String, i64)>, DbError> {
+        let rows = sqlx::query(
+            "SELECT company, company_canonical FROM job_applications WHERE archived = 0",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut counts: HashMap<String, i64> = HashMap::new();
+        for row in &rows {
+            let company: String = row.get("company");
+            let canonical: Option<String> = row.get("company_canonical");
+            let key = canonical.unwrap_or_else(|| canonicalize_company(&company));
+            *counts.entry(key).or_default() += 1;
+        }
+
+        let mut counts: Vec<(String, i64)> = counts.into_iter().collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        Ok(counts)
+    }
+
+    /// Counts how often each rejection reason was recorded.
+    ///
+    /// Jobs without a reason are excluded. Results are ordered by frequency
+    /// (highest first), then alphabetically.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// for (reason, count) in db.rejection_reason_counts().await? {
+    ///     println!("{reason}: {count}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn rejection_reason_counts(&self) -> Result<Vec<(String, i64)>, DbError> {
+        let counts = sqlx::query_as(
+            r"
+            SELECT rejection_reason, COUNT(*) AS count
+            FROM job_applications
+            WHERE archived = 0 AND rejection_reason IS NOT NULL
+            GROUP BY rejection_reason
+            ORDER BY count DESC, rejection_reason
+            ",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(counts)
+    }
+
+    /// Counts job applications per `salary_max` bucket.
+    ///
+    /// Each bucket is identified by its start, a multiple of `bucket_size`,
+    /// and covers `start..start + bucket_size`. Only non-empty buckets are
+    /// returned, ordered by bucket start.
+    ///
+    /// # Arguments
+    ///
+    /// * `bucket_size` - The width of each salary bucket
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - `bucket_size` is zero (`DbError::InvalidArgument`)
+    /// - The database connection is lost
+    /// - The SQL query fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// for (start, count) in db.salary_histogram(10_000).await? {
+    ///     println!("{start}+: {count}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn salary_histogram(&self, bucket_size: u32) -> Result<Vec<(u32, i64)>, DbError> {
+        if bucket_size == 0 {
+            return Err(DbError::InvalidArgument(
+                "bucket size must be greater than zero".to_string(),
+            ));
+        }
+
+        let buckets = sqlx::query_as(
+            r"
+            SELECT (salary_max / ?1) * ?1 AS bucket, COUNT(*) AS count
+            FROM job_applications
+            WHERE archived = 0
+            GROUP BY bucket
+            ORDER BY bucket
+            ",
+        )
+        .bind(i64::from(bucket_size))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(buckets)
+    }
+
+    /// Counts dated job applications sent on a Saturday or Sunday.
+    ///
+    /// The weekday is computed from the parsed application date; jobs without
+    /// a date are not counted.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored date cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let weekend = db.count_weekend_applications().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn count_weekend_applications(&self) -> Result<i64, DbError> {
+        let dates: Vec<String> = sqlx::query_scalar(
+            "SELECT date FROM job_applications WHERE archived = 0 AND date IS NOT NULL",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut count = 0;
+        for date in dates {
+            if matches!(
+                parse_date(&date)?.weekday(),
+                time::Weekday::Saturday | time::Weekday::Sunday
+            ) {
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Writes every job application to a CSV file at `path`.
+    ///
+    /// The file starts with the header row
+    /// `company,position,location,status,salary_min,salary_max,salary_currency,date,cv_path,archived`
+    /// followed by one row per job, archived jobs included, in
+    /// [`Database::get_all_jobs`] order. Statuses use their stored form (e.g.
+    /// `interview:2`), dates are `YYYY-MM-DD`, a missing date or CV is an
+    /// empty field, `salary_currency` is the ISO 4217 code, and `archived` is
+    /// `true` or `false`. Records end with CRLF and fields containing commas,
+    /// quotes, or line breaks are quoted per RFC 4180. Parent directories are
+    /// created as needed and an existing file is overwritten.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Where to write the CSV file
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored status string cannot be parsed
+    /// - The file cannot be created or written (`DbError::Io`)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # use std::path::Path;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// db.export_csv(Path::new("data/jobs.csv")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn export_csv(&self, path: &Path) -> Result<(), DbError> {
+        let jobs = self.get_every_job().await?;
+
+        let mut csv = String::from(CSV_HEADER);
+        csv.push_str("\r\n");
+        for job in &jobs {
+            let fields = [
+                job.company.clone(),
+                job.position.clone(),
+                job.location.clone(),
+                job.status.to_db_string(),
+                job.salary.min.to_string(),
+                job.salary.max.to_string(),
+                job.salary.currency.clone(),
+                job.date.map(|date| date.to_string()).unwrap_or_default(),
+                job.cv
+                    .as_ref()
+                    .map(|cv| cv.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+                job.archived.to_string(),
+            ];
+            let record = fields
+                .iter()
+                .map(|field| quote_csv_field(field))
+                .collect::<Vec<_>>()
+                .join(",");
+            csv.push_str(&record);
+            csv.push_str("\r\n");
+        }
+
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, csv)?;
+        Ok(())
+    }
+
+    /// Imports job applications from a CSV file in the `export_csv` format.
+    ///
+    /// The file must start with the same header row as `export_csv`
+    /// writes; RFC 4180 quoting and both CRLF and LF line endings are
+    /// accepted. Every row is inserted as a new job, even if it matches an
+    /// existing one. The file is validated before anything is inserted, so a
+    /// bad row leaves the database unchanged. An empty file imports nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The CSV file to read
+    ///
+    /// # Returns
+    ///
+    /// The number of job applications inserted.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The file cannot be read (`DbError::Io`)
+    /// - The header, a status, salary, currency, date, or archived flag is
+    ///   invalid, or a row has the wrong number of fields
+    ///   (`DbError::ImportParse`, with the line the record starts on)
+    /// - The database connection is lost
+    /// - The SQL query fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # use std::path::Path;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let imported = db.import_csv(Path::new("data/jobs.csv")).await?;
+    /// println!("Imported {imported} jobs");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn import_csv(&self, path: &Path) -> Result<usize, DbError> {
+        let contents = std::fs::read_to_string(path)?;
+        let mut records = parse_csv(&contents)?.into_iter();
+
+        let Some((_, header)) = records.next() else {
+            return Ok(0);
+        };
+        if header.join(",") != CSV_HEADER {
+            return Err(DbError::ImportParse {
+                line: 1,
+                reason: format!("expected header `{CSV_HEADER}`"),
+            });
+        }
+
+        let jobs = records
+            .map(|(line, fields)| {
+                csv_record_to_job(&fields).map_err(|reason| DbError::ImportParse { line, reason })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let summary = self.import_jobs(&jobs, ImportStrategy::Duplicate).await?;
+        Ok(summary.inserted)
+    }
+
+    /// Writes every job application to a pretty-printed JSON file at `path`.
+    ///
+    /// The jobs, archived ones included, in [`Database::get_all_jobs`] order,
+    /// are wrapped in a versioned envelope, `{"version": 1, "jobs": [...]}`, so
+    /// [`Database::import_json`] can check compatibility. Parent directories
+    /// are created as needed and an existing file is overwritten.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Where to write the JSON file
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored status string cannot be parsed
+    /// - The file cannot be created or written (`DbError::Io`)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # use std::path::Path;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// db.export_json(Path::new("data/backup.json")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "serde")]
+    pub async fn export_json(&self, path: &Path) -> Result<(), DbError> {
+        let jobs = self.get_every_job().await?;
+        let export = serde_json::json!({
+            "version": JSON_EXPORT_VERSION,
+            "jobs": jobs,
+        });
+        let json = serde_json::to_string_pretty(&export)?;
+
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Imports job applications from a JSON file written by `export_json`.
+    ///
+    /// Both the versioned envelope and the bare array of older exports
+    /// (version 0) are accepted. The whole file is parsed before the
+    /// database is touched, and the import runs in one transaction, so a
+    /// corrupt file or failed insert leaves the existing jobs unchanged.
+    ///
+    /// By default the jobs are added next to the existing ones with new ids.
+    /// With `replace`, the stored jobs are swapped for the imported ones with
+    /// [`Database::replace_all`]: imported jobs keep their ids and jobs missing
+    /// from the file are deleted.
+    ///
+    /// The file holds only the jobs themselves, not their status history,
+    /// tags, reminders, or checklists. A replaced job whose id is still stored
+    /// keeps that related data; every other imported job starts without it, so
+    /// restoring into an empty database brings back the jobs alone.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The JSON file to read
+    /// * `replace` - Whether the file replaces the stored jobs
+    ///
+    /// # Returns
+    ///
+    /// The number of job applications inserted.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The file cannot be read (`DbError::Io`)
+    /// - The file is not valid JSON, its version is not a whole number, or it
+    ///   is not a list of jobs (`DbError::Json`)
+    /// - The envelope has a version newer than `JSON_EXPORT_VERSION`
+    ///   (`DbError::UnsupportedVersion`)
+    /// - The database connection is lost
+    /// - Two jobs share the same id with `replace` (`DbError::InvalidArgument`)
+    /// - The SQL execution fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # use std::path::Path;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let restored = db.import_json(Path::new("data/backup.json"), true).await?;
+    /// println!("Restored {restored} jobs");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "serde")]
+    pub async fn import_json(&self, path: &Path, replace: bool) -> Result<usize, DbError> {
+        let contents = std::fs::read_to_string(path)?;
+        let value: serde_json::Value = serde_json::from_str(&contents)?;
+
+        let jobs_value = if value.is_array() {
+            value
+        } else {
+            let version = match value.get("version") {
+                Some(version) => serde_json::from_value(version.clone())?,
+                None => 0,
+            };
+            if version > JSON_EXPORT_VERSION {
+                return Err(DbError::UnsupportedVersion(version));
+            }
+            value.get("jobs").cloned().unwrap_or_default()
+        };
+        let jobs: Vec<JobApplication> = serde_json::from_value(jobs_value)?;
+
+        if replace {
+            self.replace_all(&jobs).await?;
+            Ok(jobs.len())
+        } else {
+            let summary = self.import_jobs(&jobs, ImportStrategy::Duplicate).await?;
+            Ok(summary.inserted)
+        }
+    }
+
+    /// Writes interviews and offer deadlines as an iCalendar (RFC 5545) file.
+    ///
+    /// Each job in the `Interview` status with an `interview_at` produces one
+    /// `VEVENT`, and each job in the `Offer` status with an `offer_deadline`
+    /// another, ordered by start time. Times left over from an earlier status
+    /// are skipped. Times are written in UTC and text values are escaped and
+    /// folded per the spec.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Where the calendar is written, e.g. a file
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored status string cannot be parsed
+    /// - Writing to `writer` fails (`DbError::Io`)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let file = std::fs::File::create("data/interviews.ics")?;
+    /// db.export_ics(std::io::BufWriter::new(file)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn export_ics(&self, mut writer: impl Write) -> Result<(), DbError> {
+        let jobs = self.get_all_jobs().await?;
+
+        let mut events: Vec<(OffsetDateTime, &str, String, &JobApplication)> = Vec::new();
+        for job in &jobs {
+            if let Some(at) = job.interview_at
+                && matches!(job.status, Status::Interview(_))
+            {
+                let summary = format!("Interview: {} at {}", job.position, job.company);
+                events.push((at, "interview", summary, job));
+            }
+            if let Some(deadline) = job.offer_deadline
+                && matches!(job.status, Status::Offer(_))
+            {
+                let summary = format!("Offer deadline: {} at {}", job.position, job.company);
+                events.push((deadline, "offer-deadline", summary, job));
+            }
+        }
+        events.sort_by_key(|(start, ..)| *start);
+
+        let stamp = format_ics_timestamp(OffsetDateTime::now_utc());
+        write_ics_line(&mut writer, "BEGIN:VCALENDAR")?;
+        write_ics_line(&mut writer, "VERSION:2.0")?;
+        write_ics_line(&mut writer, "PRODID:-//job_tracker//Job Tracker//EN")?;
+        for (start, kind, summary, job) in events {
+            write_ics_line(&mut writer, "BEGIN:VEVENT")?;
+            write_ics_line(
+                &mut writer,
+                &format!("UID:job-{}-{kind}@job_tracker", job.id.unwrap_or(0)),
+            )?;
+            write_ics_line(&mut writer, &format!("DTSTAMP:{stamp}"))?;
+            write_ics_line(
+                &mut writer,
+                &format!("DTSTART:{}", format_ics_timestamp(start)),
+            )?;
+            write_ics_line(
+                &mut writer,
+                &format!("SUMMARY:{}", escape_ics_text(&summary)),
+            )?;
+            if !job.location.is_empty() {
+                write_ics_line(
+                    &mut writer,
+                    &format!("LOCATION:{}", escape_ics_text(&job.location)),
+                )?;
+            }
+            write_ics_line(&mut writer, "END:VEVENT")?;
+        }
+        write_ics_line(&mut writer, "END:VCALENDAR")?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Retrieves the job applications whose company matches a regular
+    /// expression.
+    ///
+    /// The pattern uses the `regex` crate's syntax and matches anywhere in
+    /// the company name unless anchored. Requires the `regex` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The regular expression to match company names against
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The pattern is not a valid regular expression (`DbError::InvalidPattern`)
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored status string cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let jobs = db.search_company_regex(r"(?i)^(google|alphabet)\b").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "regex")]
+    pub async fn search_company_regex(
+        &self,
+        pattern: &str,
+    ) -> Result<Vec<JobApplication>, DbError> {
+        let regex =
+            regex::Regex::new(pattern).map_err(|e| DbError::InvalidPattern(e.to_string()))?;
+
+        let mut jobs = self.get_all_jobs().await?;
+        jobs.retain(|job| regex.is_match(&job.company));
+        Ok(jobs)
+    }
+
+    /// Retrieves the job applications whose company, position, or location
+    /// contains `query`.
+    ///
+    /// Unlike [`Database::search_all`], only the columns shown in the job
+    /// list are searched. Matching is case-insensitive, and `%`, `_`, and `\`
+    /// in `query` match literally. Returns pinned jobs first, then the rest
+    /// by creation date (most recent first), or an empty vector if none match.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The keyword to search for
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored status string cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let jobs = db.search_jobs("rust").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_jobs(&self, query: &str) -> Result<Vec<JobApplication>, DbError> {
+        let pattern = format!("%{}%", escape_like(query));
+        let rows = sqlx::query(
+            r"
+            SELECT * FROM job_applications
+            WHERE archived = 0 AND (
+                LOWER(company) LIKE LOWER(?1) ESCAPE '\'
+                OR LOWER(position) LIKE LOWER(?1) ESCAPE '\'
+                OR LOWER(location) LIKE LOWER(?1) ESCAPE '\'
+            )
+            ORDER BY pinned DESC, created_at DESC, id DESC
+            ",
+        )
+        .bind(pattern)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Self::rows_to_job_applications(&rows)
+    }
+
+    /// Retrieves the job applications where any text column contains `query`.
+    ///
+    /// Matching is case-insensitive for ASCII and searches company, position,
+    /// location, CV path, canonical company, rejection reason, notes, contact
+    /// name and email, and URL. `%`, `_`, and `\` in `query` match literally.
+    /// An empty query returns every job. Pinned jobs come first, then the
+    /// rest by creation date (most recent first).
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The text to search for
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored status string cannot be parsed
+    /// - A stored date cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let jobs = db.search_all("remote").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_all(&self, query: &str) -> Result<Vec<JobApplication>, DbError> {
+        let pattern = format!("%{}%", escape_like(query));
+        let conditions = SEARCH_COLUMNS
+            .iter()
+            .map(|column| format!("{column} LIKE ? ESCAPE '\\'"))
+            .collect::<Vec<_>>()
+            .join(" OR ");
+        let sql = format!(
+            "SELECT * FROM job_applications WHERE archived = 0 AND ({conditions}) ORDER BY pinned DESC, created_at DESC, id DESC"
+        );
+
+        let mut query = sqlx::query(&sql);
+        for _ in SEARCH_COLUMNS {
+            query = query.bind(&pattern);
+        }
+        let rows = query.fetch_all(&self.pool).await?;
+
+        Self::rows_to_job_applications(&rows)
+    }
+
+    /// Retrieves all job applications ordered by how close their salary-range
+    /// midpoint is to `target`, closest first.
+    ///
+    /// Jobs equally far from the target keep the most recently added first.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The salary to compare against
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored status string cannot be parsed
+    /// - A stored date cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let closest = db.get_jobs_near_salary(100_000).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_jobs_near_salary(&self, target: u32) -> Result<Vec<JobApplication>, DbError> {
+        let rows = sqlx::query(
+            r"
+            SELECT * FROM job_applications
+            WHERE archived = 0
+            ORDER BY ABS((salary_min + salary_max) / 2.0 - ?), created_at DESC, id DESC
+            ",
+        )
+        .bind(i64::from(target))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Self::rows_to_job_applications(&rows)
+    }
+
+    /// Retrieves job applications whose row was created between `start` and
+    /// `end`, inclusive.
+    ///
+    /// This filters on the database's `created_at` audit timestamp rather than
+    /// the application date. `created_at` has whole-second precision, so
+    /// sub-second parts of the bounds are ignored. Returns job applications
+    /// ordered by creation date (most recent first).
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The earliest creation time to include
+    /// * `end` - The latest creation time to include
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored status string cannot be parsed
+    /// - A stored date or timestamp cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # use time::{Duration, OffsetDateTime};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let now = OffsetDateTime::now_utc();
+    /// let this_week = db.get_jobs_created_between(now - Duration::days(7), now).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_jobs_created_between(
+        &self,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+    ) -> Result<Vec<JobApplication>, DbError> {
+        let rows = sqlx::query(
+            "SELECT * FROM job_applications WHERE archived = 0 AND created_at BETWEEN ? AND ? ORDER BY created_at DESC, id DESC",
+        )
+        .bind(format_timestamp(start))
+        .bind(format_timestamp(end))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Self::rows_to_job_applications(&rows)
+    }
+
+    /// Retrieves job applications without an application date, so they can
+    /// be backfilled.
+    ///
+    /// Returns job applications ordered by creation date (most recent first).
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored status string cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let undated = db.get_jobs_missing_date().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_jobs_missing_date(&self) -> Result<Vec<JobApplication>, DbError> {
+        let rows = sqlx::query(
+            "SELECT * FROM job_applications WHERE archived = 0 AND date IS NULL ORDER BY created_at DESC, id DESC",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Self::rows_to_job_applications(&rows)
+    }
+
+    /// Retrieves job applications whose CV path matches a SQL `LIKE` pattern.
+    ///
+    /// `%` matches any run of characters and `_` a single character, so
+    /// `"%backend%"` finds every job sent with a backend CV. Matching is
+    /// case-insensitive for ASCII. Jobs without a CV never match.
+    ///
+    /// Returns job applications ordered by creation date (most recent first).
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The `LIKE` pattern matched against the stored CV path
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored status string cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let backend = db.get_jobs_by_cv_pattern("%backend%").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_jobs_by_cv_pattern(
+        &self,
+        pattern: &str,
+    ) -> Result<Vec<JobApplication>, DbError> {
+        let rows = sqlx::query(
+            r"
+            SELECT * FROM job_applications
+            WHERE archived = 0 AND cv_path IS NOT NULL AND cv_path LIKE ?
+            ORDER BY created_at DESC, id DESC
+            ",
+        )
+        .bind(pattern)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Self::rows_to_job_applications(&rows)
+    }
+
+    /// Retrieves the IDs of the job applications at `company`.
+    ///
+    /// The company name is matched exactly but case-insensitively. Only the
+    /// `id` column is read, so this is cheap to run before a bulk operation.
+    /// IDs are returned in ascending order.
+    ///
+    /// # Arguments
+    ///
+    /// * `company` - The company name to match
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let ids = db.get_ids_by_company("techcorp").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_ids_by_company(&self, company: &str) -> Result<Vec<i64>, DbError> {
+        let ids = sqlx::query_scalar(
+            "SELECT id FROM job_applications WHERE archived = 0 AND company = ? COLLATE NOCASE ORDER BY id",
+        )
+        .bind(company)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(ids)
+    }
+
+    /// Retrieves the job applications at `company`.
+    ///
+    /// The company name is matched exactly but case-insensitively, so
+    /// "acme" finds jobs stored as "Acme". Returns job applications ordered
+    /// by creation date (most recent first).
+    ///
+    /// # Arguments
+    ///
+    /// * `company` - The company name to match
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored row cannot be converted to a `JobApplication`
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let already_tracked = !db.get_jobs_by_company("Acme").await?.is_empty();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_jobs_by_company(&self, company: &str) -> Result<Vec<JobApplication>, DbError> {
+        let rows = sqlx::query(
+            "SELECT * FROM job_applications WHERE archived = 0 AND company = ? COLLATE NOCASE ORDER BY created_at DESC, id DESC",
+        )
+        .bind(company)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Self::rows_to_job_applications(&rows)
+    }
+
+    /// Retrieves every job application except the one with `exclude_id`.
+    ///
+    /// Returns job applications ordered by creation date (most recent first).
+    /// An `exclude_id` that matches no job returns every job.
+    ///
+    /// # Arguments
+    ///
+    /// * `exclude_id` - The ID of the job application to leave out
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored status string cannot be parsed
+    /// - A stored date cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let others = db.get_other_jobs(1).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_other_jobs(&self, exclude_id: i64) -> Result<Vec<JobApplication>, DbError> {
+        let rows =
+            sqlx::query("SELECT * FROM job_applications WHERE archived = 0 AND id != ? ORDER BY created_at DESC")
+                .bind(exclude_id)
+                .fetch_all(&self.pool)
+                .await?;
+
+        Self::rows_to_job_applications(&rows)
+    }
+
+    /// Retrieves the job applications whose application date falls in `year`.
+    ///
+    /// Jobs without a date are never returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The calendar year to match
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored status string cannot be parsed
+    /// - A stored date cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let jobs = db.get_jobs_by_year(2024).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_jobs_by_year(&self, year: i32) -> Result<Vec<JobApplication>, DbError> {
+        let rows = sqlx::query(
+            r"
+            SELECT * FROM job_applications
+            WHERE archived = 0 AND date IS NOT NULL AND strftime('%Y', date) = ?
+            ORDER BY date DESC
+            ",
+        )
+        .bind(format!("{year:04}"))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Self::rows_to_job_applications(&rows)
+    }
+
+    /// Groups dated job applications by the month they were sent.
+    ///
+    /// Keys are `"YYYY-MM"`, so the map iterates in chronological order.
+    /// Within a month, jobs are ordered by date (earliest first). Jobs without
+    /// a date are left out.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored status string cannot be parsed
+    /// - A stored date cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// for (month, jobs) in db.get_jobs_grouped_by_month().await? {
+    ///     println!("{month}: {} applications", jobs.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_jobs_grouped_by_month(
+        &self,
+    ) -> Result<BTreeMap<String, Vec<JobApplication>>, DbError> {
+        let rows =
+            sqlx::query("SELECT * FROM job_applications WHERE archived = 0 AND date IS NOT NULL ORDER BY date, id")
+                .fetch_all(&self.pool)
+                .await?;
+
+        let mut months: BTreeMap<String, Vec<JobApplication>> = BTreeMap::new();
+        for job in Self::rows_to_job_applications(&rows)? {
+            let Some(date) = job.date else { continue };
+            let key = format!("{:04}-{:02}", date.year(), u8::from(date.month()));
+            months.entry(key).or_default().push(job);
+        }
+        Ok(months)
+    }
+
+    /// Counts dated job applications per month and status kind.
+    ///
+    /// Keys are `"YYYY-MM"` from the application date, so the map iterates in
+    /// chronological order. Within a month, statuses without jobs are absent.
+    /// Jobs without a date are left out.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # use job_tracker::ui::StatusSelection;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// for (month, counts) in db.status_counts_by_month().await? {
+    ///     let offers = counts.get(&StatusSelection::Offer).copied().unwrap_or(0);
+    ///     println!("{month}: {offers} offers");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn status_counts_by_month(
+        &self,
+    ) -> Result<BTreeMap<String, HashMap<StatusSelection, i64>>, DbError> {
+        let rows: Vec<(String, String)> = sqlx::query_as(
+            r"
+            SELECT strftime('%Y-%m', date) AS month, status
+            FROM job_applications
+            WHERE archived = 0 AND date IS NOT NULL
+            ",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut months: BTreeMap<String, HashMap<StatusSelection, i64>> = BTreeMap::new();
+        for (month, status) in rows {
+            let prefix = status_prefix(&status);
+            let Some(selection) = StatusSelection::ALL
+                .into_iter()
+                .find(|selection| selection.db_prefix() == prefix)
+            else {
+                continue;
+            };
+            *months
+                .entry(month)
+                .or_default()
+                .entry(selection)
+                .or_default() += 1;
+        }
+        Ok(months)
+    }
+
+    /// Retrieves the job applications that were added or modified on `today`.
+    ///
+    /// Every insert and update stamps the row's `updated_at` column (UTC, with
+    /// millisecond precision), so this covers status changes as well as edits.
+    ///
+    /// # Arguments
+    ///
+    /// * `today` - The UTC calendar day to match
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored status string cannot be parsed
+    /// - A stored date cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let today = time::OffsetDateTime::now_utc().date();
+    /// let changed = db.jobs_changed_today(today).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn jobs_changed_today(&self, today: Date) -> Result<Vec<JobApplication>, DbError> {
+        let rows = sqlx::query(
+            "SELECT * FROM job_applications WHERE archived = 0 AND date(updated_at) = ? ORDER BY updated_at DESC",
+        )
+        .bind(today.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Self::rows_to_job_applications(&rows)
+    }
+
+    /// Adds a reminder for a job application.
+    ///
+    /// Reminders are deleted together with their job application.
+    ///
+    /// # Arguments
+    ///
+    /// * `job_id` - The ID of the job application the reminder belongs to
+    /// * `remind_at` - When the reminder becomes due
+    /// * `message` - The text to show
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - No job application exists with the given ID
+    /// - The database connection is lost
+    /// - The SQL execution fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let tomorrow = time::OffsetDateTime::now_utc() + time::Duration::days(1);
+    /// db.add_reminder(1, tomorrow, "Send thank-you email").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_reminder(
+        &self,
+        job_id: i64,
+        remind_at: OffsetDateTime,
+        message: &str,
+    ) -> Result<i64, DbError> {
+        let result =
+            sqlx::query("INSERT INTO reminders (job_id, remind_at, message) VALUES (?, ?, ?)")
+                .bind(job_id)
+                .bind(format_timestamp(remind_at))
+                .bind(message)
+                .execute(&self.pool)
+                .await?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    /// Retrieves the reminders due at or before `now`, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored timestamp cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let due = db.get_due_reminders(time::OffsetDateTime::now_utc()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_due_reminders(&self, now: OffsetDateTime) -> Result<Vec<Reminder>, DbError> {
+        let rows =
+            sqlx::query("SELECT * FROM reminders WHERE remind_at <= ? ORDER BY remind_at, id")
+                .bind(format_timestamp(now))
+                .fetch_all(&self.pool)
+                .await?;
+
+        rows.iter()
+            .map(|row| {
+                let remind_at: String = row.get("remind_at");
+                Ok(Reminder {
+                    id: Some(row.get("id")),
+                    job_id: row.get("job_id"),
+                    remind_at: parse_timestamp(&remind_at)?,
+                    message: row.get("message"),
+                })
+            })
+            .collect()
+    }
+
+    /// Deletes a reminder, e.g. when the user dismisses it.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the reminder to delete
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - No reminder exists with the given ID (`DbError::NotFound`)
+    /// - The database connection is lost
+    /// - The SQL execution fails
+    pub async fn delete_reminder(&self, id: i64) -> Result<(), DbError> {
+        let result = sqlx::query("DELETE FROM reminders WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound(id));
+        }
+
+        Ok(())
+    }
+
+    /// Adds an open checklist item to a job application.
+    ///
+    /// Checklist items are deleted together with their job application.
+    ///
+    /// # Arguments
+    ///
+    /// * `job_id` - The ID of the job application the item belongs to
+    /// * `text` - A short description of the sub-task
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - No job application exists with the given ID
+    /// - The database connection is lost
+    /// - The SQL execution fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// db.add_checklist_item(1, "Tailor resume").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_checklist_item(&self, job_id: i64, text: &str) -> Result<i64, DbError> {
+        let result = sqlx::query("INSERT INTO checklist_items (job_id, text) VALUES (?, ?)")
+            .bind(job_id)
+            .bind(text)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    /// Retrieves a job application's checklist items in the order they were
+    /// added.
+    ///
+    /// # Arguments
+    ///
+    /// * `job_id` - The ID of the job application
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    pub async fn get_checklist_items(&self, job_id: i64) -> Result<Vec<ChecklistItem>, DbError> {
+        let rows = sqlx::query("SELECT * FROM checklist_items WHERE job_id = ? ORDER BY id")
+            .bind(job_id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows
+            .iter()
+            .map(|row| ChecklistItem {
+                id: Some(row.get("id")),
+                job_id: row.get("job_id"),
+                text: row.get("text"),
+                done: row.get("done"),
+            })
+            .collect())
+    }
+
+    /// Marks a checklist item as done or not done.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the checklist item
+    /// * `done` - The new completion state
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - No checklist item exists with the given ID (`DbError::NotFound`)
+    /// - The database connection is lost
+    /// - The SQL execution fails
+    pub async fn set_checklist_item_done(&self, id: i64, done: bool) -> Result<(), DbError> {
+        let result = sqlx::query("UPDATE checklist_items SET done = ? WHERE id = ?")
+            .bind(done)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound(id));
+        }
+
+        Ok(())
+    }
+
+    /// Deletes a checklist item.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the checklist item
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - No checklist item exists with the given ID (`DbError::NotFound`)
+    /// - The database connection is lost
+    /// - The SQL execution fails
+    pub async fn delete_checklist_item(&self, id: i64) -> Result<(), DbError> {
+        let result = sqlx::query("DELETE FROM checklist_items WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound(id));
+        }
+
+        Ok(())
+    }
+
+    /// Labels a job application with a tag, creating the tag if needed.
+    ///
+    /// Tag names match exactly, so "Remote" and "remote" are different tags.
+    /// Adding a tag the job already has does nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `job_id` - The ID of the job application to tag
+    /// * `tag` - The tag name, e.g. "remote"
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - No job application exists with the given ID
+    /// - The database connection is lost
+    /// - The SQL execution fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// db.add_tag(1, "dream-job").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_tag(&self, job_id: i64, tag: &str) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("INSERT INTO tags (name) VALUES (?) ON CONFLICT(name) DO NOTHING")
+            .bind(tag)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query(
+            "INSERT OR IGNORE INTO job_tags (job_id, tag_id) SELECT ?, id FROM tags WHERE name = ?",
+        )
+        .bind(job_id)
+        .bind(tag)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Removes a tag from a job application.
+    ///
+    /// The tag itself is deleted once no job uses it any more. Removing a tag
+    /// the job doesn't have does nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `job_id` - The ID of the job application
+    /// * `tag` - The tag name to remove
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL execution fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// db.remove_tag(1, "dream-job").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_tag(&self, job_id: i64, tag: &str) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            "DELETE FROM job_tags WHERE job_id = ? AND tag_id IN (SELECT id FROM tags WHERE name = ?)",
+        )
+        .bind(job_id)
+        .bind(tag)
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query("DELETE FROM tags WHERE name = ? AND id NOT IN (SELECT tag_id FROM job_tags)")
+            .bind(tag)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Retrieves a job application's tags, sorted alphabetically.
+    ///
+    /// # Arguments
+    ///
+    /// * `job_id` - The ID of the job application
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let tags = db.get_tags(1).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_tags(&self, job_id: i64) -> Result<Vec<String>, DbError> {
+        let tags = sqlx::query_scalar(
+            "SELECT tags.name FROM tags JOIN job_tags ON job_tags.tag_id = tags.id WHERE job_tags.job_id = ? ORDER BY tags.name",
+        )
+        .bind(job_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(tags)
+    }
+
+    /// Retrieves the job applications labelled with `tag`.
+    ///
+    /// Returns job applications ordered by creation date (most recent first).
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - The tag name to match exactly
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored row cannot be converted to a `JobApplication`
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let remote = db.get_jobs_with_tag("remote").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_jobs_with_tag(&self, tag: &str) -> Result<Vec<JobApplication>, DbError> {
+        let rows = sqlx::query(
+            r"
+            SELECT * FROM job_applications
+            WHERE archived = 0 AND id IN (
+                SELECT job_tags.job_id FROM job_tags
+                JOIN tags ON tags.id = job_tags.tag_id
+                WHERE tags.name = ?
+            )
+            ORDER BY created_at DESC, id DESC
+            ",
+        )
+        .bind(tag)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Self::rows_to_job_applications(&rows)
+    }
+
+    /// Retrieves every distinct non-empty location, sorted alphabetically.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL execution fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let locations = db.get_distinct_locations().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_distinct_locations(&self) -> Result<Vec<String>, DbError> {
+        let locations = sqlx::query_scalar(
+            "SELECT DISTINCT location FROM job_applications WHERE archived = 0 AND location != '' ORDER BY location",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(locations)
+    }
+
+    /// Retrieves an application setting stored with `set_setting`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The name of the setting
+    ///
+    /// # Returns
+    ///
+    /// The stored value, or `None` if the setting was never set.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// let theme = db.get_setting("theme").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_setting(&self, key: &str) -> Result<Option<String>, DbError> {
+        let value: Option<Option<String>> =
+            sqlx::query_scalar("SELECT value FROM app_settings WHERE key = ?")
+                .bind(key)
+                .fetch_optional(&self.pool)
+                .await?;
+
+        Ok(value.flatten())
+    }
+
+    /// Stores an application setting, replacing any previous value.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The name of the setting
+    /// * `value` - The value to store
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL execution fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// db.set_setting("theme", "dark").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_setting(&self, key: &str, value: &str) -> Result<(), DbError> {
+        sqlx::query(
+            r"
+            INSERT INTO app_settings (key, value) VALUES (?, ?)
+            ON CONFLICT(key) DO UPDATE SET value = excluded.value
+            ",
+        )
+        .bind(key)
+        .bind(value)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Clears all job applications from the database.
+    ///
+    /// This operation is irreversible and will remove all stored job applications,
+    /// archived ones included.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL execution fails
+    /// - Database constraints prevent deletion
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// db.clear_all().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn clear_all(&self) -> Result<(), DbError> {
+        sqlx::query("DELETE FROM job_applications")
+            .execute(&self.pool)
+            .await?;
+        self.invalidate_cache();
+        Ok(())
+    }
+
+    /// Closes the database connection pool.
+    ///
+    /// This gracefully closes all database connections in the pool.
+    /// After calling this method, no further database operations should
+    /// be performed on this instance.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if there are issues closing
+    /// the connection pool, though in practice this is rare.
+    pub async fn close(&self) -> Result<(), DbError> {
+        self.pool.close().await;
+        Ok(())
+    }
+
+    /// Drops any cached `get_all_jobs_cached` result so the next read hits the database.
+    fn invalidate_cache(&self) {
+        if let Some(cache) = &self.cache
+            && let Ok(mut cached) = cache.write()
+        {
+            cached.jobs = None;
+            cached.generation = cached.generation.wrapping_add(1);
+        }
+    }
+
+    /// Caches `jobs` read while the cache was at `generation`, unless a write
+    /// has invalidated it since.
+    fn store_cache(&self, generation: u64, jobs: &[JobApplication]) {
+        if let Some(cache) = &self.cache
+            && let Ok(mut cached) = cache.write()
+            && cached.generation == generation
+        {
+            cached.jobs = Some(jobs.to_vec());
+        }
+    }
+
+    /// Overwrites the row with `id` using `executor`, returning the number of
+    /// rows affected.
+    ///
+    /// With `expected_updated_at`, only a row still stamped with that value is
+    /// overwritten.
+    async fn update_row<'e, E>(
+        executor: E,
+        job: &JobApplication,
+        id: i64,
+        expected_updated_at: Option<&str>,
+    ) -> Result<u64, DbError>
+    where
+        E: sqlx::SqliteExecutor<'e>,
+    {
+        let date_str = job.date.map(|d| d.to_string());
+        let cv_path_str = job.cv.as_ref().map(|p| p.to_string_lossy().to_string());
+        let status_str = job.status.to_db_string();
+        let interview_at_str = job.interview_at.map(format_timestamp);
+        let offer_deadline_str = job.offer_deadline.map(format_timestamp);
+
+        let result = sqlx::query(
+            r"
+            UPDATE job_applications
+            SET date = ?, cv_path = ?, company = ?, position = ?, status = ?, location = ?, salary_min = ?, salary_max = ?,
+                salary_currency = ?, interview_at = ?,
+                offer_deadline = ?, company_canonical = ?, rejection_reason = ?, notes = ?, contact_name = ?,
+                contact_email = ?, url = ?, pinned = ?, archived = ?, priority = ?, follow_up = ?,
+                updated_at = strftime('%Y-%m-%d %H:%M:%f', 'now')
+            WHERE id = ? AND (? IS NULL OR updated_at = ?)
+            ",
+        )
+        .bind(date_str)
+        .bind(cv_path_str)
+        .bind(&job.company)
+        .bind(&job.position)
+        .bind(status_str)
+        .bind(&job.location)
+        .bind(i64::from(job.salary.min))
+        .bind(i64::from(job.salary.max))
+        .bind(&job.salary.currency)
+        .bind(interview_at_str)
+        .bind(offer_deadline_str)
+        .bind(&job.company_canonical)
+        .bind(&job.rejection_reason)
+        .bind(&job.notes)
+        .bind(&job.contact_name)
+        .bind(&job.contact_email)
+        .bind(&job.url)
+        .bind(job.pinned)
+        .bind(job.archived)
+        .bind(job.priority.to_db_string())
+        .bind(job.follow_up.map(|date| date.to_string()))
+        .bind(id)
+        .bind(expected_updated_at)
+        .bind(expected_updated_at)
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Inserts a single row using `executor`, with an explicit `id` or a
+    /// generated one when `id` is `None`.
+    async fn insert_row<'e, E>(
+        executor: E,
+        job: &JobApplication,
+        id: Option<i64>,
+    ) -> Result<i64, DbError>
+    where
+        E: sqlx::SqliteExecutor<'e>,
+    {
+        let date_str = job.date.map(|d| d.to_string());
+        let cv_path_str = job.cv.as_ref().map(|p| p.to_string_lossy().to_string());
+        let status_str = job.status.to_db_string();
+        let interview_at_str = job.interview_at.map(format_timestamp);
+        let offer_deadline_str = job.offer_deadline.map(format_timestamp);
+
+        let result = sqlx::query(
+            r"
+            INSERT INTO job_applications (id, date, cv_path, company, position, status, location, salary_min, salary_max, salary_currency, interview_at, offer_deadline, company_canonical, rejection_reason, notes, contact_name, contact_email, url, pinned, archived, priority, follow_up, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, strftime('%Y-%m-%d %H:%M:%f', 'now'))
+            ",
+        )
+        .bind(id)
+        .bind(date_str)
+        .bind(cv_path_str)
+        .bind(&job.company)
+        .bind(&job.position)
+        .bind(status_str)
+        .bind(&job.location)
+        .bind(i64::from(job.salary.min))
+        .bind(i64::from(job.salary.max))
+        .bind(&job.salary.currency)
+        .bind(interview_at_str)
+        .bind(offer_deadline_str)
+        .bind(&job.company_canonical)
+        .bind(&job.rejection_reason)
+        .bind(&job.notes)
+        .bind(&job.contact_name)
+        .bind(&job.contact_email)
+        .bind(&job.url)
+        .bind(job.pinned)
+        .bind(job.archived)
+        .bind(job.priority.to_db_string())
+        .bind(job.follow_up.map(|date| date.to_string()))
+        .execute(executor)
+        .await?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    /// Converts a list of database rows to `JobApplication` structs.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error produced by `row_to_job_application`.
+    fn rows_to_job_applications(
+        rows: &[sqlx::sqlite::SqliteRow],
+    ) -> Result<Vec<JobApplication>, DbError> {
+        rows.iter().map(Self::row_to_job_application).collect()
+    }
+
+    /// Converts a database row to a `JobApplication` struct.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The `SQLite` row to convert
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - A required column is missing from the row
+    /// - A date string cannot be parsed into a valid date
+    /// - A status string cannot be parsed into a valid status
+    /// - Column data types are unexpected
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if:
+    /// - Required columns are missing (this indicates a schema mismatch)
+    /// - Column types don't match expected types
+    fn row_to_job_application(row: &sqlx::sqlite::SqliteRow) -> Result<JobApplication, DbError> {
+        let id: i64 = row.get("id");
+        let date_str: Option<String> = row.get("date");
+        let cv_path_str: Option<String> = row.get("cv_path");
+        let company: String = row.get("company");
+        let position: String = row.get("position");
+        let status_str: String = row.get("status");
+        let location: String = row.get("location");
+        let salary_min: i64 = row.get("salary_min");
+        let salary_max: i64 = row.get("salary_max");
+        let salary_currency: String = row.get("salary_currency");
+        let interview_at_str: Option<String> = row.get("interview_at");
+        let offer_deadline_str: Option<String> = row.get("offer_deadline");
+        let company_canonical: Option<String> = row.get("company_canonical");
+        let rejection_reason: Option<String> = row.get("rejection_reason");
+        let notes: Option<String> = row.get("notes");
+        let contact_name: Option<String> = row.get("contact_name");
+        let contact_email: Option<String> = row.get("contact_email");
+        let url: Option<String> = row.get("url");
+        let pinned: bool = row.get("pinned");
+        let archived: bool = row.get("archived");
+        let priority_str: Option<String> = row.get("priority");
+        let follow_up_str: Option<String> = row.get("follow_up");
+        let updated_at_str: Option<String> = row.get("updated_at");
+        let created_at_str: Option<String> = row.get("created_at");
+
+        let date = date_str.as_deref().map(parse_date).transpose()?;
+        let follow_up = follow_up_str.as_deref().map(parse_date).transpose()?;
+
+        let interview_at = interview_at_str
+            .as_deref()
+            .map(parse_timestamp)
+            .transpose()?;
+        let offer_deadline = offer_deadline_str
+            .as_deref()
+            .map(parse_timestamp)
+            .transpose()?;
+        let updated_at = updated_at_str
+            .as_deref()
+            .map(|value| {
+                PrimitiveDateTime::parse(value, UPDATED_AT_FORMAT)
+                    .map(PrimitiveDateTime::assume_utc)
+                    .map_err(|_| DbError::InvalidDate(value.to_string()))
+            })
+            .transpose()?;
+        let created_at = created_at_str.as_deref().map(parse_timestamp).transpose()?;
+        let cv = cv_path_str.map(PathBuf::from);
+        let status = Status::from_db_string(&status_str).map_err(DbError::InvalidStatus)?;
+        // Rows written before priorities existed have none stored.
+        let priority = priority_str
+            .as_deref()
+            .map(Priority::from_db_string)
+            .transpose()
+            .map_err(DbError::InvalidPriority)?
+            .unwrap_or_default();
+        let salary = SalaryRange::with_currency(
+            u32::try_from(salary_min).unwrap_or(0),
+            u32::try_from(salary_max).unwrap_or(0),
+            &salary_currency,
+        );
+
+        Ok(JobApplication {
+            id: Some(id),
+            date,
+            cv,
+            company,
+            position,
+            status,
+            location,
+            salary,
+            interview_at,
+            offer_deadline,
+            follow_up,
+            company_canonical,
+            rejection_reason,
+            notes,
+            contact_name,
+            contact_email,
+            url,
+            pinned,
+            archived,
+            priority,
+            updated_at,
+            created_at,
+        })
+    }
+}
+
+/// Returns the part of a stored status string before any `:` payload.
+fn status_prefix(status: &str) -> &str {
+    status.split_once(':').map_or(status, |(prefix, _)| prefix)
+}
+
+/// Converts `amount` in `currency` into the base currency of `rates`.
+fn convert_to_base(rates: &CurrencyRates, amount: f64, currency: &str) -> Result<f64, DbError> {
+    rates
+        .convert(amount, currency)
+        .map_err(|_| DbError::MissingExchangeRate(currency.to_uppercase()))
+}
+
+/// Escapes `LIKE` wildcards so `value` matches literally with `ESCAPE '\'`.
+fn escape_like(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '\\' | '%' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Parses a stored ISO-8601 `YYYY-MM-DD` date.
+///
+/// # Errors
+///
+/// Returns `DbError::InvalidDate` if the string is not a valid date.
+fn parse_date(value: &str) -> Result<Date, DbError> {
+    Date::parse(value, &time::format_description::well_known::Iso8601::DATE)
+        .map_err(|_| DbError::InvalidDate(value.to_string()))
+}
+
+/// Formats a timestamp for storage using `TIMESTAMP_FORMAT` in UTC.
+fn format_timestamp(timestamp: OffsetDateTime) -> String {
+    timestamp
+        .to_offset(UtcOffset::UTC)
+        .format(TIMESTAMP_FORMAT)
+        .unwrap_or_default()
+}
+
+/// Formats an `updated_at` value the way `SQLite` stores it, in UTC.
+fn format_updated_at(timestamp: OffsetDateTime) -> String {
+    timestamp
+        .to_offset(UtcOffset::UTC)
+        .format(UPDATED_AT_FORMAT)
+        .unwrap_or_default()
+}
+
+/// Quotes a CSV field per RFC 4180 if it contains a comma, quote, or line
+/// break, doubling any quotes inside it.
+fn quote_csv_field(field: &str) -> std::borrow::Cow<'_, str> {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\"")).into()
+    } else {
+        field.into()
+    }
+}
+
+/// Splits CSV `contents` into records, each paired with the 1-based line it
+/// starts on. Quoted fields may contain commas, doubled quotes, and line
+/// breaks. Blank lines between records are skipped.
+///
+/// # Errors
+///
+/// Returns `DbError::ImportParse` for a quote that is never closed or text
+/// directly after a closing quote.
+fn parse_csv(contents: &str) -> Result<Vec<(usize, Vec<String>)>, DbError> {
+    let mut records = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut in_quotes = false;
+    let mut after_quote = false;
+    let mut chars = contents.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => {
+                    in_quotes = false;
+                    after_quote = true;
+                }
+                _ => {
+                    if c == '\n' {
+                        line += 1;
+                    }
+                    field.push(c);
+                }
+            }
+            continue;
+        }
+
+        match c {
+            ',' => {
+                fields.push(std::mem::take(&mut field));
+                after_quote = false;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                if !fields.is_empty() || !field.is_empty() || after_quote {
+                    fields.push(std::mem::take(&mut field));
+                    records.push((record_line, std::mem::take(&mut fields)));
+                }
+                after_quote = false;
+                line += 1;
+                record_line = line;
+            }
+            '"' if field.is_empty() && !after_quote => in_quotes = true,
+            _ if after_quote => {
+                return Err(DbError::ImportParse {
+                    line,
+                    reason: "unexpected text after a closing quote".to_string(),
+                });
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(DbError::ImportParse {
+            line: record_line,
+            reason: "unterminated quoted field".to_string(),
+        });
+    }
+    if !fields.is_empty() || !field.is_empty() || after_quote {
+        fields.push(field);
+        records.push((record_line, fields));
+    }
+    Ok(records)
+}
+
+/// Builds a job from the fields of one `CSV_HEADER`-ordered record.
+fn csv_record_to_job(fields: &[String]) -> Result<JobApplication, String> {
+    let [
+        company,
+        position,
+        location,
+        status,
+        salary_min,
+        salary_max,
+        salary_currency,
+        date,
+        cv_path,
+        archived,
+    ] = fields
+    else {
+        return Err(format!("expected 10 fields, found {}", fields.len()));
+    };
+
+    let status = Status::from_db_string(status)?;
+    let parse_salary = |value: &str, name: &str| {
+        value
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| format!("invalid {name} `{value}`"))
+    };
+    if salary_currency.is_empty() {
+        return Err("missing salary_currency".to_string());
+    }
+    let salary = SalaryRange::with_currency(
+        parse_salary(salary_min, "salary_min")?,
+        parse_salary(salary_max, "salary_max")?,
+        salary_currency,
+    );
+    let date = if date.is_empty() {
+        None
+    } else {
+        Some(parse_date(date).map_err(|_| format!("invalid date `{date}`"))?)
+    };
+    let archived = archived
+        .parse::<bool>()
+        .map_err(|_| format!("invalid archived flag `{archived}`"))?;
+
+    let mut job = JobApplication::new()
+        .company(company)
+        .position(position)
+        .location(location)
+        .status(status)
+        .salary(salary)
+        .archived(archived);
+    job.date = date;
+    if !cv_path.is_empty() {
+        job = job.cv(cv_path);
+    }
+    Ok(job)
+}
+
+/// Formats a timestamp as an iCalendar UTC date-time.
+fn format_ics_timestamp(timestamp: OffsetDateTime) -> String {
+    timestamp
+        .to_offset(UtcOffset::UTC)
+        .format(ICS_TIMESTAMP_FORMAT)
+        .unwrap_or_default()
+}
+
+/// Escapes an iCalendar TEXT value: backslash, `;`, `,`, and newlines.
+fn escape_ics_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Writes one iCalendar content line terminated by CRLF, folding it into
+/// continuation lines of at most `ICS_LINE_LIMIT` octets without splitting
+/// a UTF-8 character.
+fn write_ics_line(writer: &mut impl Write, line: &str) -> std::io::Result<()> {
+    let mut rest = line;
+    let mut limit = ICS_LINE_LIMIT;
+    loop {
+        let mut end = rest.len().min(limit);
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        let (chunk, tail) = rest.split_at(end);
+        writer.write_all(chunk.as_bytes())?;
+        writer.write_all(b"\r\n")?;
+        if tail.is_empty() {
+            return Ok(());
+        }
+        writer.write_all(b" ")?;
+        // The leading space counts towards the continuation line's length.
+        limit = ICS_LINE_LIMIT - 1;
+        rest = tail;
+    }
+}
+
+/// Parses a stored `TIMESTAMP_FORMAT` value as a UTC timestamp.
+///
+/// # Errors
+///
+/// Returns `DbError::InvalidDate` if the string is not a valid timestamp.
+fn parse_timestamp(value: &str) -> Result<OffsetDateTime, DbError> {
+    PrimitiveDateTime::parse(value, TIMESTAMP_FORMAT)
+        .map(PrimitiveDateTime::assume_utc)
+        .map_err(|_| DbError::InvalidDate(value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::checklist_completion;
+    use rstest::*;
+    use std::fs;
+    use std::thread;
+
+    async fn create_test_db() -> Database {
+        Database::new("sqlite::memory:").await.unwrap()
+    }
+
+    fn create_test_job() -> JobApplication {
+        JobApplication::new()
+            .company("Test Corp")
+            .position("Software Engineer")
+            .location("Remote")
+            .salary(SalaryRange::new(80_000, 120_000))
+            .status(Status::Applied)
+            .date(2024, 1, 15)
+    }
+
+    fn create_job_with_params(
+        company: &str,
+        position: &str,
+        location: &str,
+        salary_min: u32,
+        salary_max: u32,
+        status: Status,
+    ) -> JobApplication {
+        JobApplication::new()
+            .company(company)
+            .position(position)
+            .location(location)
+            .salary(SalaryRange::new(salary_min, salary_max))
+            .status(status)
+            .date(2024, 1, 15)
+    }
+
+    async fn cleanup_test_files(test_dir: &str) {
+        let _ = fs::remove_dir_all(test_dir);
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+
+    fn get_unique_test_dir(test_name: &str) -> String {
+        format!("test_{}_{:?}", test_name, thread::current().id())
+    }
+
+    async fn create_test_db_at_path(test_name: &str) -> (Database, String) {
+        let test_dir = get_unique_test_dir(test_name);
+        cleanup_test_files(&test_dir).await;
+        let db_path = format!("sqlite:{test_dir}/test.db");
+        let db = Database::new(&db_path).await.unwrap();
+        (db, test_dir)
+    }
+
+    fn assert_job_equals_ignoring_id(actual: &JobApplication, expected: &JobApplication) {
+        // The id and timestamps are assigned by the database.
+        let clear = |job: &JobApplication| JobApplication {
+            id: None,
+            updated_at: None,
+            created_at: None,
+            ..job.clone()
+        };
+        assert_eq!(clear(actual), clear(expected));
+    }
+
+    #[tokio::test]
+    async fn test_database_creation() {
+        let _db = create_test_db().await;
+    }
+
+    #[tokio::test]
+    async fn test_directory_creation_debug() {
+        let _ = fs::remove_dir_all("debug_test");
+
+        let db_path = "sqlite:debug_test/test.db";
+        println!("Creating database at: {db_path}");
+
+        // Test directory creation manually first
+        let path = std::path::Path::new("debug_test/test.db");
+        if let Some(parent) = path.parent() {
+            println!("Parent directory: {parent:?}");
+            println!("Parent exists: {}", parent.exists());
+            std::fs::create_dir_all(parent).unwrap();
+            println!("Directory created successfully");
+        }
+
+        let _db = Database::new(db_path).await.unwrap();
+
+        assert!(std::path::Path::new("debug_test/test.db").exists());
+
+        let _ = fs::remove_dir_all("debug_test");
+    }
+
+    #[tokio::test]
+    async fn test_persistent_database_creation() {
+        let (db, test_dir) = create_test_db_at_path("db_creation").await;
+
+        // Verify the database file was created
+        assert!(std::path::Path::new(&format!("{test_dir}/test.db")).exists());
+
+        // Database should start empty
+        let jobs = db.get_all_jobs().await.unwrap();
+        assert_eq!(jobs.len(), 0);
+
+        cleanup_test_files(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_persistent_database_empty_start() {
+        let (db, test_dir) = create_test_db_at_path("db_empty_start").await;
+
+        let jobs = db.get_all_jobs().await.unwrap();
+        assert_eq!(jobs.len(), 0);
+
+        cleanup_test_files(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_persistent_database_data_survives_reconnect() {
+        let test_dir = get_unique_test_dir("db_data_survives");
+        cleanup_test_files(&test_dir).await;
+        let db_path = format!("sqlite:{test_dir}/test.db");
+        let test_job = create_test_job();
+
+        let _job_id = {
+            let db = Database::new(&db_path).await.unwrap();
+            let id = db.insert_job(&test_job).await.unwrap();
+            assert!(id > 0);
+            id
+        };
+
+        // Reconnect to the same database
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        let db2 = Database::new(&db_path).await.unwrap();
+        let jobs = db2.get_all_jobs().await.unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_job_equals_ignoring_id(&jobs[0], &test_job);
+
+        cleanup_test_files(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_persistent_database_multiple_operations() {
+        let (db, test_dir) = create_test_db_at_path("db_multiple_ops").await;
+
+        let job1 = create_test_job();
+        let job2 = create_job_with_params(
+            "Another Corp",
+            "Data Scientist",
+            "New York",
+            90_000,
+            130_000,
+            Status::Interview(2),
+        );
+
+        let id1 = db.insert_job(&job1).await.unwrap();
+        let id2 = db.insert_job(&job2).await.unwrap();
+
+        let all_jobs = db.get_all_jobs().await.unwrap();
+        assert_eq!(all_jobs.len(), 2);
+
+        let mut updated_job = job1.clone();
+        updated_job.id = Some(id1);
+        updated_job.status = Status::Offer(95_000);
+        db.update_job(&updated_job).await.unwrap();
+
+        let retrieved_job = db.get_job_by_id(id1).await.unwrap();
+        assert_eq!(retrieved_job.status, Status::Offer(95_000));
+
+        db.delete_job(id2).await.unwrap();
+
+        let jobs_after_delete = db.get_all_jobs().await.unwrap();
+        assert_eq!(jobs_after_delete.len(), 1);
+        assert_eq!(jobs_after_delete[0].id, Some(id1));
+
+        cleanup_test_files(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_directory_creation() {
+        let test_dir = get_unique_test_dir("dir_creation");
+        cleanup_test_files(&test_dir).await;
+        let db_path = format!("sqlite:{test_dir}/deeply/nested/test_data/test_dir.db");
+        let _db = Database::new(&db_path).await.unwrap();
+
+        assert!(
+            std::path::Path::new(&format!("{test_dir}/deeply/nested/test_data/test_dir.db"))
+                .exists()
+        );
+
+        cleanup_test_files(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_database_schema_persistence() {
+        let test_dir = get_unique_test_dir("db_schema_persist");
+        cleanup_test_files(&test_dir).await;
+        let db_path = format!("sqlite:{test_dir}/test.db");
+
+        let complex_job = JobApplication::new()
+            .company("Complex Corp")
+            .position("Full Stack Engineer")
+            .location("San Francisco, CA")
+            .salary(SalaryRange::new(100_000, 150_000))
+            .status(Status::Interview(3))
+            .date(2024, 2, 15)
+            .cv("path/to/complex_cv.pdf");
+
+        {
+            let db = Database::new(&db_path).await.unwrap();
+            db.insert_job(&complex_job).await.unwrap();
+        }
+
+        // Reconnect and verify data persistence
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        let db2 = Database::new(&db_path).await.unwrap();
+        let jobs = db2.get_all_jobs().await.unwrap();
+        assert_eq!(jobs.len(), 1);
+
+        let job = &jobs[0];
+        assert_eq!(job.company, "Complex Corp");
+        assert_eq!(job.position, "Full Stack Engineer");
+        assert_eq!(job.location, "San Francisco, CA");
+        assert_eq!(job.salary.min, 100_000);
+        assert_eq!(job.salary.max, 150_000);
+        assert_eq!(job.status, Status::Interview(3));
+        assert_eq!(job.date.unwrap().to_string(), "2024-02-15");
+        assert_eq!(job.cv, Some(PathBuf::from("path/to/complex_cv.pdf")));
+
+        cleanup_test_files(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_insert_and_get_job() {
+        let db = create_test_db().await;
+        let job = create_test_job();
+
+        let id = db.insert_job(&job).await.unwrap();
+        assert!(id > 0);
+
+        let retrieved_job = db.get_job_by_id(id).await.unwrap();
+        assert_eq!(retrieved_job.company, job.company);
+        assert_eq!(retrieved_job.position, job.position);
+        assert_eq!(retrieved_job.location, job.location);
+        assert_eq!(retrieved_job.salary, job.salary);
+        assert_eq!(retrieved_job.status, job.status);
+        assert_eq!(retrieved_job.date, job.date);
+    }
+
+    #[tokio::test]
+    async fn test_get_all_jobs() {
+        let db = create_test_db().await;
+
+        let job1 = create_test_job();
+        let job2 = JobApplication::new()
+            .company("Another Corp")
+            .position("Data Scientist")
+            .location("New York")
+            .salary(SalaryRange::new(90_000, 130_000))
+            .status(Status::Interview(2));
+
+        db.insert_job(&job1).await.unwrap();
+        db.insert_job(&job2).await.unwrap();
+
+        let all_jobs = db.get_all_jobs().await.unwrap();
+        assert_eq!(all_jobs.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_update_job() {
+        let db = create_test_db().await;
+        let mut job = create_test_job();
+
+        let id = db.insert_job(&job).await.unwrap();
+        job.id = Some(id);
+        job.company = "Updated Corp".to_string();
+        job.status = Status::Interview(1);
+
+        db.update_job(&job).await.unwrap();
+
+        let updated_job = db.get_job_by_id(id).await.unwrap();
+        assert_eq!(updated_job.company, "Updated Corp");
+        assert_eq!(updated_job.status, Status::Interview(1));
+    }
+
+    #[tokio::test]
+    async fn test_update_status() {
+        let db = create_test_db().await;
+        let job = create_test_job();
+        let id = db.insert_job(&job).await.unwrap();
+
+        db.update_status(id, &Status::Interview(2)).await.unwrap();
+
+        let updated = db.get_job_by_id(id).await.unwrap();
+        assert_eq!(updated.status, Status::Interview(2));
+        assert_eq!(updated.company, job.company);
+        assert_eq!(updated.salary, job.salary);
+
+        let result = db.update_status(999, &Status::Rejected).await;
+        assert!(matches!(result, Err(DbError::NotFound(999))));
+    }
+
+    #[tokio::test]
+    async fn test_bulk_update_status() {
+        let db = create_test_db().await;
+        let mut ids = Vec::new();
+        for company in ["Alpha", "Beta", "Gamma", "Delta"] {
+            ids.push(
+                db.insert_job(&create_test_job().company(company))
+                    .await
+                    .unwrap(),
+            );
+        }
+
+        let changed = db
+            .bulk_update_status(&ids[..3], &Status::Rejected)
+            .await
+            .unwrap();
+        assert_eq!(changed, 3);
+
+        for &id in &ids[..3] {
+            assert_eq!(db.get_job_by_id(id).await.unwrap().status, Status::Rejected);
+        }
+        assert_eq!(
+            db.get_job_by_id(ids[3]).await.unwrap().status,
+            Status::Applied
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bulk_update_status_counts_duplicate_ids_once() {
+        let db = create_test_db().await;
+        let first = db.insert_job(&create_test_job()).await.unwrap();
+        let second = db.insert_job(&create_test_job()).await.unwrap();
+
+        let changed = db
+            .bulk_update_status(&[first, first, second], &Status::Rejected)
+            .await
+            .unwrap();
+        assert_eq!(changed, 2);
+    }
+
+    #[tokio::test]
+    async fn test_bulk_update_status_rolls_back_on_missing_id() {
+        let db = create_test_db().await;
+        let first = db
+            .insert_job(&create_test_job().company("Alpha"))
+            .await
+            .unwrap();
+        let second = db
+            .insert_job(&create_test_job().company("Beta"))
+            .await
+            .unwrap();
+        let before = db.get_all_jobs().await.unwrap();
+
+        let result = db
+            .bulk_update_status(&[first, 999, second], &Status::Rejected)
+            .await;
+        assert!(matches!(result, Err(DbError::NotFound(999))));
+
+        assert_eq!(db.get_all_jobs().await.unwrap(), before);
+    }
+
+    #[tokio::test]
+    async fn test_salary_histogram() {
+        let db = create_test_db().await;
+        for (i, max) in [45_000, 50_000, 59_999, 60_000, 120_000]
+            .into_iter()
+            .enumerate()
+        {
+            let job = create_test_job()
+                .company(&format!("Company {i}"))
+                .salary(SalaryRange::new(0, max));
+            db.insert_job(&job).await.unwrap();
+        }
+
+        assert_eq!(
+            db.salary_histogram(10_000).await.unwrap(),
+            vec![(40_000, 1), (50_000, 2), (60_000, 1), (120_000, 1)]
+        );
+        assert_eq!(
+            db.salary_histogram(50_000).await.unwrap(),
+            vec![(0, 1), (50_000, 3), (100_000, 1)]
+        );
+        assert_eq!(db.salary_histogram(1_000_000).await.unwrap(), vec![(0, 5)]);
+    }
+
+    #[tokio::test]
+    async fn test_salary_histogram_empty_and_zero_bucket() {
+        let db = create_test_db().await;
+        assert!(db.salary_histogram(10_000).await.unwrap().is_empty());
+        assert!(matches!(
+            db.salary_histogram(0).await,
+            Err(DbError::InvalidArgument(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_follow_up_persistence() {
+        let db = create_test_db().await;
+        let follow_up = time::macros::date!(2024 - 04 - 15);
+        let id = db
+            .insert_job(&create_test_job().follow_up(follow_up))
+            .await
+            .unwrap();
+        assert_eq!(
+            db.get_job_by_id(id).await.unwrap().follow_up,
+            Some(follow_up)
+        );
+
+        let mut cleared = db.get_job_by_id(id).await.unwrap();
+        cleared.follow_up = None;
+        db.update_job(&cleared).await.unwrap();
+        assert_eq!(db.get_job_by_id(id).await.unwrap().follow_up, None);
+    }
+
+    #[tokio::test]
+    async fn test_priority_persistence() {
+        let db = create_test_db().await;
+        for priority in [Priority::Low, Priority::Medium, Priority::High] {
+            let id = db
+                .insert_job(&create_test_job().priority(priority))
+                .await
+                .unwrap();
+            assert_eq!(db.get_job_by_id(id).await.unwrap().priority, priority);
+        }
+
+        let id = db.insert_job(&create_test_job()).await.unwrap();
+        let mut job = db.get_job_by_id(id).await.unwrap();
+        job.priority = Priority::High;
+        db.update_job(&job).await.unwrap();
+        assert_eq!(db.get_job_by_id(id).await.unwrap().priority, Priority::High);
+    }
+
+    #[tokio::test]
+    async fn test_legacy_null_priority_defaults_to_medium() {
+        let db = create_test_db().await;
+        let id = db
+            .insert_job(&create_test_job().priority(Priority::High))
+            .await
+            .unwrap();
+        sqlx::query("UPDATE job_applications SET priority = NULL")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            db.get_job_by_id(id).await.unwrap().priority,
+            Priority::Medium
+        );
+    }
+
+    #[tokio::test]
+    async fn test_malformed_date_is_invalid_date() {
+        let db = create_test_db().await;
+        sqlx::query(
+            "INSERT INTO job_applications (company, position, status, location, date) VALUES ('Bad Date Corp', 'Engineer', 'applied', 'Remote', '2024-13-45')",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+
+        let result = db.get_all_jobs().await;
+        assert!(matches!(&result, Err(DbError::InvalidDate(value)) if value == "2024-13-45"));
+        assert_eq!(result.unwrap_err().to_string(), "Invalid date: 2024-13-45");
+    }
+
+    #[rstest]
+    #[case::interview_at("interview_at")]
+    #[case::updated_at("updated_at")]
+    #[tokio::test]
+    async fn test_malformed_timestamp_is_invalid_date(#[case] column: &str) {
+        let db = create_test_db().await;
+        let id = db.insert_job(&create_test_job()).await.unwrap();
+        sqlx::query(&format!(
+            "UPDATE job_applications SET {column} = 'next tuesday' WHERE id = ?"
+        ))
+        .bind(id)
+        .execute(&db.pool)
+        .await
+        .unwrap();
+
+        assert!(matches!(
+            db.get_job_by_id(id).await,
+            Err(DbError::InvalidDate(value)) if value == "next tuesday"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update_job_rejects_stale_copy() {
+        let db = create_test_db().await;
+        let id = db.insert_job(&create_test_job()).await.unwrap();
+        sqlx::query("UPDATE job_applications SET updated_at = '2024-03-01 12:00:00.250'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+
+        // Two editors open the same version of the job.
+        let first = db.get_job_by_id(id).await.unwrap();
+        let second = first.clone();
+
+        db.update_job(&first.position("First Edit")).await.unwrap();
+        let result = db.update_job(&second.clone().position("Second Edit")).await;
+        assert!(matches!(result, Err(DbError::Conflict(conflict)) if conflict == id));
+        assert_eq!(db.get_job_by_id(id).await.unwrap().position, "First Edit");
+
+        // A fresh copy carries the new version and saves fine.
+        let fresh = db.get_job_by_id(id).await.unwrap();
+        db.update_job(&fresh.position("Third Edit")).await.unwrap();
+
+        // Without a version the update is unconditional.
+        let mut unversioned = second.position("Forced");
+        unversioned.updated_at = None;
+        db.update_job(&unversioned).await.unwrap();
+        assert_eq!(db.get_job_by_id(id).await.unwrap().position, "Forced");
+    }
+
+    #[tokio::test]
+    async fn test_update_job_conflict_vs_not_found() {
+        let db = create_test_db().await;
+        let mut missing = create_test_job();
+        missing.id = Some(999);
+        missing.updated_at = Some(OffsetDateTime::now_utc());
+        assert!(matches!(
+            db.update_job(&missing).await,
+            Err(DbError::NotFound(999))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_pinned_jobs_first_when_filtered() {
+        let db = create_test_db().await;
+        db.insert_job(&create_test_job().company("Acme Pinned").pinned(true))
+            .await
+            .unwrap();
+        db.insert_job(&create_test_job().company("Acme Newer"))
+            .await
+            .unwrap();
+        db.insert_job(
+            &create_test_job()
+                .company("Acme Rejected")
+                .status(Status::Rejected),
+        )
+        .await
+        .unwrap();
+
+        let companies =
+            |jobs: Vec<JobApplication>| jobs.into_iter().map(|job| job.company).collect::<Vec<_>>();
+        let expected = vec!["Acme Pinned", "Acme Newer"];
+        assert_eq!(
+            companies(db.get_jobs_by_status(&Status::Applied).await.unwrap()),
+            expected
+        );
+        assert_eq!(
+            companies(
+                db.get_jobs_by_statuses(&[StatusSelection::Applied])
+                    .await
+                    .unwrap()
+            ),
+            expected
+        );
+        assert_eq!(
+            companies(db.search_jobs("acme").await.unwrap())[0],
+            "Acme Pinned"
+        );
+        assert_eq!(
+            companies(db.search_all("acme").await.unwrap())[0],
+            "Acme Pinned"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pinned_jobs_sort_first() {
+        let db = create_test_db().await.with_cache();
+        let rejected = db
+            .insert_job(
+                &create_test_job()
+                    .company("Pinned Rejected")
+                    .status(Status::Rejected),
+            )
+            .await
+            .unwrap();
+        db.insert_job(
+            &create_test_job()
+                .company("Offer")
+                .status(Status::Offer(90_000)),
+        )
+        .await
+        .unwrap();
+        db.insert_job(&create_test_job().company("Pinned Applied").pinned(true))
+            .await
+            .unwrap();
+        db.insert_job(&create_test_job().company("Newest"))
+            .await
+            .unwrap();
+        db.set_pinned(rejected, true).await.unwrap();
+
+        let companies =
+            |jobs: Vec<JobApplication>| jobs.into_iter().map(|job| job.company).collect::<Vec<_>>();
+        assert_eq!(
+            companies(db.get_all_jobs_cached().await.unwrap()),
+            vec!["Pinned Applied", "Pinned Rejected", "Newest", "Offer"]
+        );
+        assert_eq!(
+            companies(db.get_jobs(SortColumn::Pipeline).await.unwrap()),
+            vec!["Pinned Applied", "Pinned Rejected", "Offer", "Newest"]
+        );
+        assert_eq!(
+            companies(
+                db.get_jobs_by_statuses(&[StatusSelection::Offer, StatusSelection::Rejected])
+                    .await
+                    .unwrap()
+            ),
+            vec!["Pinned Rejected", "Offer"]
+        );
+
+        db.set_pinned(rejected, false).await.unwrap();
+        assert!(!db.get_job_by_id(rejected).await.unwrap().pinned);
+        assert!(matches!(
+            db.set_pinned(999, true).await,
+            Err(DbError::NotFound(999))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_mark_applied() {
+        let db = create_test_db().await;
+        let mut draft = create_test_job()
+            .company("Draft Co")
+            .cv("cvs/draft.pdf")
+            .rejection_reason("n/a");
+        draft.date = None;
+        let id = db.insert_job(&draft).await.unwrap();
+
+        let today = time::macros::date!(2024 - 03 - 10);
+        db.mark_applied(id, today).await.unwrap();
+
+        let marked = db.get_job_by_id(id).await.unwrap();
+        assert_eq!(marked.date, Some(today));
+        assert_eq!(marked.status, Status::Applied);
+        assert_eq!(marked.company, draft.company);
+        assert_eq!(marked.position, draft.position);
+        assert_eq!(marked.location, draft.location);
+        assert_eq!(marked.salary, draft.salary);
+        assert_eq!(marked.cv, draft.cv);
+        assert_eq!(marked.rejection_reason, draft.rejection_reason);
+
+        assert!(matches!(
+            db.mark_applied(999, today).await,
+            Err(DbError::NotFound(999))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_delete_job() {
+        let db = create_test_db().await;
+        let job = create_test_job();
+
+        let id = db.insert_job(&job).await.unwrap();
+        db.delete_job(id).await.unwrap();
+
+        let result = db.get_job_by_id(id).await;
+        assert!(matches!(result, Err(DbError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_clear_all() {
+        let db = create_test_db().await;
+
+        db.insert_job(&create_test_job()).await.unwrap();
+        db.insert_job(&create_test_job()).await.unwrap();
+
+        let jobs_before = db.get_all_jobs().await.unwrap();
+        assert_eq!(jobs_before.len(), 2);
+        assert_eq!(db.count_jobs().await.unwrap(), 2);
+
+        db.clear_all().await.unwrap();
+
+        let jobs_after = db.get_all_jobs().await.unwrap();
+        assert_eq!(jobs_after.len(), 0);
+        assert_eq!(db.count_jobs().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_count_jobs_including_archived() {
+        let db = create_test_db().await;
+        db.insert_job(&create_test_job()).await.unwrap();
+        for _ in 0..2 {
+            let id = db.insert_job(&create_test_job()).await.unwrap();
+            db.archive_job(id).await.unwrap();
+        }
+
+        assert_eq!(db.count_jobs().await.unwrap(), 1);
+        assert_eq!(db.count_jobs_including_archived().await.unwrap(), 3);
+
+        db.clear_all().await.unwrap();
+        assert_eq!(db.count_jobs_including_archived().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_get_jobs_by_cv_pattern() {
+        let db = create_test_db().await;
+        let backend = db
+            .insert_job(&create_test_job().cv("cvs/backend_cv.pdf"))
+            .await
+            .unwrap();
+        db.insert_job(&create_test_job().cv("cvs/frontend_cv.pdf"))
+            .await
+            .unwrap();
+        db.insert_job(&create_test_job()).await.unwrap();
+
+        let jobs = db.get_jobs_by_cv_pattern("%backend%").await.unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].id, Some(backend));
+
+        let all_cvs = db.get_jobs_by_cv_pattern("%").await.unwrap();
+        assert_eq!(all_cvs.len(), 2);
+        assert!(all_cvs.iter().all(|job| job.cv.is_some()));
+    }
+
+    #[rstest]
+    #[case::empty(&[], 0.0)]
+    #[case::all_applied(&[Status::Applied, Status::Applied, Status::Applied], 0.0)]
+    #[case::mixed(
+        &[Status::Applied, Status::Interview(2), Status::Offer(90_000), Status::Rejected],
+        0.75
+    )]
+    #[case::all_responded(&[Status::Rejected, Status::Interview(1)], 1.0)]
+    #[tokio::test]
+    async fn test_response_rate(#[case] statuses: &[Status], #[case] expected: f64) {
+        let db = create_test_db().await;
+        for status in statuses {
+            db.insert_job(&create_test_job().status(status.clone()))
+                .await
+                .unwrap();
+        }
+
+        let rate = db.response_rate().await.unwrap();
+        assert!(
+            (rate - expected).abs() < f64::EPSILON,
+            "{rate} != {expected}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_offer_deadline_persistence() {
+        let db = create_test_db().await;
+        let deadline = time::macros::datetime!(2024-04-01 17:00 UTC);
+        let id = db
+            .insert_job(
+                &create_test_job()
+                    .status(Status::Offer(95_000))
+                    .offer_deadline(deadline),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            db.get_job_by_id(id).await.unwrap().offer_deadline,
+            Some(deadline)
+        );
+
+        let mut cleared = db.get_job_by_id(id).await.unwrap();
+        cleared.offer_deadline = None;
+        db.update_job(&cleared).await.unwrap();
+        assert_eq!(db.get_job_by_id(id).await.unwrap().offer_deadline, None);
+    }
+
+    #[tokio::test]
+    async fn test_export_csv() {
+        let test_dir = get_unique_test_dir("db_export_csv");
+        cleanup_test_files(&test_dir).await;
+        let path = PathBuf::from(&test_dir).join("exports/jobs.csv");
+
+        let db = create_test_db().await;
+        db.insert_job(&create_test_job().company("Plain Corp"))
+            .await
+            .unwrap();
+        db.insert_job(
+            &create_test_job()
+                .company("Acme, Inc.")
+                .position("Engineer \"Platform\"")
+                .status(Status::Interview(2))
+                .cv("cvs/backend_cv.pdf"),
+        )
+        .await
+        .unwrap();
+        let mut undated = create_test_job()
+            .location("Berlin\nGermany")
+            .status(Status::Offer(95_000));
+        undated.date = None;
+        db.insert_job(&undated).await.unwrap();
+
+        db.export_csv(&path).await.unwrap();
+
+        let csv = fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            csv,
+            "company,position,location,status,salary_min,salary_max,salary_currency,date,cv_path,archived\r\n\
+             Test Corp,Software Engineer,\"Berlin\nGermany\",offer:95000,80000,120000,USD,,,false\r\n\
+             \"Acme, Inc.\",\"Engineer \"\"Platform\"\"\",Remote,interview:2,80000,120000,USD,2024-01-15,cvs/backend_cv.pdf,false\r\n\
+             Plain Corp,Software Engineer,Remote,applied,80000,120000,USD,2024-01-15,,false\r\n"
+        );
+
+        cleanup_test_files(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_import_csv_valid_file() {
+        let test_dir = get_unique_test_dir("db_import_csv");
+        cleanup_test_files(&test_dir).await;
+        fs::create_dir_all(&test_dir).unwrap();
+        let path = PathBuf::from(&test_dir).join("jobs.csv");
+        fs::write(
+            &path,
+            "company,position,location,status,salary_min,salary_max,salary_currency,date,cv_path,archived\n\
+             \"Acme, Inc.\",\"Engineer \"\"Platform\"\"\",Remote,interview:2,80000,120000,USD,2024-01-15,cvs/backend_cv.pdf,false\r\n\
+             Plain Corp,Developer,\"Berlin\nGermany\",offer:95000,70000,90000,EUR,,,false\n\
+             \n",
+        )
+        .unwrap();
+
+        let db = create_test_db().await;
+        assert_eq!(db.import_csv(&path).await.unwrap(), 2);
+
+        let jobs = db.get_all_jobs().await.unwrap();
+        assert_eq!(jobs.len(), 2);
+        let plain = &jobs[0];
+        assert_eq!(plain.company, "Plain Corp");
+        assert_eq!(plain.location, "Berlin\nGermany");
+        assert_eq!(plain.status, Status::Offer(95_000));
+        assert_eq!(
+            plain.salary,
+            SalaryRange::with_currency(70_000, 90_000, "EUR")
+        );
+        assert_eq!(plain.date, None);
+        assert_eq!(plain.cv, None);
+        let acme = &jobs[1];
+        assert_eq!(acme.company, "Acme, Inc.");
+        assert_eq!(acme.position, "Engineer \"Platform\"");
+        assert_eq!(acme.status, Status::Interview(2));
+        assert_eq!(acme.date.unwrap().to_string(), "2024-01-15");
+        assert_eq!(acme.cv, Some(PathBuf::from("cvs/backend_cv.pdf")));
+
+        cleanup_test_files(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_import_csv_round_trips_export() {
+        let test_dir = get_unique_test_dir("db_import_csv_round_trip");
+        cleanup_test_files(&test_dir).await;
+        let path = PathBuf::from(&test_dir).join("jobs.csv");
+
+        let source = create_test_db().await;
+        source
+            .insert_job(
+                &create_test_job()
+                    .company("A, \"B\" & C")
+                    .salary(SalaryRange::with_currency(50_000, 60_000, "EUR"))
+                    .status(Status::Rejected),
+            )
+            .await
+            .unwrap();
+        source.export_csv(&path).await.unwrap();
+
+        let target = create_test_db().await;
+        assert_eq!(target.import_csv(&path).await.unwrap(), 1);
+        let job = &target.get_all_jobs().await.unwrap()[0];
+        assert_eq!(job.company, "A, \"B\" & C");
+        assert_eq!(
+            job.salary,
+            SalaryRange::with_currency(50_000, 60_000, "EUR")
+        );
+        assert_eq!(job.status, Status::Rejected);
+
+        cleanup_test_files(&test_dir).await;
+    }
+
+    #[rstest]
+    #[case::malformed_salary(
+        "company,position,location,status,salary_min,salary_max,salary_currency,date,cv_path,archived\n\
+         Acme,Engineer,Remote,applied,lots,120000,USD,2024-01-15,,false\n",
+        2
+    )]
+    #[case::unknown_status(
+        "company,position,location,status,salary_min,salary_max,salary_currency,date,cv_path,archived\n\
+         Acme,Engineer,Remote,applied,80000,120000,USD,2024-01-15,,false\n\
+         \"Multi\nline\",Engineer,Remote,pending,80000,120000,USD,2024-01-15,,false\n\
+         Later,Engineer,Remote,applied,80000,120000,USD,2024-01-15,,false\n",
+        3
+    )]
+    #[case::missing_field(
+        "company,position,location,status,salary_min,salary_max,salary_currency,date,cv_path,archived\nAcme,Engineer\n",
+        2
+    )]
+    #[case::invalid_archived_flag(
+        "company,position,location,status,salary_min,salary_max,salary_currency,date,cv_path,archived\n\
+         Acme,Engineer,Remote,applied,80000,120000,USD,2024-01-15,,maybe\n",
+        2
+    )]
+    #[case::wrong_header("name,role\nAcme,Engineer\n", 1)]
+    #[case::unterminated_quote(
+        "company,position,location,status,salary_min,salary_max,salary_currency,date,cv_path,archived\n\"Acme,Engineer\n",
+        2
+    )]
+    #[tokio::test]
+    async fn test_import_csv_reports_line(#[case] contents: &str, #[case] expected_line: usize) {
+        let test_dir = get_unique_test_dir("db_import_csv_errors");
+        cleanup_test_files(&test_dir).await;
+        fs::create_dir_all(&test_dir).unwrap();
+        let path = PathBuf::from(&test_dir).join("jobs.csv");
+        fs::write(&path, contents).unwrap();
+
+        let db = create_test_db().await;
+        match db.import_csv(&path).await {
+            Err(DbError::ImportParse { line, .. }) => assert_eq!(line, expected_line),
+            other => panic!("expected ImportParse, got {other:?}"),
+        }
+        assert_eq!(db.count_jobs().await.unwrap(), 0);
+
+        cleanup_test_files(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_import_csv_empty_file() {
+        let test_dir = get_unique_test_dir("db_import_csv_empty");
+        cleanup_test_files(&test_dir).await;
+        fs::create_dir_all(&test_dir).unwrap();
+        let path = PathBuf::from(&test_dir).join("empty.csv");
+        fs::write(&path, "").unwrap();
+
+        let db = create_test_db().await;
+        assert_eq!(db.import_csv(&path).await.unwrap(), 0);
+
+        fs::write(
+            &path,
+            "company,position,location,status,salary_min,salary_max,salary_currency,date,cv_path,archived\r\n",
+        )
+        .unwrap();
+        assert_eq!(db.import_csv(&path).await.unwrap(), 0);
+        assert_eq!(db.count_jobs().await.unwrap(), 0);
+
+        cleanup_test_files(&test_dir).await;
+    }
+
+    #[cfg(feature = "serde")]
+    async fn export_json_fixture(test_dir: &str) -> PathBuf {
+        cleanup_test_files(test_dir).await;
+        let path = PathBuf::from(test_dir).join("backup.json");
+        let source = create_test_db().await;
+        source
+            .insert_job(&create_test_job().company("Acme").notes("Recruiter: Jane"))
+            .await
+            .unwrap();
+        source
+            .insert_job(
+                &create_test_job()
+                    .company("Globex")
+                    .status(Status::Offer(90_000)),
+            )
+            .await
+            .unwrap();
+        source.export_json(&path).await.unwrap();
+        path
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn test_export_json_envelope() {
+        let test_dir = get_unique_test_dir("db_export_json");
+        let path = export_json_fixture(&test_dir).await;
+
+        let json = fs::read_to_string(&path).unwrap();
+        assert!(json.contains('\n'), "export should be pretty-printed");
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["version"], JSON_EXPORT_VERSION);
+        assert_eq!(value["jobs"].as_array().unwrap().len(), 2);
+        assert_eq!(value["jobs"][0]["company"], "Globex");
+        assert_eq!(value["jobs"][0]["status"]["type"], "offer");
+
+        cleanup_test_files(&test_dir).await;
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn test_import_json_append() {
+        let test_dir = get_unique_test_dir("db_import_json_append");
+        let path = export_json_fixture(&test_dir).await;
+
+        let db = create_test_db().await;
+        let existing = db
+            .insert_job(&create_test_job().company("Existing"))
+            .await
+            .unwrap();
+        assert_eq!(db.import_json(&path, false).await.unwrap(), 2);
+
+        let jobs = db.get_all_jobs().await.unwrap();
+        assert_eq!(jobs.len(), 3);
+        assert!(jobs.iter().any(|job| job.id == Some(existing)));
+        let acme = jobs.iter().find(|job| job.company == "Acme").unwrap();
+        assert_eq!(acme.notes.as_deref(), Some("Recruiter: Jane"));
+        let globex = jobs.iter().find(|job| job.company == "Globex").unwrap();
+        assert_eq!(globex.status, Status::Offer(90_000));
+
+        cleanup_test_files(&test_dir).await;
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn test_import_json_replace() {
+        let test_dir = get_unique_test_dir("db_import_json_replace");
+        let path = export_json_fixture(&test_dir).await;
+
+        let db = create_test_db().await;
+        for _ in 0..3 {
+            db.insert_job(&create_test_job().company("Old"))
+                .await
+                .unwrap();
+        }
+        assert_eq!(db.import_json(&path, true).await.unwrap(), 2);
+
+        let mut companies: Vec<String> = db
+            .get_all_jobs()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|job| job.company)
+            .collect();
+        companies.sort();
+        assert_eq!(companies, vec!["Acme", "Globex"]);
+
+        cleanup_test_files(&test_dir).await;
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn test_json_round_trip_keeps_archived_jobs() {
+        let test_dir = get_unique_test_dir("db_json_round_trip_archived");
+        cleanup_test_files(&test_dir).await;
+        let path = PathBuf::from(&test_dir).join("backup.json");
+
+        let db = create_test_db().await;
+        let active = db
+            .insert_job(&create_test_job().company("Active"))
+            .await
+            .unwrap();
+        let archived = db
+            .insert_job(&create_test_job().company("Archived"))
+            .await
+            .unwrap();
+        db.archive_job(archived).await.unwrap();
+
+        db.export_json(&path).await.unwrap();
+        assert_eq!(db.import_json(&path, true).await.unwrap(), 2);
+
+        let ids = |jobs: Vec<JobApplication>| jobs.iter().map(|job| job.id).collect::<Vec<_>>();
+        assert_eq!(ids(db.get_all_jobs().await.unwrap()), vec![Some(active)]);
+        let restored = db.get_archived_jobs().await.unwrap();
+        assert_eq!(ids(restored.clone()), vec![Some(archived)]);
+        assert!(restored[0].archived);
+        assert_eq!(restored[0].company, "Archived");
+
+        cleanup_test_files(&test_dir).await;
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn test_import_json_legacy_array() {
+        let test_dir = get_unique_test_dir("db_import_json_legacy");
+        cleanup_test_files(&test_dir).await;
+        fs::create_dir_all(&test_dir).unwrap();
+        let path = PathBuf::from(&test_dir).join("legacy.json");
+        fs::write(
+            &path,
+            r#"[{"company": "Legacy Corp", "position": "Dev", "status": {"type": "rejected"}}]"#,
+        )
+        .unwrap();
+
+        let db = create_test_db().await;
+        assert_eq!(db.import_json(&path, false).await.unwrap(), 1);
+        let job = &db.get_all_jobs().await.unwrap()[0];
+        assert_eq!(job.company, "Legacy Corp");
+        assert_eq!(job.status, Status::Rejected);
+
+        cleanup_test_files(&test_dir).await;
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case::older_version(r#"{"version": 0, "jobs": [{"company": "Old Corp"}]}"#)]
+    #[case::missing_version(r#"{"jobs": [{"company": "Old Corp"}]}"#)]
+    #[tokio::test]
+    async fn test_import_json_accepts_older_versions(#[case] contents: &str) {
+        let test_dir = get_unique_test_dir("db_import_json_older");
+        cleanup_test_files(&test_dir).await;
+        fs::create_dir_all(&test_dir).unwrap();
+        let path = PathBuf::from(&test_dir).join("older.json");
+        fs::write(&path, contents).unwrap();
+
+        let db = create_test_db().await;
+        assert_eq!(db.import_json(&path, false).await.unwrap(), 1);
+        assert_eq!(db.get_all_jobs().await.unwrap()[0].company, "Old Corp");
+
+        cleanup_test_files(&test_dir).await;
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case::truncated(r#"{"version": 1, "jobs": [{"company": "Acme""#)]
+    #[case::not_jobs(r#"{"version": 1, "jobs": [{"company": 42}]}"#)]
+    #[case::unsupported_version(r#"{"version": 2, "jobs": []}"#)]
+    #[case::string_version(r#"{"version": "2", "jobs": []}"#)]
+    #[case::fractional_version(r#"{"version": 1.5, "jobs": []}"#)]
+    #[tokio::test]
+    async fn test_import_json_errors_leave_table_untouched(#[case] contents: &str) {
+        let test_dir = get_unique_test_dir("db_import_json_corrupt");
+        cleanup_test_files(&test_dir).await;
+        fs::create_dir_all(&test_dir).unwrap();
+        let path = PathBuf::from(&test_dir).join("corrupt.json");
+        fs::write(&path, contents).unwrap();
+
+        let db = create_test_db().await;
+        let id = db.insert_job(&create_test_job()).await.unwrap();
+
+        for replace in [false, true] {
+            let result = db.import_json(&path, replace).await;
+            assert!(
+                matches!(
+                    result,
+                    Err(DbError::Json(_) | DbError::UnsupportedVersion(2))
+                ),
+                "unexpected result: {result:?}"
+            );
+            let jobs = db.get_all_jobs().await.unwrap();
+            assert_eq!(jobs.len(), 1);
+            assert_eq!(jobs[0].id, Some(id));
+        }
+
+        cleanup_test_files(&test_dir).await;
+    }
+
+    /// Joins folded iCalendar lines and splits the result into content lines.
+    fn unfold_ics(ics: &str) -> Vec<String> {
+        ics.replace("\r\n ", "")
+            .split("\r\n")
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_export_ics_events() {
+        let db = create_test_db().await;
+        db.insert_job(
+            &create_test_job()
+                .company("Acme")
+                .status(Status::Interview(1))
+                .interview_at(time::macros::datetime!(2024-03-20 14:30 UTC)),
+        )
+        .await
+        .unwrap();
+        db.insert_job(
+            &create_test_job()
+                .company("Globex")
+                .status(Status::Offer(90_000))
+                .interview_at(time::macros::datetime!(2024-03-10 09:00 UTC))
+                .offer_deadline(time::macros::datetime!(2024-04-01 17:00 +02:00)),
+        )
+        .await
+        .unwrap();
+        db.insert_job(&create_test_job()).await.unwrap();
+
+        let mut out = Vec::new();
+        db.export_ics(&mut out).await.unwrap();
+        let ics = String::from_utf8(out).unwrap();
+        let lines = unfold_ics(&ics);
+
+        assert_eq!(lines.first().map(String::as_str), Some("BEGIN:VCALENDAR"));
+        assert_eq!(lines.last().map(String::as_str), Some("END:VCALENDAR"));
+        assert_eq!(lines.iter().filter(|l| *l == "BEGIN:VEVENT").count(), 2);
+        assert_eq!(lines.iter().filter(|l| *l == "END:VEVENT").count(), 2);
+        let starts: Vec<&str> = lines
+            .iter()
+            .filter_map(|line| line.strip_prefix("DTSTART:"))
+            .collect();
+        assert_eq!(starts, vec!["20240320T143000Z", "20240401T150000Z"]);
+        assert!(lines.contains(&"SUMMARY:Offer deadline: Software Engineer at Globex".to_string()));
+        assert!(ics.ends_with("\r\n"));
+        assert!(!ics.replace("\r\n", "").contains('\n'));
+    }
+
+    #[tokio::test]
+    async fn test_export_ics_skips_times_from_earlier_statuses() {
+        let db = create_test_db().await;
+        let id = db
+            .insert_job(
+                &create_test_job()
+                    .status(Status::Interview(1))
+                    .interview_at(time::macros::datetime!(2024-03-20 14:30 UTC)),
+            )
+            .await
+            .unwrap();
+        db.update_status(id, &Status::Offer(90_000)).await.unwrap();
+        assert!(db.get_job_by_id(id).await.unwrap().interview_at.is_some());
+
+        let mut out = Vec::new();
+        db.export_ics(&mut out).await.unwrap();
+        let ics = String::from_utf8(out).unwrap();
+        assert!(!ics.contains("BEGIN:VEVENT"));
+    }
+
+    #[tokio::test]
+    async fn test_export_ics_escapes_and_folds() {
+        let db = create_test_db().await;
+        let position = "Staff Engineer, Platform; Infrastructure \\ Tooling ".repeat(3);
+        db.insert_job(
+            &create_test_job()
+                .company("Ünïcode GmbH")
+                .position(&position)
+                .location("Berlin,\nGermany")
+                .status(Status::Interview(1))
+                .interview_at(time::macros::datetime!(2024-03-20 14:30 UTC)),
+        )
+        .await
+        .unwrap();
+
+        let mut out = Vec::new();
+        db.export_ics(&mut out).await.unwrap();
+        let ics = String::from_utf8(out).unwrap();
+
+        for line in ics.split("\r\n") {
+            assert!(line.len() <= ICS_LINE_LIMIT, "line too long: {line:?}");
+        }
+        let lines = unfold_ics(&ics);
+        let summary = lines
+            .iter()
+            .find_map(|line| line.strip_prefix("SUMMARY:"))
+            .unwrap();
+        assert!(
+            summary.starts_with(
+                "Interview: Staff Engineer\\, Platform\\; Infrastructure \\\\ Tooling"
+            )
+        );
+        assert!(summary.ends_with(" at Ünïcode GmbH"));
+        assert!(lines.contains(&"LOCATION:Berlin\\,\\nGermany".to_string()));
+    }
+
+    #[rstest]
+    #[case::applied(Status::Applied, 2)]
+    #[case::any_interview_round(Status::Interview(1), 3)]
+    #[case::unused_interview_round(Status::Interview(7), 3)]
+    #[case::exact_offer(Status::Offer(90_000), 1)]
+    #[case::other_offer(Status::Offer(1), 0)]
+    #[case::rejected(Status::Rejected, 1)]
+    #[case::withdrawn(Status::Withdrawn, 1)]
+    #[tokio::test]
+    async fn test_get_jobs_by_status(#[case] status: Status, #[case] expected: usize) {
+        let db = create_test_db().await;
+        for seeded in [
+            Status::Applied,
+            Status::Applied,
+            Status::Interview(1),
+            Status::Interview(3),
+            Status::Interview(3),
+            Status::Offer(90_000),
+            Status::Rejected,
+            Status::Withdrawn,
+        ] {
+            db.insert_job(&create_test_job().status(seeded))
+                .await
+                .unwrap();
+        }
+
+        let jobs = db.get_jobs_by_status(&status).await.unwrap();
+        assert_eq!(jobs.len(), expected);
+        assert!(jobs.iter().all(|job| match status {
+            Status::Interview(_) => matches!(job.status, Status::Interview(_)),
+            _ => job.status == status,
+        }));
+    }
+
+    #[tokio::test]
+    async fn test_get_jobs_paginated() {
+        let db = create_test_db().await;
+        for i in 0..10 {
+            db.insert_job(&create_test_job().company(&format!("Company {i}")))
+                .await
+                .unwrap();
+        }
+        assert_eq!(db.count_jobs().await.unwrap(), 10);
+
+        let mut page_sizes = Vec::new();
+        let mut ids = Vec::new();
+        let mut offset = 0;
+        loop {
+            let page = db.get_jobs_paginated(3, offset).await.unwrap();
+            if page.is_empty() {
+                break;
+            }
+            page_sizes.push(page.len());
+            ids.extend(page.iter().map(|job| job.id));
+            offset += 3;
+        }
+        assert_eq!(page_sizes, vec![3, 3, 3, 1]);
+
+        let all_ids: Vec<_> = db
+            .get_all_jobs()
+            .await
+            .unwrap()
+            .iter()
+            .map(|job| job.id)
+            .collect();
+        assert_eq!(ids, all_ids);
+
+        assert!(db.get_jobs_paginated(3, 100).await.unwrap().is_empty());
+        assert!(db.get_jobs_paginated(0, 0).await.unwrap().is_empty());
+        assert!(matches!(
+            db.get_jobs_paginated(-1, 0).await,
+            Err(DbError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            db.get_jobs_paginated(3, -3).await,
+            Err(DbError::InvalidArgument(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_notes_persistence() {
+        let db = create_test_db().await;
+        let id = db
+            .insert_job(&create_test_job().notes("Recruiter: Jane\nCall back Monday"))
+            .await
+            .unwrap();
+        assert_eq!(
+            db.get_job_by_id(id).await.unwrap().notes.as_deref(),
+            Some("Recruiter: Jane\nCall back Monday")
+        );
+
+        let mut cleared = db.get_job_by_id(id).await.unwrap();
+        cleared.notes = None;
+        db.update_job(&cleared).await.unwrap();
+        assert_eq!(db.get_job_by_id(id).await.unwrap().notes, None);
+
+        let without = db.insert_job(&create_test_job()).await.unwrap();
+        assert_eq!(db.get_job_by_id(without).await.unwrap().notes, None);
+    }
+
+    #[tokio::test]
+    async fn test_salary_currency_persistence() {
+        let db = create_test_db().await;
+        let id = db
+            .insert_job(
+                &create_test_job().salary(SalaryRange::with_currency(60_000, 75_000, "EUR")),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            db.get_job_by_id(id).await.unwrap().salary,
+            SalaryRange::with_currency(60_000, 75_000, "EUR")
+        );
+
+        let mut job = db.get_job_by_id(id).await.unwrap();
+        job.salary.currency = "CHF".to_string();
+        db.update_job(&job).await.unwrap();
+        assert_eq!(db.get_job_by_id(id).await.unwrap().salary.currency, "CHF");
+    }
+
+    #[tokio::test]
+    async fn test_contact_persistence() {
+        let db = create_test_db().await;
+        let id = db
+            .insert_job(
+                &create_test_job()
+                    .contact_name("Jane Doe")
+                    .contact_email("jane@corp.com"),
+            )
+            .await
+            .unwrap();
+        let job = db.get_job_by_id(id).await.unwrap();
+        assert_eq!(job.contact_name.as_deref(), Some("Jane Doe"));
+        assert_eq!(job.contact_email.as_deref(), Some("jane@corp.com"));
+
+        let mut changed = job;
+        changed.contact_name = None;
+        changed.contact_email = Some("hr@corp.com".to_string());
+        db.update_job(&changed).await.unwrap();
+        let job = db.get_job_by_id(id).await.unwrap();
+        assert_eq!(job.contact_name, None);
+        assert_eq!(job.contact_email.as_deref(), Some("hr@corp.com"));
+    }
+
+    #[tokio::test]
+    async fn test_url_persistence() {
+        let db = create_test_db().await;
+        let id = db
+            .insert_job(&create_test_job().url("https://corp.com/jobs/42"))
+            .await
+            .unwrap();
+        assert_eq!(
+            db.get_job_by_id(id).await.unwrap().url.as_deref(),
+            Some("https://corp.com/jobs/42")
+        );
+
+        let mut job = db.get_job_by_id(id).await.unwrap();
+        job.url = None;
+        db.update_job(&job).await.unwrap();
+        assert_eq!(db.get_job_by_id(id).await.unwrap().url, None);
+    }
+
+    async fn insert_sortable_jobs(db: &Database) {
+        for (company, max, date) in [
+            ("beta", 90_000, Some((2024, 3, 1))),
+            ("Alpha", 120_000, Some((2024, 1, 1))),
+            ("gamma", 60_000, None),
+            ("Delta", 150_000, Some((2024, 2, 1))),
+        ] {
+            let mut job = create_test_job()
+                .company(company)
+                .salary(SalaryRange::new(50_000, max));
+            job.date = None;
+            if let Some((year, month, day)) = date {
+                job = job.date(year, month, day);
+            }
+            db.insert_job(&job).await.unwrap();
+        }
+    }
+
+    #[rstest]
+    #[case::company_asc(SortBy::Company, SortOrder::Asc, &["Alpha", "beta", "Delta", "gamma"])]
+    #[case::company_desc(SortBy::Company, SortOrder::Desc, &["gamma", "Delta", "beta", "Alpha"])]
+    #[case::salary_max_desc(SortBy::SalaryMax, SortOrder::Desc, &["Delta", "Alpha", "beta", "gamma"])]
+    #[case::salary_max_asc(SortBy::SalaryMax, SortOrder::Asc, &["gamma", "beta", "Alpha", "Delta"])]
+    #[case::date_desc(SortBy::Date, SortOrder::Desc, &["beta", "Delta", "Alpha", "gamma"])]
+    #[case::created_at_asc(SortBy::CreatedAt, SortOrder::Asc, &["beta", "Alpha", "gamma", "Delta"])]
+    #[tokio::test]
+    async fn test_get_jobs_sorted(
+        #[case] by: SortBy,
+        #[case] order: SortOrder,
+        #[case] expected: &[&str],
+    ) {
+        let db = create_test_db().await;
+        insert_sortable_jobs(&db).await;
+
+        let companies: Vec<String> = db
+            .get_jobs_sorted(by, order)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|job| job.company)
+            .collect();
+        assert_eq!(companies, expected);
+    }
+
+    #[tokio::test]
+    async fn test_get_jobs_sorted_keeps_pinned_first() {
+        let db = create_test_db().await;
+        insert_sortable_jobs(&db).await;
+        let pinned = db
+            .insert_job(&create_test_job().company("Zulu").pinned(true))
+            .await
+            .unwrap();
+
+        let jobs = db
+            .get_jobs_sorted(SortBy::Company, SortOrder::Asc)
+            .await
+            .unwrap();
+        assert_eq!(jobs[0].id, Some(pinned));
+        assert_eq!(jobs[1].company, "Alpha");
+    }
+
+    #[tokio::test]
+    async fn test_job_with_cv_path() {
+        let db = create_test_db().await;
+        let job = create_test_job().cv("path/to/resume.pdf");
+
+        let id = db.insert_job(&job).await.unwrap();
+        let retrieved_job = db.get_job_by_id(id).await.unwrap();
+
+        assert_eq!(retrieved_job.cv, Some(PathBuf::from("path/to/resume.pdf")));
+    }
+
+    #[rstest]
+    #[case(Status::Applied)]
+    #[case(Status::Interview(1))]
+    #[case(Status::Interview(3))]
+    #[case(Status::Offer(95_000))]
+    #[case(Status::Rejected)]
+    #[tokio::test]
+    async fn test_job_status_persistence(#[case] status: Status) {
+        let db = create_test_db().await;
+        let job = create_test_job().status(status.clone());
+
+        let id = db.insert_job(&job).await.unwrap();
+        let retrieved_job = db.get_job_by_id(id).await.unwrap();
+
+        assert_eq!(retrieved_job.status, status);
+    }
+
+    #[tokio::test]
+    async fn test_get_jobs_between() {
+        let db = create_test_db().await;
+        for (company, month, day) in [
+            ("Before", 2, 29),
+            ("Start", 3, 1),
+            ("Middle", 3, 15),
+            ("End", 3, 31),
+            ("After", 4, 1),
+        ] {
+            db.insert_job(&create_test_job().company(company).date(2024, month, day))
+                .await
+                .unwrap();
+        }
+        let mut undated = create_test_job().company("Undated");
+        undated.date = None;
+        db.insert_job(&undated).await.unwrap();
+
+        let march = db
+            .get_jobs_between(
+                time::macros::date!(2024 - 03 - 01),
+                time::macros::date!(2024 - 03 - 31),
+            )
+            .await
+            .unwrap();
+        let companies: Vec<&str> = march.iter().map(|job| job.company.as_str()).collect();
+        assert_eq!(companies, vec!["Start", "Middle", "End"]);
+
+        let single_day = db
+            .get_jobs_between(
+                time::macros::date!(2024 - 03 - 15),
+                time::macros::date!(2024 - 03 - 15),
+            )
+            .await
+            .unwrap();
+        assert_eq!(single_day.len(), 1);
+        assert_eq!(single_day[0].company, "Middle");
+    }
+
+    #[tokio::test]
+    async fn test_get_recent_jobs() {
+        let db = create_test_db().await;
+        for company in ["First", "Second", "Third", "Fourth", "Fifth"] {
+            db.insert_job(&create_test_job().company(company))
+                .await
+                .unwrap();
+        }
+
+        let recent = db.get_recent_jobs(2).await.unwrap();
+        let companies: Vec<&str> = recent.iter().map(|job| job.company.as_str()).collect();
+        assert_eq!(companies, vec!["Fifth", "Fourth"]);
+
+        assert!(db.get_recent_jobs(0).await.unwrap().is_empty());
+        assert_eq!(db.get_recent_jobs(10).await.unwrap().len(), 5);
+        assert!(matches!(
+            db.get_recent_jobs(-1).await,
+            Err(DbError::InvalidArgument(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update_nonexistent_job() {
+        let db = create_test_db().await;
+        let mut job = create_test_job();
+        job.id = Some(999);
+
+        let result = db.update_job(&job).await;
+        assert!(matches!(result, Err(DbError::NotFound(999))));
+    }
+
+    #[tokio::test]
+    async fn test_update_job_without_id() {
+        let db = create_test_db().await;
+        let job = create_test_job();
+        assert_eq!(job.id, None);
+
+        let result = db.update_job(&job).await;
+        assert!(matches!(result, Err(DbError::MissingId)));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Cannot update a job application without an ID"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_nonexistent_job() {
+        let db = create_test_db().await;
+        let result = db.delete_job(999).await;
+        assert!(matches!(result, Err(DbError::NotFound(999))));
+    }
+
+    #[tokio::test]
+    async fn test_interview_at_persistence() {
+        let db = create_test_db().await;
+        let at = time::macros::datetime!(2024-03-20 14:30 UTC);
+        let job = create_test_job()
+            .status(Status::Interview(1))
+            .interview_at(at);
+
+        let id = db.insert_job(&job).await.unwrap();
+        let retrieved_job = db.get_job_by_id(id).await.unwrap();
+        assert_eq!(retrieved_job.interview_at, Some(at));
+
+        let mut cleared = retrieved_job.clone();
+        cleared.interview_at = None;
+        db.update_job(&cleared).await.unwrap();
+        assert_eq!(db.get_job_by_id(id).await.unwrap().interview_at, None);
+    }
+
+    #[tokio::test]
+    async fn test_count_upcoming_interviews_window() {
+        let db = create_test_db().await;
+        let now = OffsetDateTime::now_utc();
+
+        for offset in [
+            Duration::days(1),
+            Duration::days(3),
+            Duration::days(10),
+            -Duration::days(1),
+        ] {
+            let job = create_test_job()
+                .status(Status::Interview(1))
+                .interview_at(now + offset);
+            db.insert_job(&job).await.unwrap();
+        }
+        // Not an interview, so never counted even with a scheduled time.
+        db.insert_job(&create_test_job().interview_at(now + Duration::days(1)))
+            .await
+            .unwrap();
+        // Interview without a scheduled time.
+        db.insert_job(&create_test_job().status(Status::Interview(2)))
+            .await
+            .unwrap();
+
+        assert_eq!(db.count_upcoming_interviews(0).await.unwrap(), 0);
+        assert_eq!(db.count_upcoming_interviews(7).await.unwrap(), 2);
+        assert_eq!(db.count_upcoming_interviews(14).await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_single_connection_pool_serves_concurrent_reads() {
+        let test_dir = get_unique_test_dir("db_single_connection");
+        cleanup_test_files(&test_dir).await;
+        let db = Database::new_with_options(&format!("sqlite:{test_dir}/test.db"), 1)
+            .await
+            .unwrap();
+        db.insert_job(&create_test_job()).await.unwrap();
+
+        let readers: Vec<_> = (0..8)
+            .map(|_| {
+                let db = db.clone();
+                tokio::spawn(async move { db.get_all_jobs().await })
+            })
+            .collect();
+        for reader in readers {
+            assert_eq!(reader.await.unwrap().unwrap().len(), 1);
+        }
+
+        let journal_mode: String = sqlx::query_scalar("PRAGMA journal_mode")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(journal_mode, "wal");
+
+        cleanup_test_files(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_migration_adds_missing_columns() {
+        let test_dir = get_unique_test_dir("db_migration");
+        cleanup_test_files(&test_dir).await;
+        fs::create_dir_all(&test_dir).unwrap();
+        let db_path = format!("sqlite:{test_dir}/test.db");
+
+        {
+            let options = SqliteConnectOptions::from_str(&db_path)
+                .unwrap()
+                .create_if_missing(true);
+            let pool = SqlitePool::connect_with(options).await.unwrap();
+            sqlx::query(
+                r"
+                CREATE TABLE job_applications (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    date TEXT,
+                    cv_path TEXT,
+                    company TEXT NOT NULL,
+                    position TEXT NOT NULL,
+                    status TEXT NOT NULL,
+                    location TEXT NOT NULL,
+                    salary_min INTEGER NOT NULL DEFAULT 0,
+                    salary_max INTEGER NOT NULL DEFAULT 0,
+                    created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+                )
+                ",
+            )
+            .execute(&pool)
+            .await
+            .unwrap();
+            sqlx::query(
+                "INSERT INTO job_applications (company, position, status, location) VALUES ('Legacy Corp', 'Dev', 'applied', 'Remote')",
+            )
+            .execute(&pool)
+            .await
+            .unwrap();
+            pool.close().await;
+        }
+
+        let db = Database::new(&db_path).await.unwrap();
+        let jobs = db.get_all_jobs().await.unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].company, "Legacy Corp");
+        assert_eq!(jobs[0].interview_at, None);
+        assert_eq!(jobs[0].notes, None);
+        assert_eq!(jobs[0].salary.currency, "USD");
+
+        let mut legacy = jobs[0].clone();
+        legacy.notes = Some("Migrated".to_string());
+        db.update_job(&legacy).await.unwrap();
+        assert_eq!(
+            db.get_job_by_id(legacy.id.unwrap())
+                .await
+                .unwrap()
+                .notes
+                .as_deref(),
+            Some("Migrated")
+        );
+
+        db.close().await.unwrap();
+        cleanup_test_files(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_job() {
+        let db = create_test_db().await;
+        let original = create_test_job()
+            .status(Status::Interview(2))
+            .notes("Ask about the team");
+        let original_id = db.insert_job(&original).await.unwrap();
+
+        let copy_id = db.duplicate_job(original_id).await.unwrap();
+        assert_ne!(copy_id, original_id);
+
+        let copy = db.get_job_by_id(copy_id).await.unwrap();
+        assert_eq!(copy.status, Status::Applied);
+        assert_eq!(copy.company, original.company);
+        assert_eq!(copy.position, original.position);
+        assert_eq!(copy.notes, original.notes);
+        assert_eq!(
+            db.get_job_by_id(original_id).await.unwrap().status,
+            Status::Interview(2)
+        );
+
+        assert!(matches!(
+            db.duplicate_job(999).await,
+            Err(DbError::NotFound(999))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_insert_jobs_returns_ids_in_order() {
+        let db = create_test_db().await;
+        let jobs: Vec<JobApplication> = (0..50)
+            .map(|i| create_test_job().company(&format!("Company {i}")))
+            .collect();
+
+        let ids = db.insert_jobs(&jobs).await.unwrap();
+        assert_eq!(ids.len(), 50);
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+        for (id, job) in ids.iter().zip(&jobs) {
+            assert_eq!(db.get_job_by_id(*id).await.unwrap().company, job.company);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_insert_jobs_rolls_back_on_failure() {
+        let db = create_test_db().await.with_cache();
+        assert!(db.get_all_jobs_cached().await.unwrap().is_empty());
+        sqlx::query(
+            r"
+            CREATE TRIGGER reject_boom BEFORE INSERT ON job_applications
+            WHEN NEW.company = 'Boom'
+            BEGIN
+                SELECT RAISE(ABORT, 'rejected by test');
+            END
+            ",
+        )
+        .execute(&db.pool)
+        .await
+        .unwrap();
+
+        let jobs = [
+            create_test_job().company("First"),
+            create_test_job().company("Boom"),
+            create_test_job().company("Last"),
+        ];
+        assert!(db.insert_jobs(&jobs).await.is_err());
+        assert_eq!(db.count_jobs().await.unwrap(), 0);
+        assert!(db.get_all_jobs_cached().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_stats() {
+        let db = create_test_db().await;
+        for (status, max) in [
+            (Status::Applied, 100_000),
+            (Status::Interview(1), 120_000),
+            (Status::Interview(3), 80_000),
+            (Status::Offer(90_000), 110_000),
+            (Status::Rejected, 90_000),
+        ] {
+            db.insert_job(
+                &create_test_job()
+                    .status(status)
+                    .salary(SalaryRange::new(0, max)),
+            )
+            .await
+            .unwrap();
+        }
+
+        let stats = db.stats(&CurrencyRates::default()).await.unwrap();
+        assert_eq!(stats.total, 5);
+        assert_eq!(
+            stats.by_status,
+            HashMap::from([
+                ("applied".to_string(), 1),
+                ("interview".to_string(), 2),
+                ("offer".to_string(), 1),
+                ("rejected".to_string(), 1),
+            ])
+        );
+        assert!((stats.avg_salary_max - 100_000.0).abs() < f64::EPSILON);
+        assert_eq!(stats.offer_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_stats_empty() {
+        let db = create_test_db().await;
+        let stats = db.stats(&CurrencyRates::default()).await.unwrap();
+        assert_eq!(stats, JobStats::default());
+        assert!(!stats.avg_salary_max.is_nan());
+    }
+
+    #[tokio::test]
+    async fn test_settings_round_trip() {
+        let db = create_test_db().await;
+        assert_eq!(db.get_setting("theme").await.unwrap(), None);
+
+        db.set_setting("theme", "dark").await.unwrap();
+        db.set_setting("other", "value").await.unwrap();
+        assert_eq!(
+            db.get_setting("theme").await.unwrap().as_deref(),
+            Some("dark")
+        );
+
+        db.set_setting("theme", "light").await.unwrap();
+        assert_eq!(
+            db.get_setting("theme").await.unwrap().as_deref(),
+            Some("light")
+        );
+        assert_eq!(
+            db.get_setting("other").await.unwrap().as_deref(),
+            Some("value")
+        );
+
+        // Settings are not job data, so clearing the jobs keeps them.
+        db.clear_all().await.unwrap();
+        assert_eq!(
+            db.get_setting("theme").await.unwrap().as_deref(),
+            Some("light")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_archived_jobs_left_out_of_listings_and_counts() {
+        let db = create_test_db().await;
+        let mut ids = Vec::new();
+        for i in 0..5 {
+            ids.push(
+                db.insert_job(&create_test_job().company(&format!("Company {i}")))
+                    .await
+                    .unwrap(),
+            );
+        }
+        db.archive_job(ids[2]).await.unwrap();
+
+        let all_ids: Vec<_> = db
+            .get_all_jobs()
+            .await
+            .unwrap()
+            .iter()
+            .map(|job| job.id)
+            .collect();
+        assert_eq!(all_ids.len(), 4);
+        assert!(!all_ids.contains(&Some(ids[2])));
+        assert_eq!(db.count_jobs().await.unwrap(), 4);
+
+        let mut paged_ids = Vec::new();
+        for offset in (0..db.count_jobs().await.unwrap()).step_by(2) {
+            let page = db.get_jobs_paginated(2, offset).await.unwrap();
+            paged_ids.extend(page.iter().map(|job| job.id));
+        }
+        assert_eq!(paged_ids, all_ids);
+
+        let sorted = db
+            .get_jobs_sorted(SortBy::Company, SortOrder::Asc)
+            .await
+            .unwrap();
+        assert_eq!(sorted.len(), 4);
+        assert_eq!(
+            db.get_jobs_by_status(&Status::Applied).await.unwrap().len(),
+            4
+        );
+        assert!(db.search_all("Company 2").await.unwrap().is_empty());
+        assert_eq!(db.stats(&CurrencyRates::default()).await.unwrap().total, 4);
+    }
+
+    #[tokio::test]
+    async fn test_export_csv_includes_archived_jobs() {
+        let test_dir = get_unique_test_dir("db_export_csv_archived");
+        cleanup_test_files(&test_dir).await;
+        let path = PathBuf::from(&test_dir).join("jobs.csv");
+
+        let db = create_test_db().await;
+        let archived = db
+            .insert_job(&create_test_job().company("Archived Corp"))
+            .await
+            .unwrap();
+        db.archive_job(archived).await.unwrap();
+
+        db.export_csv(&path).await.unwrap();
+        assert!(
+            fs::read_to_string(&path)
+                .unwrap()
+                .contains("\r\nArchived Corp,")
+        );
+
+        cleanup_test_files(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_import_csv_round_trips_archived_job() {
+        let test_dir = get_unique_test_dir("db_import_csv_archived");
+        cleanup_test_files(&test_dir).await;
+        let path = PathBuf::from(&test_dir).join("jobs.csv");
+
+        let source = create_test_db().await;
+        source
+            .insert_job(&create_test_job().company("Active Corp"))
+            .await
+            .unwrap();
+        let archived = source
+            .insert_job(&create_test_job().company("Archived Corp"))
+            .await
+            .unwrap();
+        source.archive_job(archived).await.unwrap();
+        source.export_csv(&path).await.unwrap();
+
+        let target = create_test_db().await;
+        assert_eq!(target.import_csv(&path).await.unwrap(), 2);
+        let companies =
+            |jobs: Vec<JobApplication>| jobs.into_iter().map(|job| job.company).collect::<Vec<_>>();
+        assert_eq!(
+            companies(target.get_all_jobs().await.unwrap()),
+            ["Active Corp"]
+        );
+        assert_eq!(
+            companies(target.get_archived_jobs().await.unwrap()),
+            ["Archived Corp"]
+        );
+
+        cleanup_test_files(&test_dir).await;
+    }
+
+    #[tokio::test]
+    async fn test_archive_and_restore_job() {
+        let db = create_test_db().await.with_cache();
+        let kept = db
+            .insert_job(&create_test_job().company("Kept"))
+            .await
+            .unwrap();
+        let archived = db
+            .insert_job(
+                &create_test_job()
+                    .company("Archived")
+                    .status(Status::Rejected),
+            )
+            .await
+            .unwrap();
+        assert_eq!(db.get_all_jobs_cached().await.unwrap().len(), 2);
+
+        db.archive_job(archived).await.unwrap();
+        let ids =
+            |jobs: Vec<JobApplication>| jobs.iter().filter_map(|job| job.id).collect::<Vec<_>>();
+        assert_eq!(ids(db.get_all_jobs_cached().await.unwrap()), vec![kept]);
+        assert_eq!(ids(db.get_archived_jobs().await.unwrap()), vec![archived]);
+        assert_eq!(
+            db.get_job_by_id(archived).await.unwrap().status,
+            Status::Rejected
+        );
+
+        db.unarchive_job(archived).await.unwrap();
+        assert_eq!(db.get_all_jobs_cached().await.unwrap().len(), 2);
+        assert!(db.get_archived_jobs().await.unwrap().is_empty());
+
+        assert!(matches!(
+            db.archive_job(999).await,
+            Err(DbError::NotFound(999))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_all_jobs_bypasses_cache() {
+        let db = create_test_db().await.with_cache();
+        let id = db.insert_job(&create_test_job()).await.unwrap();
+        assert_eq!(db.get_all_jobs_cached().await.unwrap().len(), 1);
+
+        // Delete behind the cache's back; only the cached read misses it.
+        sqlx::query("DELETE FROM job_applications WHERE id = ?")
+            .bind(id)
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        assert!(db.get_all_jobs().await.unwrap().is_empty());
+        assert_eq!(db.get_all_jobs_cached().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_all_jobs_cached() {
+        let db = create_test_db().await.with_cache();
+        let id = db.insert_job(&create_test_job()).await.unwrap();
+        assert_eq!(db.get_all_jobs_cached().await.unwrap().len(), 1);
+
+        sqlx::query("DELETE FROM job_applications WHERE id = ?")
+            .bind(id)
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(db.get_all_jobs_cached().await.unwrap().len(), 1);
+
+        db.insert_job(&create_test_job().company("Fresh Corp"))
+            .await
+            .unwrap();
+        let jobs = db.get_all_jobs_cached().await.unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].company, "Fresh Corp");
+    }
+
+    #[tokio::test]
+    async fn test_cache_skips_rows_read_before_a_write() {
+        let db = create_test_db().await.with_cache();
+        let stale = db.get_all_jobs_cached().await.unwrap();
+        let generation = db.cache.as_ref().unwrap().read().unwrap().generation;
+
+        // A write lands between the read and the cache store.
+        db.insert_job(&create_test_job()).await.unwrap();
+        db.store_cache(generation, &stale);
+
+        assert!(db.cache.as_ref().unwrap().read().unwrap().jobs.is_none());
+        assert_eq!(db.get_all_jobs_cached().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cache_invalidated_by_writes() {
+        let db = create_test_db().await.with_cache();
+        assert!(db.get_all_jobs_cached().await.unwrap().is_empty());
+
+        let id = db.insert_job(&create_test_job()).await.unwrap();
+        assert_eq!(db.get_all_jobs_cached().await.unwrap().len(), 1);
+
+        let mut job = db.get_job_by_id(id).await.unwrap();
+        job.company = "Cached Corp".to_string();
+        db.update_job(&job).await.unwrap();
+        assert_eq!(
+            db.get_all_jobs_cached().await.unwrap()[0].company,
+            "Cached Corp"
+        );
+
+        db.delete_job(id).await.unwrap();
+        assert!(db.get_all_jobs_cached().await.unwrap().is_empty());
+
+        db.insert_job(&create_test_job()).await.unwrap();
+        assert_eq!(db.get_all_jobs_cached().await.unwrap().len(), 1);
+        db.clear_all().await.unwrap();
+        assert!(db.get_all_jobs_cached().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_jobs_with_overlapping_salary() {
+        let db = create_test_db().await;
+        let base =
+            create_job_with_params("Base", "Dev", "Remote", 80_000, 120_000, Status::Applied);
+        let base_id = db.insert_job(&base).await.unwrap();
+
+        let overlapping = [
+            ("Inside", 90_000, 110_000),
+            ("Lower", 60_000, 85_000),
+            ("Upper", 115_000, 150_000),
+            ("Touching", 120_000, 130_000),
+        ];
+        for (company, min, max) in overlapping {
+            db.insert_job(&create_job_with_params(
+                company,
+                "Dev",
+                "Remote",
+                min,
+                max,
+                Status::Applied,
+            ))
+            .await
+            .unwrap();
+        }
+        db.insert_job(&create_job_with_params(
+            "Below",
+            "Dev",
+            "Remote",
+            40_000,
+            79_999,
+            Status::Applied,
+        ))
+        .await
+        .unwrap();
+        db.insert_job(&create_job_with_params(
+            "Above",
+            "Dev",
+            "Remote",
+            120_001,
+            200_000,
+            Status::Applied,
+        ))
+        .await
+        .unwrap();
+
+        let jobs = db.get_jobs_with_overlapping_salary(base_id).await.unwrap();
+        let mut companies: Vec<&str> = jobs.iter().map(|j| j.company.as_str()).collect();
+        companies.sort_unstable();
+        assert_eq!(companies, vec!["Inside", "Lower", "Touching", "Upper"]);
+        assert!(jobs.iter().all(|j| j.id != Some(base_id)));
+    }
+
+    #[tokio::test]
+    async fn test_get_jobs_with_overlapping_salary_excludes_identical_self() {
+        let db = create_test_db().await;
+        let base_id = db.insert_job(&create_test_job()).await.unwrap();
+
+        let jobs = db.get_jobs_with_overlapping_salary(base_id).await.unwrap();
+        assert!(jobs.is_empty());
+
+        let twin_id = db.insert_job(&create_test_job()).await.unwrap();
+        let jobs = db.get_jobs_with_overlapping_salary(base_id).await.unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].id, Some(twin_id));
+    }
+
+    #[tokio::test]
+    async fn test_get_jobs_with_overlapping_salary_missing_base() {
+        let db = create_test_db().await;
+        let result = db.get_jobs_with_overlapping_salary(999).await;
+        assert!(matches!(result, Err(DbError::NotFound(999))));
+    }
+
+    #[tokio::test]
+    async fn test_get_jobs_by_statuses() {
+        let db = create_test_db().await;
+        for status in [
+            Status::Applied,
+            Status::Interview(1),
+            Status::Interview(3),
+            Status::Offer(90_000),
+            Status::Rejected,
+        ] {
+            db.insert_job(&create_test_job().status(status))
+                .await
+                .unwrap();
+        }
+
+        let interviews = db
+            .get_jobs_by_statuses(&[StatusSelection::Interview])
+            .await
+            .unwrap();
+        assert_eq!(interviews.len(), 2);
+
+        let active = db
+            .get_jobs_by_statuses(&[StatusSelection::Applied, StatusSelection::Offer])
+            .await
+            .unwrap();
+        let statuses: Vec<Status> = active.into_iter().map(|j| j.status).collect();
+        assert_eq!(statuses.len(), 2);
+        assert!(statuses.contains(&Status::Applied));
+        assert!(statuses.contains(&Status::Offer(90_000)));
+
+        assert!(db.get_jobs_by_statuses(&[]).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_count_weekend_applications() {
+        let db = create_test_db().await;
+        // 2024-03-11 is a Monday; walk through the whole week.
+        for day in 11..=17 {
+            db.insert_job(&create_test_job().date(2024, 3, day))
+                .await
+                .unwrap();
+        }
+        let mut undated = create_test_job();
+        undated.date = None;
+        db.insert_job(&undated).await.unwrap();
+
+        assert_eq!(db.count_weekend_applications().await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_count_weekend_applications_none() {
+        let db = create_test_db().await;
+        // Wednesday and Friday only.
+        db.insert_job(&create_test_job().date(2024, 1, 3))
+            .await
+            .unwrap();
+        db.insert_job(&create_test_job().date(2024, 1, 5))
+            .await
+            .unwrap();
+
+        assert_eq!(db.count_weekend_applications().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_expected_pipeline_value() {
+        let db = create_test_db().await;
+        let seeded = [
+            ("A", 90_000, 110_000, Status::Applied),      // 100k * 0.05
+            ("B", 80_000, 120_000, Status::Interview(2)), // 100k * 0.2
+            ("C", 50_000, 70_000, Status::Offer(65_000)), // 60k * 0.8
+            ("D", 200_000, 300_000, Status::Rejected),    // weighted at zero
+        ];
+        for (company, min, max, status) in seeded {
+            db.insert_job(&create_job_with_params(
+                company, "Dev", "Remote", min, max, status,
+            ))
+            .await
+            .unwrap();
+        }
+
+        let value = db
+            .expected_pipeline_value(&CurrencyRates::default())
+            .await
+            .unwrap();
+        assert!((value - 73_000.0).abs() < 1e-6, "unexpected value {value}");
+    }
+
+    #[tokio::test]
+    async fn test_expected_pipeline_value_empty() {
+        let db = create_test_db().await;
+        let value = db
+            .expected_pipeline_value(&CurrencyRates::default())
+            .await
+            .unwrap();
+        assert!(value.abs() < f64::EPSILON);
+    }
+
+    #[tokio::test]
+    async fn test_get_distinct_locations() {
+        let db = create_test_db().await;
+        for location in ["Remote", "Berlin", "", "Remote", "Austin", "Berlin"] {
+            db.insert_job(&create_test_job().location(location))
+                .await
+                .unwrap();
+        }
+
+        let locations = db.get_distinct_locations().await.unwrap();
+        assert_eq!(locations, vec!["Austin", "Berlin", "Remote"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_distinct_locations_empty() {
+        let db = create_test_db().await;
+        db.insert_job(&create_test_job().location(""))
+            .await
+            .unwrap();
+
+        assert!(db.get_distinct_locations().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_jobs_pipeline_order() {
+        let db = create_test_db().await;
+        let seeded = [
+            ("Rejected Old", Status::Rejected, Some((2024, 3, 1))),
+            ("Applied Old", Status::Applied, Some((2024, 1, 10))),
+            ("Offer", Status::Offer(90_000), Some((2024, 1, 1))),
+            ("Applied Undated", Status::Applied, None),
+            ("Interview", Status::Interview(2), Some((2024, 2, 1))),
+            ("Applied New", Status::Applied, Some((2024, 2, 20))),
+        ];
+        for (company, status, date) in seeded {
+            let mut job = create_test_job().company(company).status(status);
+            job.date = None;
+            if let Some((y, m, d)) = date {
+                job = job.date(y, m, d);
+            }
+            db.insert_job(&job).await.unwrap();
+        }
+
+        let companies: Vec<String> = db
+            .get_jobs(SortColumn::Pipeline)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|job| job.company)
+            .collect();
+        assert_eq!(
+            companies,
+            vec![
+                "Offer",
+                "Interview",
+                "Applied New",
+                "Applied Old",
+                "Applied Undated",
+                "Rejected Old",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_jobs_created_at_matches_get_all_jobs() {
+        let db = create_test_db().await;
+        db.insert_job(&create_test_job().company("First"))
+            .await
+            .unwrap();
+        db.insert_job(&create_test_job().company("Second"))
+            .await
+            .unwrap();
+
+        let sorted = db.get_jobs(SortColumn::CreatedAt).await.unwrap();
+        let all = db.get_all_jobs().await.unwrap();
+        let ids = |jobs: &[JobApplication]| jobs.iter().map(|j| j.id).collect::<Vec<_>>();
+        assert_eq!(ids(&sorted), ids(&all));
+    }
+
+    #[tokio::test]
+    async fn test_status_history_recorded_on_change() {
+        let db = create_test_db().await;
+        let id = db.insert_job(&create_test_job()).await.unwrap();
+        db.update_status(id, &Status::Interview(1)).await.unwrap();
+        db.update_status(id, &Status::Interview(1)).await.unwrap();
+        let job = db.get_job_by_id(id).await.unwrap().position("Renamed");
+        db.update_job(&job).await.unwrap();
+        db.update_status(id, &Status::Offer(90_000)).await.unwrap();
+
+        let statuses: Vec<String> =
+            sqlx::query_scalar("SELECT status FROM status_history WHERE job_id = ? ORDER BY id")
+                .bind(id)
+                .fetch_all(&db.pool)
+                .await
+                .unwrap();
+        assert_eq!(statuses, vec!["applied", "interview:1", "offer:90000"]);
+
+        db.delete_job(id).await.unwrap();
+        let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM status_history")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(remaining, 0);
+    }
+
+    #[tokio::test]
+    async fn test_get_status_history() {
+        let db = create_test_db().await;
+        let id = db.insert_job(&create_test_job()).await.unwrap();
+        db.update_status(id, &Status::Interview(1)).await.unwrap();
+        let job = db
+            .get_job_by_id(id)
+            .await
+            .unwrap()
+            .status(Status::Offer(90_000));
+        db.update_job(&job).await.unwrap();
+        let other = db.insert_job(&create_test_job()).await.unwrap();
+
+        let history = db.get_status_history(id).await.unwrap();
+        let statuses: Vec<Status> = history.iter().map(|(status, _)| status.clone()).collect();
+        assert_eq!(
+            statuses,
+            vec![Status::Applied, Status::Interview(1), Status::Offer(90_000)]
+        );
+        assert!(history.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+        assert_eq!(history[0].1, OffsetDateTime::now_utc().date());
+
+        assert_eq!(db.get_status_history(other).await.unwrap().len(), 1);
+        assert!(db.get_status_history(999).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_average_interview_rounds_for_offers() {
+        let db = create_test_db().await;
+        assert_eq!(
+            db.average_interview_rounds_for_offers().await.unwrap(),
+            None
+        );
+
+        let histories: [&[Status]; 4] = [
+            // Three rounds, then an offer.
+            &[
+                Status::Interview(1),
+                Status::Interview(2),
+                Status::Interview(3),
+                Status::Offer(100_000),
+            ],
+            // One round, an offer, then rejected after all.
+            &[
+                Status::Interview(1),
+                Status::Offer(80_000),
+                Status::Rejected,
+            ],
+            // Straight to an offer.
+            &[Status::Offer(70_000)],
+            // Never got an offer, so not counted.
+            &[Status::Interview(5), Status::Rejected],
+        ];
+        for (i, history) in histories.into_iter().enumerate() {
+            let id = db
+                .insert_job(&create_test_job().company(&format!("Company {i}")))
+                .await
+                .unwrap();
+            for status in history {
+                db.update_status(id, status).await.unwrap();
+            }
+        }
+
+        let average = db
+            .average_interview_rounds_for_offers()
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(
+            (average - 4.0 / 3.0).abs() < 1e-9,
+            "unexpected average {average}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_status_counts_by_month() {
+        let db = create_test_db().await;
+        for (status, (year, month, day)) in [
+            (Status::Applied, (2024, 1, 5)),
+            (Status::Applied, (2024, 1, 20)),
+            (Status::Interview(1), (2024, 1, 21)),
+            (Status::Interview(3), (2024, 2, 1)),
+            (Status::Offer(90_000), (2024, 2, 29)),
+            (Status::Rejected, (2023, 12, 31)),
+        ] {
+            db.insert_job(&create_test_job().status(status).date(year, month, day))
+                .await
+                .unwrap();
+        }
+        let mut undated = create_test_job().status(Status::Rejected);
+        undated.date = None;
+        db.insert_job(&undated).await.unwrap();
+
+        let counts = db.status_counts_by_month().await.unwrap();
+        assert_eq!(
+            counts.keys().map(String::as_str).collect::<Vec<_>>(),
+            vec!["2023-12", "2024-01", "2024-02"]
+        );
+        assert_eq!(
+            counts["2023-12"],
+            HashMap::from([(StatusSelection::Rejected, 1)])
+        );
+        assert_eq!(
+            counts["2024-01"],
+            HashMap::from([
+                (StatusSelection::Applied, 2),
+                (StatusSelection::Interview, 1)
+            ])
+        );
+        assert_eq!(
+            counts["2024-02"],
+            HashMap::from([(StatusSelection::Interview, 1), (StatusSelection::Offer, 1)])
+        );
+        assert!(
+            db.status_counts_by_month()
+                .await
+                .unwrap()
+                .values()
+                .all(|month| !month.is_empty())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_jobs_grouped_by_month() {
+        let db = create_test_db().await;
+        for (company, (year, month, day)) in [
+            ("March Late", (2024, 3, 28)),
+            ("January", (2024, 1, 15)),
+            ("March Early", (2024, 3, 2)),
+            ("Previous December", (2023, 12, 31)),
+        ] {
+            db.insert_job(&create_test_job().company(company).date(year, month, day))
+                .await
+                .unwrap();
+        }
+        let mut undated = create_test_job().company("Undated");
+        undated.date = None;
+        db.insert_job(&undated).await.unwrap();
+
+        let months = db.get_jobs_grouped_by_month().await.unwrap();
+        let grouped: Vec<(&str, Vec<&str>)> = months
+            .iter()
+            .map(|(month, jobs)| {
+                (
+                    month.as_str(),
+                    jobs.iter().map(|job| job.company.as_str()).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            grouped,
+            vec![
+                ("2023-12", vec!["Previous December"]),
+                ("2024-01", vec!["January"]),
+                ("2024-03", vec!["March Early", "March Late"]),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_jobs_missing_date() {
+        let db = create_test_db().await;
+        db.insert_job(&create_test_job().company("Dated"))
+            .await
+            .unwrap();
+        let mut undated = create_test_job().company("Undated");
+        undated.date = None;
+        let first = db.insert_job(&undated).await.unwrap();
+        let second = db
+            .insert_job(&undated.clone().position("Other"))
+            .await
+            .unwrap();
+
+        let missing = db.get_jobs_missing_date().await.unwrap();
+        let ids: Vec<Option<i64>> = missing.iter().map(|job| job.id).collect();
+        assert_eq!(ids, vec![Some(second), Some(first)]);
+        assert!(missing.iter().all(|job| job.date.is_none()));
+    }
+
+    #[tokio::test]
+    async fn test_get_ids_by_company() {
+        let db = create_test_db().await;
+        let first = db
+            .insert_job(&create_test_job().company("TechCorp"))
+            .await
+            .unwrap();
+        db.insert_job(&create_test_job().company("TechCorp Labs"))
+            .await
+            .unwrap();
+        let second = db
+            .insert_job(&create_test_job().company("TECHCORP").position("Manager"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            db.get_ids_by_company("techcorp").await.unwrap(),
+            vec![first, second]
+        );
+        assert!(db.get_ids_by_company("Nobody").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_jobs_by_company() {
+        let db = create_test_db().await;
+        for (company, position) in [
+            ("Acme", "Engineer"),
+            ("ACME", "Manager"),
+            ("Beta", "Engineer"),
+        ] {
+            db.insert_job(&create_test_job().company(company).position(position))
+                .await
+                .unwrap();
+        }
+
+        let acme = db.get_jobs_by_company("acme").await.unwrap();
+        let positions: Vec<&str> = acme.iter().map(|job| job.position.as_str()).collect();
+        assert_eq!(positions, vec!["Manager", "Engineer"]);
+        assert_eq!(db.get_jobs_by_company("Beta").await.unwrap().len(), 1);
+        assert!(db.get_jobs_by_company("Gamma").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_lowball_offers() {
+        let db = create_test_db().await;
+        let range = SalaryRange::new(80_000, 100_000);
+        for (company, status) in [
+            ("Below", Status::Offer(79_999)),
+            ("Way Below", Status::Offer(0)),
+            ("At Min", Status::Offer(80_000)),
+            ("Within", Status::Offer(90_000)),
+            ("Above", Status::Offer(120_000)),
+            ("Not An Offer", Status::Interview(2)),
+            ("Rejected", Status::Rejected),
+        ] {
+            let job = create_test_job()
+                .company(company)
+                .salary(range.clone())
+                .status(status);
+            db.insert_job(&job).await.unwrap();
+        }
+
+        let lowball = db.get_lowball_offers().await.unwrap();
+        let companies: Vec<&str> = lowball.iter().map(|j| j.company.as_str()).collect();
+        assert_eq!(companies, vec!["Way Below", "Below"]);
+    }
+
+    #[tokio::test]
+    async fn test_created_at_read_back() {
+        let db = create_test_db().await;
+        let before = OffsetDateTime::now_utc() - time::Duration::seconds(1);
+        let id = db.insert_job(&create_test_job()).await.unwrap();
+        let created_at = db.get_job_by_id(id).await.unwrap().created_at.unwrap();
+        assert!(created_at >= before.replace_nanosecond(0).unwrap());
+        assert!(created_at <= OffsetDateTime::now_utc());
+    }
+
+    #[tokio::test]
+    async fn test_get_jobs_created_between() {
+        let db = create_test_db().await;
+        for (company, created_at) in [
+            ("Before", "2024-03-09 23:59:59"),
+            ("Start", "2024-03-10 00:00:00"),
+            ("Middle", "2024-03-12 09:30:00"),
+            ("End", "2024-03-15 12:00:00"),
+            ("After", "2024-03-15 12:00:01"),
+        ] {
+            let id = db
+                .insert_job(&create_test_job().company(company))
+                .await
+                .unwrap();
+            sqlx::query("UPDATE job_applications SET created_at = ? WHERE id = ?")
+                .bind(created_at)
+                .bind(id)
+                .execute(&db.pool)
+                .await
+                .unwrap();
+        }
+
+        // Bounds in another offset are compared in UTC.
+        let start = time::macros::datetime!(2024-03-10 01:00 +01:00);
+        let end = time::macros::datetime!(2024-03-15 12:00:00.750 UTC);
+        let jobs = db.get_jobs_created_between(start, end).await.unwrap();
+        let companies: Vec<&str> = jobs.iter().map(|j| j.company.as_str()).collect();
+        assert_eq!(companies, vec!["End", "Middle", "Start"]);
+        assert_eq!(
+            jobs[1].created_at,
+            Some(time::macros::datetime!(2024-03-12 09:30 UTC))
+        );
+
+        assert!(
+            db.get_jobs_created_between(end, start)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_replace_all() {
+        let db = create_test_db().await.with_cache();
+        db.insert_job(&create_test_job().company("Local"))
+            .await
+            .unwrap();
+        assert_eq!(db.get_all_jobs_cached().await.unwrap().len(), 1);
+
+        let mut remote = create_test_job().company("Remote A");
+        remote.id = Some(40);
+        let fresh = create_test_job().company("Remote B");
+        db.replace_all(&[remote, fresh]).await.unwrap();
+
+        let jobs = db.get_all_jobs_cached().await.unwrap();
+        assert_eq!(jobs.len(), 2);
+        assert!(jobs.iter().all(|job| job.company.starts_with("Remote")));
+        assert_eq!(db.get_job_by_id(40).await.unwrap().company, "Remote A");
+    }
+
+    #[tokio::test]
+    async fn test_replace_all_keeps_related_data_of_kept_jobs() {
+        let db = create_test_db().await;
+        let kept = db
+            .insert_job(&create_test_job().company("Kept"))
+            .await
+            .unwrap();
+        let dropped = db
+            .insert_job(&create_test_job().company("Dropped"))
+            .await
+            .unwrap();
+        let tomorrow = OffsetDateTime::now_utc() + Duration::days(1);
+        for id in [kept, dropped] {
+            db.add_tag(id, "remote").await.unwrap();
+            db.add_checklist_item(id, "Tailor resume").await.unwrap();
+            db.add_reminder(id, tomorrow, "Follow up").await.unwrap();
+        }
+
+        let mut job = db.get_job_by_id(kept).await.unwrap();
+        job.company = "Kept Renamed".to_string();
+        db.replace_all(&[job, create_test_job().company("Fresh")])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            db.get_job_by_id(kept).await.unwrap().company,
+            "Kept Renamed"
+        );
+        assert_eq!(db.get_tags(kept).await.unwrap(), ["remote"]);
+        assert_eq!(db.get_checklist_items(kept).await.unwrap().len(), 1);
+        assert_eq!(db.get_status_history(kept).await.unwrap().len(), 1);
+        let reminders = db
+            .get_due_reminders(tomorrow + Duration::days(1))
+            .await
+            .unwrap();
+        assert_eq!(
+            reminders.iter().map(|r| r.job_id).collect::<Vec<_>>(),
+            [kept]
+        );
+
+        assert!(matches!(
+            db.get_job_by_id(dropped).await,
+            Err(DbError::NotFound(_))
+        ));
+        assert!(db.get_tags(dropped).await.unwrap().is_empty());
+        assert!(db.get_checklist_items(dropped).await.unwrap().is_empty());
+        assert_eq!(db.get_all_jobs().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_replace_all_failure_keeps_original_data() {
+        let db = create_test_db().await;
+        let id = db
+            .insert_job(&create_test_job().company("Original"))
+            .await
+            .unwrap();
+
+        let mut first = create_test_job().company("Remote A");
+        first.id = Some(7);
+        let mut duplicate = create_test_job().company("Remote B");
+        duplicate.id = Some(7);
+        assert!(matches!(
+            db.replace_all(&[first, duplicate]).await,
+            Err(DbError::InvalidArgument(_))
+        ));
+
+        let jobs = db.get_all_jobs().await.unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].id, Some(id));
+        assert_eq!(jobs[0].company, "Original");
+    }
+
+    #[tokio::test]
+    async fn test_jobs_changed_today() {
+        let db = create_test_db().await;
+        let untouched = db
+            .insert_job(&create_test_job().company("Yesterday"))
+            .await
+            .unwrap();
+        let edited = db
+            .insert_job(&create_test_job().company("Edited"))
+            .await
+            .unwrap();
+        let fresh = db
+            .insert_job(&create_test_job().company("Fresh"))
+            .await
+            .unwrap();
+
+        sqlx::query("UPDATE job_applications SET updated_at = '2024-03-09 23:59:59.999'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "UPDATE job_applications SET updated_at = '2024-03-10 08:15:00.250' WHERE id = ?",
+        )
+        .bind(fresh)
+        .execute(&db.pool)
+        .await
+        .unwrap();
+
+        let today = time::macros::date!(2024 - 03 - 10);
+        let changed = db.jobs_changed_today(today).await.unwrap();
+        assert_eq!(
+            changed.iter().map(|j| j.id).collect::<Vec<_>>(),
+            vec![Some(fresh)]
+        );
+
+        db.update_status(edited, &Status::Interview(1))
+            .await
+            .unwrap();
+        let now = OffsetDateTime::now_utc().date();
+        let changed = db.jobs_changed_today(now).await.unwrap();
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].id, Some(edited));
+        assert!(changed.iter().all(|j| j.id != Some(untouched)));
+    }
+
+    async fn seed_import_collision(db: &Database) -> (i64, Vec<JobApplication>) {
+        let existing = db
+            .insert_job(&create_job_with_params(
+                "TechCorp",
+                "Engineer",
+                "Remote",
+                80_000,
+                100_000,
+                Status::Applied,
+            ))
+            .await
+            .unwrap();
+        let imported = vec![
+            create_job_with_params(
+                "techcorp",
+                "engineer",
+                "Berlin",
+                90_000,
+                110_000,
+                Status::Interview(1),
+            ),
+            create_job_with_params(
+                "NewCo",
+                "Designer",
+                "Austin",
+                70_000,
+                90_000,
+                Status::Applied,
+            ),
+        ];
+        (existing, imported)
+    }
+
+    #[tokio::test]
+    async fn test_import_jobs_skip() {
+        let db = create_test_db().await;
+        let (existing, imported) = seed_import_collision(&db).await;
+
+        let summary = db
+            .import_jobs(&imported, ImportStrategy::Skip)
+            .await
+            .unwrap();
+        assert_eq!(
+            summary,
+            ImportSummary {
+                inserted: 1,
+                updated: 0,
+                skipped: 1
+            }
+        );
+        assert_eq!(db.get_all_jobs().await.unwrap().len(), 2);
+        assert_eq!(db.get_job_by_id(existing).await.unwrap().location, "Remote");
+    }
+
+    #[tokio::test]
+    async fn test_import_jobs_overwrite() {
+        let db = create_test_db().await;
+        let (existing, imported) = seed_import_collision(&db).await;
+
+        let summary = db
+            .import_jobs(&imported, ImportStrategy::Overwrite)
+            .await
+            .unwrap();
+        assert_eq!(
+            summary,
+            ImportSummary {
+                inserted: 1,
+                updated: 1,
+                skipped: 0
+            }
+        );
+        assert_eq!(db.get_all_jobs().await.unwrap().len(), 2);
+        let overwritten = db.get_job_by_id(existing).await.unwrap();
+        assert_eq!(overwritten.location, "Berlin");
+        assert_eq!(overwritten.status, Status::Interview(1));
+    }
+
+    #[tokio::test]
+    async fn test_import_jobs_duplicate() {
+        let db = create_test_db().await;
+        let (existing, imported) = seed_import_collision(&db).await;
+
+        let summary = db
+            .import_jobs(&imported, ImportStrategy::Duplicate)
+            .await
+            .unwrap();
+        assert_eq!(
+            summary,
+            ImportSummary {
+                inserted: 2,
+                updated: 0,
+                skipped: 0
+            }
+        );
+        assert_eq!(db.get_all_jobs().await.unwrap().len(), 3);
+        assert_eq!(db.get_job_by_id(existing).await.unwrap().location, "Remote");
+    }
+
+    #[tokio::test]
+    async fn test_get_jobs_by_year() {
+        let db = create_test_db().await;
+        for (company, year, month) in [
+            ("A", 2023, 12),
+            ("B", 2024, 1),
+            ("C", 2024, 6),
+            ("D", 2023, 2),
+        ] {
+            db.insert_job(&create_test_job().company(company).date(year, month, 15))
+                .await
+                .unwrap();
+        }
+        let mut undated = create_test_job().company("Undated");
+        undated.date = None;
+        db.insert_job(&undated).await.unwrap();
+
+        let companies =
+            |jobs: Vec<JobApplication>| jobs.into_iter().map(|job| job.company).collect::<Vec<_>>();
+        assert_eq!(
+            companies(db.get_jobs_by_year(2024).await.unwrap()),
+            vec!["C", "B"]
+        );
+        assert_eq!(
+            companies(db.get_jobs_by_year(2023).await.unwrap()),
+            vec!["A", "D"]
+        );
+        assert!(db.get_jobs_by_year(2022).await.unwrap().is_empty());
     }
 
     #[tokio::test]
-    async fn test_database_creation() {
-        let _db = create_test_db().await;
+    async fn test_average_salary_by_status() {
+        let db = create_test_db().await;
+        let seeded = [
+            (60_000, 80_000, Status::Applied),          // 70k
+            (80_000, 100_000, Status::Applied),         // 90k
+            (90_000, 110_000, Status::Interview(1)),    // 100k
+            (100_000, 140_000, Status::Interview(3)),   // 120k
+            (120_000, 125_000, Status::Offer(121_000)), // 122.5k
+        ];
+        for (min, max, status) in seeded {
+            db.insert_job(&create_job_with_params(
+                "Co", "Dev", "Remote", min, max, status,
+            ))
+            .await
+            .unwrap();
+        }
+
+        let averages = db
+            .average_salary_by_status(&CurrencyRates::default())
+            .await
+            .unwrap();
+        assert_eq!(averages.len(), 3);
+        assert!(!averages.contains_key(&StatusSelection::Rejected));
+        for (selection, expected) in [
+            (StatusSelection::Applied, 80_000.0),
+            (StatusSelection::Interview, 110_000.0),
+            (StatusSelection::Offer, 122_500.0),
+        ] {
+            let actual = averages[&selection];
+            assert!((actual - expected).abs() < 1e-6, "{selection}: {actual}");
+        }
     }
 
     #[tokio::test]
-    async fn test_directory_creation_debug() {
-        let _ = fs::remove_dir_all("debug_test");
-
-        let db_path = "sqlite:debug_test/test.db";
-        println!("Creating database at: {db_path}");
+    async fn test_average_salary_by_status_empty() {
+        let db = create_test_db().await;
+        assert!(
+            db.average_salary_by_status(&CurrencyRates::default())
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
 
-        // Test directory creation manually first
-        let path = std::path::Path::new("debug_test/test.db");
-        if let Some(parent) = path.parent() {
-            println!("Parent directory: {parent:?}");
-            println!("Parent exists: {}", parent.exists());
-            std::fs::create_dir_all(parent).unwrap();
-            println!("Directory created successfully");
+    #[tokio::test]
+    async fn test_stats_convert_to_configured_currency() {
+        let config =
+            crate::config::Config::parse("currency.base = eur\ncurrency.rate.usd = 0.5\n").unwrap();
+        let rates = &config.currency;
+        let db = create_test_db().await;
+        for salary in [
+            SalaryRange::with_currency(100_000, 140_000, "USD"), // 60k / 70k EUR
+            SalaryRange::with_currency(60_000, 80_000, "EUR"),
+        ] {
+            db.insert_job(&create_test_job().salary(salary).status(Status::Applied))
+                .await
+                .unwrap();
         }
 
-        let _db = Database::new(db_path).await.unwrap();
-
-        assert!(std::path::Path::new("debug_test/test.db").exists());
+        let averages = db.average_salary_by_status(rates).await.unwrap();
+        assert!((averages[&StatusSelection::Applied] - 65_000.0).abs() < 1e-6);
+        let stats = db.stats(rates).await.unwrap();
+        assert!((stats.avg_salary_max - 75_000.0).abs() < 1e-6);
+        let value = db.expected_pipeline_value(rates).await.unwrap();
+        assert!((value - 6_500.0).abs() < 1e-6);
 
-        let _ = fs::remove_dir_all("debug_test");
+        db.insert_job(&create_test_job().salary(SalaryRange::with_currency(50_000, 60_000, "gbp")))
+            .await
+            .unwrap();
+        assert!(matches!(
+            db.average_salary_by_status(rates).await,
+            Err(DbError::MissingExchangeRate(currency)) if currency == "GBP"
+        ));
+        assert!(matches!(
+            db.stats(rates).await,
+            Err(DbError::MissingExchangeRate(_))
+        ));
+        assert!(matches!(
+            db.expected_pipeline_value(rates).await,
+            Err(DbError::MissingExchangeRate(_))
+        ));
     }
 
     #[tokio::test]
-    async fn test_persistent_database_creation() {
-        let (db, test_dir) = create_test_db_at_path("db_creation").await;
-
-        // Verify the database file was created
-        assert!(std::path::Path::new(&format!("{test_dir}/test.db")).exists());
+    async fn test_get_other_jobs() {
+        let db = create_test_db().await;
+        let mut ids = Vec::new();
+        for company in ["A", "B", "C"] {
+            ids.push(
+                db.insert_job(&create_test_job().company(company))
+                    .await
+                    .unwrap(),
+            );
+        }
 
-        // Database should start empty
-        let jobs = db.get_all_jobs().await.unwrap();
-        assert_eq!(jobs.len(), 0);
+        let others = db.get_other_jobs(ids[1]).await.unwrap();
+        let mut other_ids: Vec<i64> = others.iter().filter_map(|job| job.id).collect();
+        other_ids.sort_unstable();
+        assert_eq!(other_ids, vec![ids[0], ids[2]]);
 
-        cleanup_test_files(&test_dir).await;
+        assert_eq!(db.get_other_jobs(999).await.unwrap().len(), 3);
     }
 
     #[tokio::test]
-    async fn test_persistent_database_empty_start() {
-        let (db, test_dir) = create_test_db_at_path("db_empty_start").await;
-
-        let jobs = db.get_all_jobs().await.unwrap();
-        assert_eq!(jobs.len(), 0);
+    async fn test_count_by_canonical_company() {
+        let db = create_test_db().await;
+        for job in [
+            create_test_job().company("Google"),
+            create_test_job().company("Google Inc."),
+            create_test_job()
+                .company("Alphabet")
+                .company_canonical("google"),
+            create_test_job().company("Acme LLC"),
+        ] {
+            db.insert_job(&job).await.unwrap();
+        }
 
-        cleanup_test_files(&test_dir).await;
+        let counts = db.count_by_canonical_company().await.unwrap();
+        assert_eq!(
+            counts,
+            vec![("google".to_string(), 3), ("acme".to_string(), 1)]
+        );
     }
 
     #[tokio::test]
-    async fn test_persistent_database_data_survives_reconnect() {
-        let test_dir = get_unique_test_dir("db_data_survives");
-        cleanup_test_files(&test_dir).await;
-        let db_path = format!("sqlite:{test_dir}/test.db");
-        let test_job = create_test_job();
-
-        let _job_id = {
-            let db = Database::new(&db_path).await.unwrap();
-            let id = db.insert_job(&test_job).await.unwrap();
-            assert!(id > 0);
-            id
-        };
-
-        // Reconnect to the same database
-        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
-        let db2 = Database::new(&db_path).await.unwrap();
-        let jobs = db2.get_all_jobs().await.unwrap();
-        assert_eq!(jobs.len(), 1);
-        assert_job_equals_ignoring_id(&jobs[0], &test_job);
+    async fn test_company_canonical_round_trip() {
+        let db = create_test_db().await;
+        let id = db
+            .insert_job(
+                &create_test_job()
+                    .company("YouTube")
+                    .company_canonical("google"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            db.get_job_by_id(id)
+                .await
+                .unwrap()
+                .company_canonical
+                .as_deref(),
+            Some("google")
+        );
 
-        cleanup_test_files(&test_dir).await;
+        let mut job = db.get_job_by_id(id).await.unwrap();
+        job.company_canonical = None;
+        db.update_job(&job).await.unwrap();
+        assert_eq!(db.get_job_by_id(id).await.unwrap().company_canonical, None);
     }
 
     #[tokio::test]
-    async fn test_persistent_database_multiple_operations() {
-        let (db, test_dir) = create_test_db_at_path("db_multiple_ops").await;
+    async fn test_rejection_reason_counts() {
+        let db = create_test_db().await;
+        let rejected = || create_test_job().status(Status::Rejected);
+        for job in [
+            rejected().rejection_reason("Position filled"),
+            rejected().rejection_reason("Salary mismatch"),
+            rejected().rejection_reason("Position filled"),
+            rejected(),
+            create_test_job(),
+        ] {
+            db.insert_job(&job).await.unwrap();
+        }
 
-        let job1 = create_test_job();
-        let job2 = create_job_with_params(
-            "Another Corp",
-            "Data Scientist",
-            "New York",
-            90_000,
-            130_000,
-            Status::Interview(2),
+        let counts = db.rejection_reason_counts().await.unwrap();
+        assert_eq!(
+            counts,
+            vec![
+                ("Position filled".to_string(), 2),
+                ("Salary mismatch".to_string(), 1),
+            ]
         );
+    }
 
-        let id1 = db.insert_job(&job1).await.unwrap();
-        let id2 = db.insert_job(&job2).await.unwrap();
-
-        let all_jobs = db.get_all_jobs().await.unwrap();
-        assert_eq!(all_jobs.len(), 2);
-
-        let mut updated_job = job1.clone();
-        updated_job.id = Some(id1);
-        updated_job.status = Status::Offer(95_000);
-        db.update_job(&updated_job).await.unwrap();
-
-        let retrieved_job = db.get_job_by_id(id1).await.unwrap();
-        assert_eq!(retrieved_job.status, Status::Offer(95_000));
+    #[tokio::test]
+    async fn test_rejection_reason_counts_empty() {
+        let db = create_test_db().await;
+        db.insert_job(&create_test_job().status(Status::Rejected))
+            .await
+            .unwrap();
+        assert!(db.rejection_reason_counts().await.unwrap().is_empty());
+    }
 
-        db.delete_job(id2).await.unwrap();
+    #[tokio::test]
+    async fn test_get_due_reminders() {
+        let db = create_test_db().await;
+        let job_id = db.insert_job(&create_test_job()).await.unwrap();
+        let now = time::macros::datetime!(2024-06-01 12:00 UTC);
 
-        let jobs_after_delete = db.get_all_jobs().await.unwrap();
-        assert_eq!(jobs_after_delete.len(), 1);
-        assert_eq!(jobs_after_delete[0].id, Some(id1));
+        db.add_reminder(job_id, now + Duration::hours(1), "Not yet")
+            .await
+            .unwrap();
+        db.add_reminder(job_id, now, "Due now").await.unwrap();
+        db.add_reminder(job_id, now - Duration::days(2), "Overdue")
+            .await
+            .unwrap();
 
-        cleanup_test_files(&test_dir).await;
+        let due = db.get_due_reminders(now).await.unwrap();
+        let messages: Vec<&str> = due.iter().map(|r| r.message.as_str()).collect();
+        assert_eq!(messages, vec!["Overdue", "Due now"]);
+        assert!(due.iter().all(|r| r.job_id == job_id));
+        assert_eq!(due[1].remind_at, now);
     }
 
     #[tokio::test]
-    async fn test_directory_creation() {
-        let test_dir = get_unique_test_dir("dir_creation");
-        cleanup_test_files(&test_dir).await;
-        let db_path = format!("sqlite:{test_dir}/deeply/nested/test_data/test_dir.db");
-        let _db = Database::new(&db_path).await.unwrap();
-
-        assert!(
-            std::path::Path::new(&format!("{test_dir}/deeply/nested/test_data/test_dir.db"))
-                .exists()
-        );
+    async fn test_delete_reminder() {
+        let db = create_test_db().await;
+        let job_id = db.insert_job(&create_test_job()).await.unwrap();
+        let now = OffsetDateTime::now_utc();
+        let id = db.add_reminder(job_id, now, "Follow up").await.unwrap();
 
-        cleanup_test_files(&test_dir).await;
+        db.delete_reminder(id).await.unwrap();
+        assert!(db.get_due_reminders(now).await.unwrap().is_empty());
+        assert!(matches!(
+            db.delete_reminder(id).await,
+            Err(DbError::NotFound(_))
+        ));
     }
 
     #[tokio::test]
-    async fn test_database_schema_persistence() {
-        let test_dir = get_unique_test_dir("db_schema_persist");
-        cleanup_test_files(&test_dir).await;
-        let db_path = format!("sqlite:{test_dir}/test.db");
+    async fn test_reminders_deleted_with_job() {
+        let db = create_test_db().await;
+        let job_id = db.insert_job(&create_test_job()).await.unwrap();
+        let now = OffsetDateTime::now_utc();
+        db.add_reminder(job_id, now, "Follow up").await.unwrap();
 
-        let complex_job = JobApplication::new()
-            .company("Complex Corp")
-            .position("Full Stack Engineer")
-            .location("San Francisco, CA")
-            .salary(SalaryRange::new(100_000, 150_000))
-            .status(Status::Interview(3))
-            .date(2024, 2, 15)
-            .cv("path/to/complex_cv.pdf");
+        db.delete_job(job_id).await.unwrap();
+        assert!(db.get_due_reminders(now).await.unwrap().is_empty());
+        assert!(db.add_reminder(job_id, now, "Orphan").await.is_err());
+    }
 
-        {
-            let db = Database::new(&db_path).await.unwrap();
-            db.insert_job(&complex_job).await.unwrap();
+    #[cfg(feature = "regex")]
+    #[tokio::test]
+    async fn test_search_company_regex() {
+        let db = create_test_db().await;
+        for company in ["Google", "Google Inc.", "Alphabet", "Goodyear"] {
+            db.insert_job(&create_test_job().company(company))
+                .await
+                .unwrap();
         }
 
-        // Reconnect and verify data persistence
-        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
-        let db2 = Database::new(&db_path).await.unwrap();
-        let jobs = db2.get_all_jobs().await.unwrap();
-        assert_eq!(jobs.len(), 1);
+        let mut companies: Vec<String> = db
+            .search_company_regex(r"^(Google|Alpha)")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|job| job.company)
+            .collect();
+        companies.sort();
+        assert_eq!(companies, vec!["Alphabet", "Google", "Google Inc."]);
+    }
 
-        let job = &jobs[0];
-        assert_eq!(job.company, "Complex Corp");
-        assert_eq!(job.position, "Full Stack Engineer");
-        assert_eq!(job.location, "San Francisco, CA");
-        assert_eq!(job.salary.min, 100_000);
-        assert_eq!(job.salary.max, 150_000);
-        assert_eq!(job.status, Status::Interview(3));
-        assert_eq!(job.date.unwrap().to_string(), "2024-02-15");
-        assert_eq!(job.cv, Some(PathBuf::from("path/to/complex_cv.pdf")));
+    #[cfg(feature = "regex")]
+    #[tokio::test]
+    async fn test_search_company_regex_invalid_pattern() {
+        let db = create_test_db().await;
+        let result = db.search_company_regex("(unclosed").await;
+        assert!(matches!(result, Err(DbError::InvalidPattern(_))));
+    }
 
-        cleanup_test_files(&test_dir).await;
+    #[tokio::test]
+    async fn test_updated_at_read_back() {
+        let db = create_test_db().await;
+        let id = db.insert_job(&create_test_job()).await.unwrap();
+        assert!(db.get_job_by_id(id).await.unwrap().updated_at.is_some());
+
+        sqlx::query("UPDATE job_applications SET updated_at = '2024-03-01 12:00:00.500'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        let job = db.get_job_by_id(id).await.unwrap();
+        assert_eq!(
+            job.updated_at,
+            Some(time::macros::datetime!(2024-03-01 12:00:00.5 UTC))
+        );
+        assert_eq!(
+            job.days_in_status(time::macros::datetime!(2024-03-05 13:00 UTC)),
+            Some(4)
+        );
     }
 
     #[tokio::test]
-    async fn test_insert_and_get_job() {
+    async fn test_search_all_matches_any_text_column() {
         let db = create_test_db().await;
-        let job = create_test_job();
+        let by_reason = db
+            .insert_job(
+                &create_test_job()
+                    .company("Acme")
+                    .status(Status::Rejected)
+                    .rejection_reason("Needed more Kubernetes experience"),
+            )
+            .await
+            .unwrap();
+        let by_cv = db
+            .insert_job(
+                &create_test_job()
+                    .company("Initech")
+                    .cv("cvs/kubernetes_cv.pdf"),
+            )
+            .await
+            .unwrap();
+        db.insert_job(&create_test_job().company("Globex"))
+            .await
+            .unwrap();
 
-        let id = db.insert_job(&job).await.unwrap();
-        assert!(id > 0);
+        let mut ids: Vec<i64> = db
+            .search_all("KUBERNETES")
+            .await
+            .unwrap()
+            .iter()
+            .filter_map(|job| job.id)
+            .collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec![by_reason, by_cv]);
+    }
 
-        let retrieved_job = db.get_job_by_id(id).await.unwrap();
-        assert_eq!(retrieved_job.company, job.company);
-        assert_eq!(retrieved_job.position, job.position);
-        assert_eq!(retrieved_job.location, job.location);
-        assert_eq!(retrieved_job.salary, job.salary);
-        assert_eq!(retrieved_job.status, job.status);
-        assert_eq!(retrieved_job.date, job.date);
+    #[tokio::test]
+    async fn test_search_all_matches_url() {
+        let db = create_test_db().await;
+        let posting = db
+            .insert_job(&create_test_job().url("https://jobs.example.com/postings/4217"))
+            .await
+            .unwrap();
+        db.insert_job(&create_test_job()).await.unwrap();
+
+        let jobs = db.search_all("postings/4217").await.unwrap();
+        assert_eq!(
+            jobs.iter().map(|job| job.id).collect::<Vec<_>>(),
+            vec![Some(posting)]
+        );
     }
 
     #[tokio::test]
-    async fn test_get_all_jobs() {
+    async fn test_search_jobs_partial_and_case_insensitive() {
         let db = create_test_db().await;
+        let rust = db
+            .insert_job(&create_test_job().position("Senior Rust Developer"))
+            .await
+            .unwrap();
+        let berlin = db
+            .insert_job(&create_test_job().location("Berlin, Germany"))
+            .await
+            .unwrap();
+        db.insert_job(&create_test_job().cv("cvs/rust_cv.pdf"))
+            .await
+            .unwrap();
 
-        let job1 = create_test_job();
-        let job2 = JobApplication::new()
-            .company("Another Corp")
-            .position("Data Scientist")
-            .location("New York")
-            .salary(SalaryRange::new(90_000, 130_000))
-            .status(Status::Interview(2));
+        let jobs = db.search_jobs("rUsT").await.unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].id, Some(rust));
 
-        db.insert_job(&job1).await.unwrap();
-        db.insert_job(&job2).await.unwrap();
+        let jobs = db.search_jobs("erl").await.unwrap();
+        assert_eq!(
+            jobs.iter().map(|job| job.id).collect::<Vec<_>>(),
+            vec![Some(berlin)]
+        );
 
-        let all_jobs = db.get_all_jobs().await.unwrap();
-        assert_eq!(all_jobs.len(), 2);
+        assert_eq!(db.search_jobs("test corp").await.unwrap().len(), 3);
+        assert!(
+            db.search_jobs("nothing like this")
+                .await
+                .unwrap()
+                .is_empty()
+        );
     }
 
     #[tokio::test]
-    async fn test_update_job() {
+    async fn test_search_jobs_escapes_wildcards() {
         let db = create_test_db().await;
-        let mut job = create_test_job();
+        db.insert_job(&create_test_job().company("100% Remote"))
+            .await
+            .unwrap();
+        db.insert_job(&create_test_job().company("1000 Remote"))
+            .await
+            .unwrap();
+        db.insert_job(&create_test_job().position("back_end"))
+            .await
+            .unwrap();
+        db.insert_job(&create_test_job().position("backXend"))
+            .await
+            .unwrap();
 
-        let id = db.insert_job(&job).await.unwrap();
-        job.id = Some(id);
-        job.company = "Updated Corp".to_string();
-        job.status = Status::Interview(1);
+        let percent = db.search_jobs("100%").await.unwrap();
+        assert_eq!(percent.len(), 1);
+        assert_eq!(percent[0].company, "100% Remote");
 
-        db.update_job(&job).await.unwrap();
+        let underscore = db.search_jobs("k_e").await.unwrap();
+        assert_eq!(underscore.len(), 1);
+        assert_eq!(underscore[0].position, "back_end");
 
-        let updated_job = db.get_job_by_id(id).await.unwrap();
-        assert_eq!(updated_job.company, "Updated Corp");
-        assert_eq!(updated_job.status, Status::Interview(1));
+        assert_eq!(db.search_jobs("%").await.unwrap().len(), 1);
     }
 
     #[tokio::test]
-    async fn test_delete_job() {
+    async fn test_search_all_escapes_wildcards() {
         let db = create_test_db().await;
-        let job = create_test_job();
+        db.insert_job(&create_test_job().company("100% Remote"))
+            .await
+            .unwrap();
+        db.insert_job(&create_test_job().company("1000 Remote"))
+            .await
+            .unwrap();
+        db.insert_job(&create_test_job().company("snake_case Inc"))
+            .await
+            .unwrap();
+        db.insert_job(&create_test_job().company("snakeXcase Inc"))
+            .await
+            .unwrap();
 
-        let id = db.insert_job(&job).await.unwrap();
-        db.delete_job(id).await.unwrap();
+        let percent = db.search_all("100%").await.unwrap();
+        assert_eq!(percent.len(), 1);
+        assert_eq!(percent[0].company, "100% Remote");
 
-        let result = db.get_job_by_id(id).await;
-        assert!(matches!(result, Err(DbError::NotFound(_))));
+        let underscore = db.search_all("e_c").await.unwrap();
+        assert_eq!(underscore.len(), 1);
+        assert_eq!(underscore[0].company, "snake_case Inc");
     }
 
     #[tokio::test]
-    async fn test_clear_all() {
+    async fn test_checklist_items_add_and_toggle() {
         let db = create_test_db().await;
+        let job_id = db.insert_job(&create_test_job()).await.unwrap();
+        let other_job = db.insert_job(&create_test_job()).await.unwrap();
 
-        db.insert_job(&create_test_job()).await.unwrap();
-        db.insert_job(&create_test_job()).await.unwrap();
+        let resume = db
+            .add_checklist_item(job_id, "Tailor resume")
+            .await
+            .unwrap();
+        db.add_checklist_item(job_id, "Send thank-you email")
+            .await
+            .unwrap();
+        db.add_checklist_item(other_job, "Unrelated").await.unwrap();
 
-        let jobs_before = db.get_all_jobs().await.unwrap();
-        assert_eq!(jobs_before.len(), 2);
+        let items = db.get_checklist_items(job_id).await.unwrap();
+        let texts: Vec<&str> = items.iter().map(|item| item.text.as_str()).collect();
+        assert_eq!(texts, vec!["Tailor resume", "Send thank-you email"]);
+        assert!(items.iter().all(|item| !item.done));
+        assert_eq!(checklist_completion(&items), 0);
 
-        db.clear_all().await.unwrap();
+        db.set_checklist_item_done(resume, true).await.unwrap();
+        let items = db.get_checklist_items(job_id).await.unwrap();
+        assert!(items[0].done);
+        assert_eq!(checklist_completion(&items), 50);
 
-        let jobs_after = db.get_all_jobs().await.unwrap();
-        assert_eq!(jobs_after.len(), 0);
+        db.set_checklist_item_done(resume, false).await.unwrap();
+        assert!(!db.get_checklist_items(job_id).await.unwrap()[0].done);
+
+        assert!(matches!(
+            db.set_checklist_item_done(999, true).await,
+            Err(DbError::NotFound(999))
+        ));
     }
 
     #[tokio::test]
-    async fn test_job_with_cv_path() {
+    async fn test_checklist_items_delete() {
         let db = create_test_db().await;
-        let job = create_test_job().cv("path/to/resume.pdf");
+        let job_id = db.insert_job(&create_test_job()).await.unwrap();
+        let id = db
+            .add_checklist_item(job_id, "Research team")
+            .await
+            .unwrap();
+        db.add_checklist_item(job_id, "Prepare questions")
+            .await
+            .unwrap();
 
-        let id = db.insert_job(&job).await.unwrap();
-        let retrieved_job = db.get_job_by_id(id).await.unwrap();
+        db.delete_checklist_item(id).await.unwrap();
+        assert_eq!(db.get_checklist_items(job_id).await.unwrap().len(), 1);
+        assert!(matches!(
+            db.delete_checklist_item(id).await,
+            Err(DbError::NotFound(_))
+        ));
 
-        assert_eq!(retrieved_job.cv, Some(PathBuf::from("path/to/resume.pdf")));
+        db.delete_job(job_id).await.unwrap();
+        assert!(db.get_checklist_items(job_id).await.unwrap().is_empty());
     }
 
-    #[rstest]
-    #[case(Status::Applied)]
-    #[case(Status::Interview(1))]
-    #[case(Status::Interview(3))]
-    #[case(Status::Offer(95_000))]
-    #[case(Status::Rejected)]
     #[tokio::test]
-    async fn test_job_status_persistence(#[case] status: Status) {
+    async fn test_tags_add_list_and_filter() {
         let db = create_test_db().await;
-        let job = create_test_job().status(status.clone());
+        let backend = db
+            .insert_job(&create_test_job().company("Backend Co"))
+            .await
+            .unwrap();
+        let frontend = db
+            .insert_job(&create_test_job().company("Frontend Co"))
+            .await
+            .unwrap();
 
-        let id = db.insert_job(&job).await.unwrap();
-        let retrieved_job = db.get_job_by_id(id).await.unwrap();
+        db.add_tag(backend, "remote").await.unwrap();
+        db.add_tag(backend, "backend").await.unwrap();
+        db.add_tag(backend, "remote").await.unwrap();
+        db.add_tag(frontend, "remote").await.unwrap();
 
-        assert_eq!(retrieved_job.status, status);
+        assert_eq!(
+            db.get_tags(backend).await.unwrap(),
+            vec!["backend", "remote"]
+        );
+        assert_eq!(db.get_tags(frontend).await.unwrap(), vec!["remote"]);
+
+        let remote = db.get_jobs_with_tag("remote").await.unwrap();
+        let companies: Vec<&str> = remote.iter().map(|job| job.company.as_str()).collect();
+        assert_eq!(companies, vec!["Frontend Co", "Backend Co"]);
+        let backend_jobs = db.get_jobs_with_tag("backend").await.unwrap();
+        assert_eq!(backend_jobs.len(), 1);
+        assert_eq!(backend_jobs[0].id, Some(backend));
+        assert!(db.get_jobs_with_tag("dream-job").await.unwrap().is_empty());
+
+        assert!(db.add_tag(999, "remote").await.is_err());
     }
 
     #[tokio::test]
-    async fn test_update_nonexistent_job() {
+    async fn test_remove_tag_keeps_tags_still_in_use() {
         let db = create_test_db().await;
-        let mut job = create_test_job();
-        job.id = Some(999);
+        let first = db.insert_job(&create_test_job()).await.unwrap();
+        let second = db.insert_job(&create_test_job()).await.unwrap();
+        db.add_tag(first, "remote").await.unwrap();
+        db.add_tag(second, "remote").await.unwrap();
+        db.add_tag(first, "dream-job").await.unwrap();
+        let tag_count = || async {
+            sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM tags")
+                .fetch_one(&db.pool)
+                .await
+                .unwrap()
+        };
 
-        let result = db.update_job(&job).await;
-        assert!(matches!(result, Err(DbError::NotFound(999))));
+        db.remove_tag(first, "remote").await.unwrap();
+        assert_eq!(db.get_tags(first).await.unwrap(), vec!["dream-job"]);
+        assert_eq!(db.get_jobs_with_tag("remote").await.unwrap().len(), 1);
+        assert_eq!(tag_count().await, 2);
+
+        db.remove_tag(first, "dream-job").await.unwrap();
+        assert!(db.get_tags(first).await.unwrap().is_empty());
+        assert_eq!(tag_count().await, 1);
+
+        db.remove_tag(first, "not-a-tag").await.unwrap();
+        db.delete_job(second).await.unwrap();
+        assert!(db.get_jobs_with_tag("remote").await.unwrap().is_empty());
     }
 
     #[tokio::test]
-    async fn test_delete_nonexistent_job() {
+    async fn test_get_jobs_near_salary() {
         let db = create_test_db().await;
-        let result = db.delete_job(999).await;
-        assert!(matches!(result, Err(DbError::NotFound(999))));
+        let bands = [
+            ("Far Below", 40_000, 60_000),    // 50k, 50k away
+            ("Just Above", 100_000, 120_000), // 110k, 10k away
+            ("Exact", 90_000, 110_000),       // 100k, 0 away
+            ("Far Above", 180_000, 220_000),  // 200k, 100k away
+            ("Just Below", 85_000, 95_000),   // 90k, 10k away
+            ("Below", 70_000, 80_000),        // 75k, 25k away
+        ];
+        for (company, min, max) in bands {
+            db.insert_job(&create_job_with_params(
+                company,
+                "Dev",
+                "Remote",
+                min,
+                max,
+                Status::Applied,
+            ))
+            .await
+            .unwrap();
+        }
+
+        let companies: Vec<String> = db
+            .get_jobs_near_salary(100_000)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|job| job.company)
+            .collect();
+        assert_eq!(
+            companies,
+            vec![
+                "Exact",
+                "Just Below",
+                "Just Above",
+                "Below",
+                "Far Below",
+                "Far Above"
+            ]
+        );
     }
 
     #[tokio::test]
@@ -1,4 +1,5 @@
 use crate::db::DbError;
+use crate::model::JobApplication;
 use std::fmt;
 
 /// Comprehensive error types for the job tracker application.
@@ -108,6 +109,7 @@ pub type AppResult<T> = Result<T, AppError>;
 /// Validation error builder for input validation.
 ///
 /// Represents a validation error for a specific field with a descriptive message.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ValidationError {
     field: String,
     message: String,
@@ -135,6 +137,18 @@ impl ValidationError {
         }
     }
 
+    /// Returns the name of the field that failed validation.
+    #[must_use]
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    /// Returns the validation error message.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
     /// Converts the validation error to an `AppError`.
     ///
     /// # Examples
@@ -201,6 +215,60 @@ pub trait Validate {
     }
 }
 
+impl Validate for JobApplication {
+    /// Validates a job application.
+    ///
+    /// Checks that the company and position are not blank, that the minimum
+    /// salary does not exceed the maximum, that a contact email, if set,
+    /// contains an `@`, and that a posting URL, if set, uses `http://` or
+    /// `https://`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::error::Validate;
+    /// # use job_tracker::model::{JobApplication, SalaryRange};
+    /// let job = JobApplication::new()
+    ///     .company("TechCorp")
+    ///     .position("Engineer")
+    ///     .salary(SalaryRange::new(90_000, 60_000));
+    /// assert!(!job.is_valid());
+    /// ```
+    fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        if self.company.trim().is_empty() {
+            errors.push(ValidationError::new("company", "Company is required"));
+        }
+        if self.position.trim().is_empty() {
+            errors.push(ValidationError::new("position", "Position is required"));
+        }
+        if self.salary.min > self.salary.max {
+            errors.push(ValidationError::new(
+                "salary",
+                "Minimum cannot exceed maximum",
+            ));
+        }
+        if let Some(email) = &self.contact_email
+            && !email.contains('@')
+        {
+            errors.push(ValidationError::new(
+                "contact_email",
+                "Email address must contain @",
+            ));
+        }
+        if let Some(url) = &self.url
+            && !url.starts_with("http://")
+            && !url.starts_with("https://")
+        {
+            errors.push(ValidationError::new(
+                "url",
+                "URL must start with http:// or https://",
+            ));
+        }
+        errors
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -277,6 +345,73 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_job_application_salary_validation() {
+        use crate::model::SalaryRange;
+
+        let job = JobApplication::new()
+            .company("TechCorp")
+            .position("Engineer")
+            .salary(SalaryRange::new(90_000, 60_000));
+        let errors = job.validate();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field(), "salary");
+
+        let job = job.salary(SalaryRange::new(60_000, 90_000));
+        assert!(job.is_valid());
+    }
+
+    #[test]
+    fn test_job_application_contact_email_validation() {
+        let job = JobApplication::new()
+            .company("TechCorp")
+            .position("Engineer")
+            .contact_email("not-an-email");
+        let errors = job.validate();
+        let fields: Vec<&str> = errors.iter().map(ValidationError::field).collect();
+        assert_eq!(fields, vec!["contact_email"]);
+
+        assert!(job.contact_email("r@corp.com").is_valid());
+    }
+
+    #[test]
+    fn test_job_application_url_scheme_validation() {
+        let job = JobApplication::new()
+            .company("TechCorp")
+            .position("Engineer");
+        for url in ["http://corp.com/jobs/1", "https://corp.com/jobs/1"] {
+            assert!(job.clone().url(url).is_valid(), "{url} should be valid");
+        }
+        for url in ["corp.com/jobs/1", "ftp://corp.com/jobs/1", ""] {
+            let errors = job.clone().url(url).validate();
+            let fields: Vec<&str> = errors.iter().map(ValidationError::field).collect();
+            assert_eq!(fields, vec!["url"], "{url} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_job_application_requires_company() {
+        let job = JobApplication::new().company("  ").position("Engineer");
+        let errors = job.validate();
+        let fields: Vec<&str> = errors.iter().map(ValidationError::field).collect();
+        assert_eq!(fields, vec!["company"]);
+    }
+
+    #[test]
+    fn test_job_application_requires_position() {
+        let job = JobApplication::new().company("TechCorp");
+        let errors = job.validate();
+        let fields: Vec<&str> = errors.iter().map(ValidationError::field).collect();
+        assert_eq!(fields, vec!["position"]);
+    }
+
+    #[test]
+    fn test_job_application_requires_company_and_position() {
+        let errors = JobApplication::new().validate();
+        let fields: Vec<&str> = errors.iter().map(ValidationError::field).collect();
+        assert_eq!(fields, vec!["company", "position"]);
+    }
+
     #[test]
     fn test_validate_trait() {
         let valid_struct = TestStruct { value: 5 };
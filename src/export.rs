@@ -0,0 +1,207 @@
+use crate::db::{Database, DbError};
+use crate::model::JobApplication;
+use crate::ui::StatusSelection;
+use std::path::Path;
+
+impl Database {
+    /// Writes a Markdown report of every job application to `path`.
+    ///
+    /// The report is laid out by [`render_markdown`]. Parent directories are
+    /// created as needed and an existing file is overwritten.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Where to write the Markdown file
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database connection is lost
+    /// - The SQL query fails
+    /// - A stored row cannot be converted to a `JobApplication`
+    /// - The file cannot be created or written (`DbError::Io`)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use job_tracker::db::Database;
+    /// # use std::path::Path;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let db = Database::new("sqlite::memory:").await?;
+    /// db.export_markdown(Path::new("data/jobs.md")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn export_markdown(&self, path: &Path) -> Result<(), DbError> {
+        let jobs = self.get_all_jobs().await?;
+        let markdown = render_markdown(&jobs);
+
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, markdown)?;
+        Ok(())
+    }
+}
+
+/// Renders `jobs` as a Markdown report.
+///
+/// The report opens with a summary table counting jobs per status kind,
+/// followed by a `##` section per status kind with a table of its jobs, in
+/// funnel order. Status kinds without jobs are left out. Jobs keep their
+/// order within a section, and `|` in values is escaped.
+///
+/// # Examples
+///
+/// ```
+/// # use job_tracker::export::render_markdown;
+/// # use job_tracker::model::{JobApplication, Status};
+/// let jobs = [JobApplication::new().company("Acme").status(Status::Interview(2))];
+/// let report = render_markdown(&jobs);
+/// assert!(report.contains("| Interview | 1 |"));
+/// assert!(report.contains("## Interview"));
+/// ```
+#[must_use]
+pub fn render_markdown(jobs: &[JobApplication]) -> String {
+    let groups: Vec<(StatusSelection, Vec<&JobApplication>)> = StatusSelection::ALL
+        .into_iter()
+        .map(|selection| {
+            let members = jobs
+                .iter()
+                .filter(|job| StatusSelection::from_status(&job.status) == selection)
+                .collect();
+            (selection, members)
+        })
+        .filter(|(_, members): &(_, Vec<_>)| !members.is_empty())
+        .collect();
+
+    let mut lines = vec![
+        "# Job Applications".to_string(),
+        String::new(),
+        "## Summary".to_string(),
+        String::new(),
+        "| Status | Count |".to_string(),
+        "| --- | --- |".to_string(),
+    ];
+    for (selection, members) in &groups {
+        lines.push(format!("| {selection} | {} |", members.len()));
+    }
+    lines.push(format!("| **Total** | {} |", jobs.len()));
+
+    for (selection, members) in &groups {
+        lines.push(String::new());
+        lines.push(format!("## {selection}"));
+        lines.push(String::new());
+        lines.push("| Company | Position | Location | Salary | Date |".to_string());
+        lines.push("| --- | --- | --- | --- | --- |".to_string());
+        for job in members {
+            let cells = [
+                job.company.clone(),
+                job.position.clone(),
+                job.location.clone(),
+                job.salary.to_string(),
+                job.date.map(|date| date.to_string()).unwrap_or_default(),
+            ];
+            let row = cells
+                .iter()
+                .map(|cell| escape_markdown_cell(cell))
+                .collect::<Vec<_>>()
+                .join(" | ");
+            lines.push(format!("| {row} |"));
+        }
+    }
+
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+/// Keeps `value` inside one table cell: pipes are escaped and line breaks
+/// become spaces.
+fn escape_markdown_cell(value: &str) -> String {
+    value
+        .replace('|', "\\|")
+        .replace("\r\n", " ")
+        .replace(['\n', '\r'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{SalaryRange, Status};
+
+    fn sample_jobs() -> Vec<JobApplication> {
+        vec![
+            JobApplication::new()
+                .company("Acme")
+                .position("Engineer")
+                .location("Remote")
+                .salary(SalaryRange::new(80_000, 100_000))
+                .date(2024, 3, 1),
+            JobApplication::new()
+                .company("Beta")
+                .position("Designer")
+                .status(Status::Interview(2))
+                .date(2024, 3, 5),
+            JobApplication::new()
+                .company("Gamma | Sons")
+                .position("Manager")
+                .status(Status::Interview(1))
+                .date(2024, 3, 9),
+            JobApplication::new()
+                .company("Delta")
+                .position("Analyst")
+                .status(Status::Rejected)
+                .date(2024, 2, 20),
+        ]
+    }
+
+    #[test]
+    fn test_render_markdown_groups_by_status() {
+        let report = render_markdown(&sample_jobs());
+
+        assert!(report.starts_with("# Job Applications\n\n## Summary\n"));
+        assert!(report.contains("| Applied | 1 |\n| Interview | 2 |\n| Rejected | 1 |\n"));
+        assert!(report.contains("| **Total** | 4 |"));
+        assert!(!report.contains("Offer"));
+
+        let applied = report.find("## Applied").unwrap();
+        let interview = report.find("## Interview").unwrap();
+        let rejected = report.find("## Rejected").unwrap();
+        assert!(applied < interview && interview < rejected);
+
+        assert!(report.contains("| Acme | Engineer | Remote | 80000 - 100000 USD | 2024-03-01 |"));
+        let beta = report.find("| Beta |").unwrap();
+        let gamma = report.find("| Gamma \\| Sons |").unwrap();
+        assert!(interview < beta && beta < gamma && gamma < rejected);
+        assert!(report[rejected..].contains("| Delta | Analyst |"));
+    }
+
+    #[test]
+    fn test_render_markdown_empty() {
+        let report = render_markdown(&[]);
+        assert!(report.contains("| **Total** | 0 |"));
+        assert_eq!(report.matches("## ").count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_export_markdown_writes_file() {
+        let db = Database::new("sqlite::memory:").await.unwrap();
+        db.insert_jobs(&sample_jobs()).await.unwrap();
+        let path = std::env::temp_dir()
+            .join(format!("job_tracker_export_{}", std::process::id()))
+            .join("jobs.md");
+
+        db.export_markdown(&path).await.unwrap();
+        let report = std::fs::read_to_string(&path).unwrap();
+        for heading in ["## Summary", "## Applied", "## Interview", "## Rejected"] {
+            assert!(report.contains(heading), "missing {heading}");
+        }
+        for company in ["| Acme |", "| Beta |", "| Gamma \\| Sons |", "| Delta |"] {
+            assert!(report.contains(company), "missing {company}");
+        }
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
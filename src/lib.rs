@@ -1,4 +1,6 @@
+pub mod config;
 pub mod db;
 pub mod error;
+pub mod export;
 pub mod model;
 pub mod ui;
@@ -1,5 +1,12 @@
 use job_tracker::ui;
+use std::process::ExitCode;
 
-fn main() -> iced::Result {
-    ui::run()
+fn main() -> ExitCode {
+    match ui::run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
 }
@@ -1,13 +1,71 @@
+use crate::error::{Validate, ValidationError};
 use std::path::PathBuf;
-use time::{Date, Month, UtcDateTime};
+use time::{Date, Month, OffsetDateTime, UtcDateTime};
 
+/// Serialized with an internal `type` tag, e.g. `{"type":"interview","round":2}`
+/// or `{"type":"offer","amount":90000}`, when the `serde` feature is enabled.
+///
+/// The derived ordering follows declaration order: `Applied` < `Interview`
+/// < `Offer` < `Accepted` < `Rejected` < `Withdrawn`, with interviews ordered
+/// by round and offers by amount. Use `funnel_rank` for pipeline progress.
 #[derive(Debug, Default, Clone, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "StatusRepr", into = "StatusRepr")
+)]
 pub enum Status {
     #[default]
     Applied,
     Interview(u8),
-    Offer(i32),
+    /// An offer of the given amount; never negative.
+    Offer(u32),
+    /// An offer that was accepted; the end of a successful application.
+    Accepted,
     Rejected,
+    /// An application the candidate pulled out of, as opposed to `Rejected`.
+    Withdrawn,
+}
+
+/// Serde shape of `Status`: internally tagged enums need named fields.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum StatusRepr {
+    Applied,
+    Interview { round: u8 },
+    Offer { amount: u32 },
+    Accepted,
+    Rejected,
+    Withdrawn,
+}
+
+#[cfg(feature = "serde")]
+impl From<StatusRepr> for Status {
+    fn from(repr: StatusRepr) -> Self {
+        match repr {
+            StatusRepr::Applied => Self::Applied,
+            StatusRepr::Interview { round } => Self::Interview(round),
+            StatusRepr::Offer { amount } => Self::Offer(amount),
+            StatusRepr::Accepted => Self::Accepted,
+            StatusRepr::Rejected => Self::Rejected,
+            StatusRepr::Withdrawn => Self::Withdrawn,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Status> for StatusRepr {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Applied => Self::Applied,
+            Status::Interview(round) => Self::Interview { round },
+            Status::Offer(amount) => Self::Offer { amount },
+            Status::Accepted => Self::Accepted,
+            Status::Rejected => Self::Rejected,
+            Status::Withdrawn => Self::Withdrawn,
+        }
+    }
 }
 
 impl Status {
@@ -20,7 +78,9 @@ impl Status {
     /// assert_eq!(Status::Applied.to_db_string(), "applied");
     /// assert_eq!(Status::Interview(2).to_db_string(), "interview:2");
     /// assert_eq!(Status::Offer(75_000).to_db_string(), "offer:75000");
+    /// assert_eq!(Status::Accepted.to_db_string(), "accepted");
     /// assert_eq!(Status::Rejected.to_db_string(), "rejected");
+    /// assert_eq!(Status::Withdrawn.to_db_string(), "withdrawn");
     /// ```
     #[must_use]
     pub fn to_db_string(&self) -> String {
@@ -28,7 +88,35 @@ impl Status {
             Self::Applied => "applied".to_string(),
             Self::Interview(round) => format!("interview:{round}"),
             Self::Offer(amount) => format!("offer:{amount}"),
+            Self::Accepted => "accepted".to_string(),
             Self::Rejected => "rejected".to_string(),
+            Self::Withdrawn => "withdrawn".to_string(),
+        }
+    }
+
+    /// Returns the position of the status in the pipeline sort order.
+    ///
+    /// Lower ranks are further along the funnel, so accepted offers sort
+    /// first, then open offers, interviews, fresh applications, rejections,
+    /// and withdrawals last.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::Status;
+    /// assert!(Status::Accepted.funnel_rank() < Status::Offer(90_000).funnel_rank());
+    /// assert!(Status::Offer(90_000).funnel_rank() < Status::Interview(1).funnel_rank());
+    /// assert!(Status::Applied.funnel_rank() < Status::Rejected.funnel_rank());
+    /// ```
+    #[must_use]
+    pub const fn funnel_rank(&self) -> u8 {
+        match self {
+            Self::Accepted => 0,
+            Self::Offer(_) => 1,
+            Self::Interview(_) => 2,
+            Self::Applied => 3,
+            Self::Rejected => 4,
+            Self::Withdrawn => 5,
         }
     }
 
@@ -44,6 +132,7 @@ impl Status {
     /// - The status string is not recognized
     /// - The interview round cannot be parsed as a number
     /// - The offer amount cannot be parsed as a number
+    /// - The offer amount is negative
     /// - The status format is malformed
     ///
     /// # Panics
@@ -62,15 +151,20 @@ impl Status {
     /// assert_eq!(Status::from_db_string("applied").unwrap(), Status::Applied);
     /// assert_eq!(Status::from_db_string("interview:3").unwrap(), Status::Interview(3));
     /// assert_eq!(Status::from_db_string("offer:80000").unwrap(), Status::Offer(80_000));
+    /// assert_eq!(Status::from_db_string("accepted").unwrap(), Status::Accepted);
     /// assert_eq!(Status::from_db_string("rejected").unwrap(), Status::Rejected);
+    /// assert_eq!(Status::from_db_string("withdrawn").unwrap(), Status::Withdrawn);
     ///
     /// assert!(Status::from_db_string("unknown").is_err());
     /// assert!(Status::from_db_string("interview:abc").is_err());
+    /// assert!(Status::from_db_string("offer:-1").is_err());
     /// ```
     pub fn from_db_string(s: &str) -> Result<Self, String> {
         match s {
             "applied" => Ok(Self::Applied),
+            "accepted" => Ok(Self::Accepted),
             "rejected" => Ok(Self::Rejected),
+            "withdrawn" => Ok(Self::Withdrawn),
             s if s.starts_with("interview:") => {
                 let round_str = s.strip_prefix("interview:").unwrap();
                 let round = round_str
@@ -80,8 +174,13 @@ impl Status {
             }
             s if s.starts_with("offer:") => {
                 let amount_str = s.strip_prefix("offer:").unwrap();
+                if let Some(magnitude) = amount_str.strip_prefix('-')
+                    && magnitude.parse::<u64>().is_ok()
+                {
+                    return Err(format!("Offer amount cannot be negative: {amount_str}"));
+                }
                 let amount = amount_str
-                    .parse::<i32>()
+                    .parse::<u32>()
                     .map_err(|_| format!("Invalid offer amount: {amount_str}"))?;
                 Ok(Self::Offer(amount))
             }
@@ -90,26 +189,168 @@ impl Status {
     }
 }
 
-#[derive(Debug, Default, Clone, Eq, Ord, PartialEq, PartialOrd)]
+/// How much an application matters relative to the others.
+///
+/// Ordered `Low` < `Medium` < `High`. Serialized in lowercase when the
+/// `serde` feature is enabled.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Priority {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+impl Priority {
+    /// Converts the priority to its database string representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::Priority;
+    /// assert_eq!(Priority::High.to_db_string(), "high");
+    /// ```
+    #[must_use]
+    pub const fn to_db_string(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+        }
+    }
+
+    /// Creates a priority from its database string representation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not `low`, `medium` or `high`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::Priority;
+    /// assert_eq!(Priority::from_db_string("low").unwrap(), Priority::Low);
+    /// assert!(Priority::from_db_string("urgent").is_err());
+    /// ```
+    pub fn from_db_string(s: &str) -> Result<Self, String> {
+        match s {
+            "low" => Ok(Self::Low),
+            "medium" => Ok(Self::Medium),
+            "high" => Ok(Self::High),
+            _ => Err(format!("Unknown priority: {s}")),
+        }
+    }
+}
+
+impl std::fmt::Display for Priority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Low => "Low",
+            Self::Medium => "Medium",
+            Self::High => "High",
+        })
+    }
+}
+
+/// Currency of a `SalaryRange` created without one.
+pub const DEFAULT_CURRENCY: &str = "USD";
+
+/// With the `serde` feature, a missing `currency` deserializes to
+/// `DEFAULT_CURRENCY`.
+#[derive(Debug, Clone, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct SalaryRange {
     pub min: u32,
     pub max: u32,
+    /// Currency code the amounts are in, e.g. "USD" or "EUR".
+    pub currency: String,
+}
+
+impl Default for SalaryRange {
+    fn default() -> Self {
+        Self::new(0, 0)
+    }
 }
 
 impl SalaryRange {
+    /// Creates a range in `DEFAULT_CURRENCY`.
+    #[must_use]
+    pub fn new(min: u32, max: u32) -> Self {
+        Self::with_currency(min, max, DEFAULT_CURRENCY)
+    }
+
+    /// Creates a range in the given currency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::SalaryRange;
+    /// let salary = SalaryRange::with_currency(60_000, 75_000, "EUR");
+    /// assert_eq!(salary.to_string(), "60000 - 75000 EUR");
+    /// ```
+    #[must_use]
+    pub fn with_currency(min: u32, max: u32, currency: &str) -> Self {
+        Self {
+            min,
+            max,
+            currency: currency.to_string(),
+        }
+    }
+
+    /// Returns whether the range is a single target salary.
     #[must_use]
-    pub const fn new(min: u32, max: u32) -> Self {
-        Self { min, max }
+    pub const fn is_single(&self) -> bool {
+        self.min == self.max
+    }
+
+    /// Formats the range with `format_amount`, showing a single amount when
+    /// the minimum and maximum are equal. The currency is left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::SalaryRange;
+    /// let fmt = |amount: u32| format!("${amount}");
+    /// assert_eq!(SalaryRange::new(100_000, 100_000).format_with(fmt), "$100000");
+    /// assert_eq!(SalaryRange::new(80_000, 100_000).format_with(fmt), "$80000 - $100000");
+    /// ```
+    pub fn format_with(&self, format_amount: impl Fn(u32) -> String) -> String {
+        if self.is_single() {
+            format_amount(self.min)
+        } else {
+            format!("{} - {}", format_amount(self.min), format_amount(self.max))
+        }
     }
 }
 
 impl std::fmt::Display for SalaryRange {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} - {}", self.min, self.max)
+        write!(
+            f,
+            "{} {}",
+            self.format_with(|amount| amount.to_string()),
+            self.currency
+        )
     }
 }
 
-#[derive(Debug, Clone)]
+/// With the `serde` feature, missing fields deserialize to their
+/// `JobApplication::default()` values, so hand-written JSON can stay short.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct JobApplication {
     pub id: Option<i64>,
     pub date: Option<Date>,
@@ -119,6 +360,109 @@ pub struct JobApplication {
     pub status: Status,
     pub location: String,
     pub salary: SalaryRange,
+    pub interview_at: Option<OffsetDateTime>,
+    /// When a pending offer must be answered by.
+    pub offer_deadline: Option<OffsetDateTime>,
+    /// When to follow up on the application, e.g. by emailing the recruiter.
+    pub follow_up: Option<Date>,
+    /// Manual override of the name used to group this job by company.
+    ///
+    /// When `None`, grouping uses `canonicalize_company(&company)`.
+    pub company_canonical: Option<String>,
+    /// Why the application was rejected, if known.
+    pub rejection_reason: Option<String>,
+    /// Free-text notes, e.g. the recruiter's name or next steps.
+    pub notes: Option<String>,
+    /// Name of the recruiter or hiring manager.
+    pub contact_name: Option<String>,
+    /// Email address of the contact.
+    pub contact_email: Option<String>,
+    /// Link to the original job posting.
+    pub url: Option<String>,
+    /// Whether the job is kept at the top of listings regardless of sort.
+    pub pinned: bool,
+    /// Whether the job is archived: hidden from listings but kept.
+    pub archived: bool,
+    /// How much the application matters relative to the others.
+    pub priority: Priority,
+    /// When the job was last inserted or modified, maintained by the database.
+    ///
+    /// `None` for jobs that have not been stored yet.
+    pub updated_at: Option<OffsetDateTime>,
+    /// When the job was first stored, maintained by the database.
+    ///
+    /// Unlike `date`, this is the audit timestamp of the row itself. `None`
+    /// for jobs that have not been stored yet.
+    pub created_at: Option<OffsetDateTime>,
+}
+
+/// 64-bit FNV-1a, used where a hash must not change between builds.
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+/// Returns the number of whole days between `last_change` and `now`.
+///
+/// A `last_change` in the future counts as zero days.
+///
+/// # Examples
+///
+/// ```
+/// # use job_tracker::model::days_in_status;
+/// # use time::macros::datetime;
+/// let changed = datetime!(2024-03-01 18:00 UTC);
+/// assert_eq!(days_in_status(changed, datetime!(2024-03-04 09:00 UTC)), 2);
+/// ```
+#[must_use]
+pub fn days_in_status(last_change: OffsetDateTime, now: OffsetDateTime) -> i64 {
+    (now - last_change).whole_days().max(0)
+}
+
+/// Legal-form suffixes dropped by `canonicalize_company`.
+const COMPANY_SUFFIXES: &[&str] = &["inc", "llc", "ltd", "gmbh", "corp", "corporation"];
+
+/// Derives a grouping key from a company display name.
+///
+/// Lowercases the name, collapses whitespace, and strips trailing legal-form
+/// suffixes such as "Inc." or "LLC" along with stray punctuation.
+///
+/// # Examples
+///
+/// ```
+/// # use job_tracker::model::canonicalize_company;
+/// assert_eq!(canonicalize_company("Google Inc."), "google");
+/// assert_eq!(canonicalize_company("  Acme   Widgets, LLC "), "acme widgets");
+/// ```
+#[must_use]
+pub fn canonicalize_company(name: &str) -> String {
+    let lowered = name.to_lowercase();
+    let mut words: Vec<&str> = lowered.split_whitespace().collect();
+
+    while let Some(last) = words.last() {
+        let trimmed = last.trim_matches(|c: char| c == '.' || c == ',');
+        if trimmed.is_empty() || COMPANY_SUFFIXES.contains(&trimmed) {
+            words.pop();
+        } else {
+            break;
+        }
+    }
+
+    let mut canonical = words.join(" ");
+    canonical.truncate(canonical.trim_end_matches([',', '.']).len());
+    canonical
 }
 
 impl Default for JobApplication {
@@ -132,6 +476,20 @@ impl Default for JobApplication {
             status: Status::default(),
             location: String::new(),
             salary: SalaryRange::default(),
+            interview_at: None,
+            offer_deadline: None,
+            follow_up: None,
+            company_canonical: None,
+            rejection_reason: None,
+            notes: None,
+            contact_name: None,
+            contact_email: None,
+            url: None,
+            pinned: false,
+            archived: false,
+            priority: Priority::default(),
+            updated_at: None,
+            created_at: None,
         }
     }
 }
@@ -146,6 +504,7 @@ impl JobApplication {
     /// - Applied status
     /// - Zero salary range
     /// - No CV path
+    /// - No scheduled interview
     ///
     /// # Examples
     ///
@@ -160,6 +519,215 @@ impl JobApplication {
         Self::default()
     }
 
+    /// Returns the status after the current one in the application funnel.
+    ///
+    /// The funnel runs `Applied` → `Interview(1)` → `Offer` → `Accepted`,
+    /// where the offer amount defaults to the midpoint of the salary range.
+    /// `Accepted`, `Rejected` and `Withdrawn` end the funnel and return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::{JobApplication, SalaryRange, Status};
+    /// let job = JobApplication::new()
+    ///     .salary(SalaryRange::new(80_000, 100_000))
+    ///     .status(Status::Interview(2));
+    /// assert_eq!(job.next_status(), Some(Status::Offer(90_000)));
+    /// ```
+    #[must_use]
+    pub const fn next_status(&self) -> Option<Status> {
+        match self.status {
+            Status::Applied => Some(Status::Interview(1)),
+            Status::Interview(_) => {
+                let midpoint = self.salary.min.midpoint(self.salary.max);
+                Some(Status::Offer(midpoint))
+            }
+            Status::Offer(_) => Some(Status::Accepted),
+            Status::Accepted | Status::Rejected | Status::Withdrawn => None,
+        }
+    }
+
+    /// Returns the name used to group this job by company: the manual
+    /// override if set, otherwise the name derived from `company`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::JobApplication;
+    /// let job = JobApplication::new().company("Google Inc.");
+    /// assert_eq!(job.canonical_company(), "google");
+    ///
+    /// let job = job.company_canonical("alphabet");
+    /// assert_eq!(job.canonical_company(), "alphabet");
+    /// ```
+    #[must_use]
+    pub fn canonical_company(&self) -> String {
+        self.company_canonical
+            .clone()
+            .unwrap_or_else(|| canonicalize_company(&self.company))
+    }
+
+    /// Returns a copy to submit to another company for the same role.
+    ///
+    /// The company is cleared so the user fills it in, and the ID and
+    /// database-managed timestamps are reset so the copy is stored as a new
+    /// job. A `company_canonical` override belongs to the old company and is
+    /// cleared too; everything else is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::JobApplication;
+    /// let mut job = JobApplication::new().company("Client A").position("Engineer");
+    /// job.id = Some(3);
+    /// let copy = job.duplicate_for_other_company();
+    /// assert_eq!((copy.id, copy.company.as_str()), (None, ""));
+    /// assert_eq!(copy.position, "Engineer");
+    /// ```
+    #[must_use]
+    pub fn duplicate_for_other_company(&self) -> Self {
+        Self {
+            id: None,
+            company: String::new(),
+            company_canonical: None,
+            updated_at: None,
+            created_at: None,
+            ..self.clone()
+        }
+    }
+
+    /// Computes a hash of the job's content for deduplication and sync.
+    ///
+    /// Covers company, position, location, application date, and salary.
+    /// Database-managed fields such as `id` and the timestamps are ignored,
+    /// so a stored job and an unsaved copy of it hash equally. The hash is
+    /// FNV-1a over a fixed byte encoding and is stable across builds and
+    /// platforms, so it can be persisted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::JobApplication;
+    /// let job = JobApplication::new().company("TechCorp").position("Engineer");
+    /// let mut stored = job.clone();
+    /// stored.id = Some(7);
+    /// assert_eq!(job.content_hash(), stored.content_hash());
+    /// assert_ne!(job.content_hash(), job.position("Manager").content_hash());
+    /// ```
+    #[must_use]
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = Fnv1a::default();
+        for field in [&self.company, &self.position, &self.location] {
+            hasher.write(field.as_bytes());
+            hasher.write(&[0xFF]);
+        }
+        match self.date {
+            Some(date) => {
+                hasher.write(&[1]);
+                hasher.write(&date.to_julian_day().to_le_bytes());
+            }
+            None => hasher.write(&[0]),
+        }
+        hasher.write(&self.salary.min.to_le_bytes());
+        hasher.write(&self.salary.max.to_le_bytes());
+        hasher.0
+    }
+
+    /// Returns whether every important field is filled in: company,
+    /// position, location, application date, and a non-zero salary.
+    ///
+    /// Text fields containing only whitespace count as empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::{JobApplication, SalaryRange};
+    /// let job = JobApplication::new()
+    ///     .company("TechCorp")
+    ///     .position("Engineer")
+    ///     .location("Remote")
+    ///     .salary(SalaryRange::new(80_000, 100_000));
+    /// assert!(job.is_complete());
+    /// assert!(!job.position("").is_complete());
+    /// ```
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        [&self.company, &self.position, &self.location]
+            .iter()
+            .all(|field| !field.trim().is_empty())
+            && self.date.is_some()
+            && self.salary.max > 0
+    }
+
+    /// Returns whether the job has not been sent yet as of `today`, i.e. it
+    /// has no application date or one in the future.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::JobApplication;
+    /// # use time::macros::date;
+    /// let job = JobApplication::new().date(2024, 6, 1);
+    /// assert!(job.is_unsent(date!(2024 - 05 - 31)));
+    /// assert!(!job.is_unsent(date!(2024 - 06 - 01)));
+    /// ```
+    #[must_use]
+    pub fn is_unsent(&self, today: Date) -> bool {
+        self.date.is_none_or(|date| date > today)
+    }
+
+    /// Returns whether the follow-up date has passed as of `today`.
+    ///
+    /// A follow-up due today is not overdue yet, and a job without a
+    /// follow-up date never is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::JobApplication;
+    /// # use time::macros::date;
+    /// let job = JobApplication::new().follow_up(date!(2024 - 04 - 15));
+    /// assert!(job.is_overdue(date!(2024 - 04 - 16)));
+    /// assert!(!job.is_overdue(date!(2024 - 04 - 15)));
+    /// ```
+    #[must_use]
+    pub fn is_overdue(&self, today: Date) -> bool {
+        self.follow_up.is_some_and(|follow_up| follow_up < today)
+    }
+
+    /// Returns how many whole days the job has been in its current status.
+    ///
+    /// Uses `updated_at` as a proxy for the last status change, so edits to
+    /// other fields also reset the count. Returns `None` if the job has no
+    /// `updated_at`.
+    #[must_use]
+    pub fn days_in_status(&self, now: OffsetDateTime) -> Option<i64> {
+        self.updated_at
+            .map(|last_change| days_in_status(last_change, now))
+    }
+
+    /// Creates a `JobApplicationBuilder` that validates on `build()`.
+    ///
+    /// Unlike the fluent methods on `JobApplication`, the builder never
+    /// panics on invalid input and reports every problem at once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::JobApplication;
+    /// let job = JobApplication::builder()
+    ///     .company("TechCorp")
+    ///     .position("Engineer")
+    ///     .date(2024, 3, 15)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(job.company, "TechCorp");
+    /// ```
+    #[must_use]
+    pub fn builder() -> JobApplicationBuilder {
+        JobApplicationBuilder::default()
+    }
+
     #[must_use]
     /// Sets the application date.
     ///
@@ -256,7 +824,7 @@ impl JobApplication {
     /// let job = JobApplication::new()
     ///     .salary(SalaryRange::new(80_000, 120_000));
     /// ```
-    pub const fn salary(mut self, salary: SalaryRange) -> Self {
+    pub fn salary(mut self, salary: SalaryRange) -> Self {
         self.salary = salary;
         self
     }
@@ -296,41 +864,483 @@ impl JobApplication {
         self.status = status;
         self
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
-    #[test]
-    fn test_new_job_application() {
-        let job = JobApplication::new();
-        assert_eq!(job.id, None);
-        assert!(job.date.is_some());
-        assert!(job.cv.is_none());
-        assert_eq!(job.company, "");
-        assert_eq!(job.position, "");
-        assert_eq!(job.status, Status::default());
-        assert_eq!(job.location, "");
-        assert_eq!(job.salary, SalaryRange::default());
+    #[must_use]
+    /// Sets the date and time of the next scheduled interview.
+    ///
+    /// # Arguments
+    ///
+    /// * `interview_at` - When the interview takes place
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::{JobApplication, Status};
+    /// # use time::macros::datetime;
+    /// let job = JobApplication::new()
+    ///     .status(Status::Interview(1))
+    ///     .interview_at(datetime!(2024-03-20 14:00 UTC));
+    /// ```
+    pub const fn interview_at(mut self, interview_at: OffsetDateTime) -> Self {
+        self.interview_at = Some(interview_at);
+        self
     }
 
-    #[test]
-    fn test_date() {
-        let job = JobApplication::new().date(2023, 1, 1);
-        assert_eq!(
-            job.date.unwrap(),
-            Date::from_calendar_date(2023, Month::January, 1).unwrap()
-        );
+    #[must_use]
+    /// Sets when the offer must be answered by.
+    ///
+    /// # Arguments
+    ///
+    /// * `deadline` - The last moment to accept or decline the offer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::{JobApplication, Status};
+    /// # use time::macros::datetime;
+    /// let job = JobApplication::new()
+    ///     .status(Status::Offer(95_000))
+    ///     .offer_deadline(datetime!(2024-04-01 17:00 UTC));
+    /// ```
+    pub const fn offer_deadline(mut self, deadline: OffsetDateTime) -> Self {
+        self.offer_deadline = Some(deadline);
+        self
     }
 
-    #[test]
-    fn test_position() {
-        let job = JobApplication::new().position("Software Engineer");
-        assert_eq!(job.position, "Software Engineer");
+    /// Sets when to follow up on the application.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::JobApplication;
+    /// # use time::macros::date;
+    /// let job = JobApplication::new().follow_up(date!(2024 - 04 - 15));
+    /// assert_eq!(job.follow_up, Some(date!(2024 - 04 - 15)));
+    /// ```
+    #[must_use]
+    pub const fn follow_up(mut self, date: Date) -> Self {
+        self.follow_up = Some(date);
+        self
     }
 
-    #[test]
+    #[must_use]
+    /// Overrides the name used to group this job by company.
+    ///
+    /// # Arguments
+    ///
+    /// * `canonical` - The grouping name, e.g. a parent company
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::JobApplication;
+    /// let job = JobApplication::new().company("YouTube").company_canonical("google");
+    /// ```
+    pub fn company_canonical(mut self, canonical: &str) -> Self {
+        self.company_canonical = Some(canonical.to_string());
+        self
+    }
+
+    #[must_use]
+    /// Records why the application was rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `reason` - A short description, e.g. "Position filled"
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::{JobApplication, Status};
+    /// let job = JobApplication::new()
+    ///     .status(Status::Rejected)
+    ///     .rejection_reason("Position filled");
+    /// ```
+    pub fn rejection_reason(mut self, reason: &str) -> Self {
+        self.rejection_reason = Some(reason.to_string());
+        self
+    }
+
+    #[must_use]
+    /// Sets free-text notes about the application.
+    ///
+    /// # Arguments
+    ///
+    /// * `notes` - Anything worth remembering, e.g. "Recruiter: Jane, call back Monday"
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::JobApplication;
+    /// let job = JobApplication::new().notes("Recruiter: Jane");
+    /// assert_eq!(job.notes.as_deref(), Some("Recruiter: Jane"));
+    /// ```
+    pub fn notes(mut self, notes: &str) -> Self {
+        self.notes = Some(notes.to_string());
+        self
+    }
+
+    #[must_use]
+    /// Sets the name of the recruiter or hiring manager.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::JobApplication;
+    /// let job = JobApplication::new().contact_name("Jane Doe");
+    /// assert_eq!(job.contact_name.as_deref(), Some("Jane Doe"));
+    /// ```
+    pub fn contact_name(mut self, name: &str) -> Self {
+        self.contact_name = Some(name.to_string());
+        self
+    }
+
+    #[must_use]
+    /// Sets the contact's email address.
+    ///
+    /// The address is checked by `Validate`, not here.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::JobApplication;
+    /// let job = JobApplication::new().contact_email("jane@corp.com");
+    /// assert_eq!(job.contact_email.as_deref(), Some("jane@corp.com"));
+    /// ```
+    pub fn contact_email(mut self, email: &str) -> Self {
+        self.contact_email = Some(email.to_string());
+        self
+    }
+
+    #[must_use]
+    /// Sets the link to the original job posting.
+    ///
+    /// The scheme is checked by `Validate`, not here.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::JobApplication;
+    /// let job = JobApplication::new().url("https://corp.com/jobs/42");
+    /// assert_eq!(job.url.as_deref(), Some("https://corp.com/jobs/42"));
+    /// ```
+    pub fn url(mut self, url: &str) -> Self {
+        self.url = Some(url.to_string());
+        self
+    }
+
+    /// Pins the job to the top of listings, or unpins it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::JobApplication;
+    /// let job = JobApplication::new().pinned(true);
+    /// assert!(job.pinned);
+    /// ```
+    #[must_use]
+    pub const fn pinned(mut self, pinned: bool) -> Self {
+        self.pinned = pinned;
+        self
+    }
+
+    /// Archives the job, or restores it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::JobApplication;
+    /// let job = JobApplication::new().archived(true);
+    /// assert!(job.archived);
+    /// ```
+    #[must_use]
+    pub const fn archived(mut self, archived: bool) -> Self {
+        self.archived = archived;
+        self
+    }
+
+    /// Sets how much the application matters relative to the others.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::{JobApplication, Priority};
+    /// let job = JobApplication::new().priority(Priority::High);
+    /// assert_eq!(job.priority, Priority::High);
+    /// ```
+    #[must_use]
+    pub const fn priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+/// A message to show for a job application once `remind_at` has passed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reminder {
+    pub id: Option<i64>,
+    pub job_id: i64,
+    pub remind_at: OffsetDateTime,
+    pub message: String,
+}
+
+/// A sub-task tracked for a job application, such as "tailored resume".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChecklistItem {
+    pub id: Option<i64>,
+    pub job_id: i64,
+    pub text: String,
+    pub done: bool,
+}
+
+/// Returns the percentage of `items` that are done, rounded down.
+///
+/// An empty checklist is 0% complete.
+///
+/// # Examples
+///
+/// ```
+/// # use job_tracker::model::{ChecklistItem, checklist_completion};
+/// let item = |done| ChecklistItem { id: None, job_id: 1, text: String::new(), done };
+/// assert_eq!(checklist_completion(&[item(true), item(false), item(false)]), 33);
+/// ```
+#[must_use]
+pub fn checklist_completion(items: &[ChecklistItem]) -> u8 {
+    if items.is_empty() {
+        return 0;
+    }
+    let done = items.iter().filter(|item| item.done).count();
+    u8::try_from(done * 100 / items.len()).unwrap_or(100)
+}
+
+/// Fallible builder for `JobApplication`.
+///
+/// Collects the same fields as the fluent `JobApplication` methods, but defers
+/// date construction and `Validate` checks to `build()`, which returns all
+/// validation errors together instead of panicking.
+#[derive(Debug, Clone, Default)]
+pub struct JobApplicationBuilder {
+    job: JobApplication,
+    date: Option<(i32, u8, u8)>,
+}
+
+impl JobApplicationBuilder {
+    /// Sets the application date, validated when the job is built.
+    #[must_use]
+    pub const fn date(mut self, year: i32, month: u8, day: u8) -> Self {
+        self.date = Some((year, month, day));
+        self
+    }
+
+    /// Sets the company name.
+    #[must_use]
+    pub fn company(mut self, company: &str) -> Self {
+        self.job = self.job.company(company);
+        self
+    }
+
+    /// Sets the job position/title.
+    #[must_use]
+    pub fn position(mut self, position: &str) -> Self {
+        self.job = self.job.position(position);
+        self
+    }
+
+    /// Sets the job location.
+    #[must_use]
+    pub fn location(mut self, location: &str) -> Self {
+        self.job = self.job.location(location);
+        self
+    }
+
+    /// Sets the salary range for the position.
+    #[must_use]
+    pub fn salary(mut self, salary: SalaryRange) -> Self {
+        self.job = self.job.salary(salary);
+        self
+    }
+
+    /// Sets the path to the CV/resume file.
+    #[must_use]
+    pub fn cv(mut self, cv: &str) -> Self {
+        self.job = self.job.cv(cv);
+        self
+    }
+
+    /// Sets the application status.
+    #[must_use]
+    pub fn status(mut self, status: Status) -> Self {
+        self.job = self.job.status(status);
+        self
+    }
+
+    /// Sets the date and time of the next scheduled interview.
+    #[must_use]
+    pub fn interview_at(mut self, interview_at: OffsetDateTime) -> Self {
+        self.job = self.job.interview_at(interview_at);
+        self
+    }
+
+    /// Sets when the offer must be answered by.
+    #[must_use]
+    pub fn offer_deadline(mut self, deadline: OffsetDateTime) -> Self {
+        self.job = self.job.offer_deadline(deadline);
+        self
+    }
+
+    /// Sets when to follow up on the application.
+    #[must_use]
+    pub fn follow_up(mut self, date: Date) -> Self {
+        self.job = self.job.follow_up(date);
+        self
+    }
+
+    /// Overrides the name used to group this job by company.
+    #[must_use]
+    pub fn company_canonical(mut self, canonical: &str) -> Self {
+        self.job = self.job.company_canonical(canonical);
+        self
+    }
+
+    /// Records why the application was rejected.
+    #[must_use]
+    pub fn rejection_reason(mut self, reason: &str) -> Self {
+        self.job = self.job.rejection_reason(reason);
+        self
+    }
+
+    /// Sets free-text notes about the application.
+    #[must_use]
+    pub fn notes(mut self, notes: &str) -> Self {
+        self.job = self.job.notes(notes);
+        self
+    }
+
+    /// Sets the name of the recruiter or hiring manager.
+    #[must_use]
+    pub fn contact_name(mut self, name: &str) -> Self {
+        self.job = self.job.contact_name(name);
+        self
+    }
+
+    /// Sets the contact's email address.
+    #[must_use]
+    pub fn contact_email(mut self, email: &str) -> Self {
+        self.job = self.job.contact_email(email);
+        self
+    }
+
+    /// Sets the link to the original job posting.
+    #[must_use]
+    pub fn url(mut self, url: &str) -> Self {
+        self.job = self.job.url(url);
+        self
+    }
+
+    /// Pins the job to the top of listings, or unpins it.
+    #[must_use]
+    pub fn pinned(mut self, pinned: bool) -> Self {
+        self.job = self.job.pinned(pinned);
+        self
+    }
+
+    /// Archives the job, or restores it.
+    #[must_use]
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.job = self.job.archived(archived);
+        self
+    }
+
+    /// Sets how much the application matters relative to the others.
+    #[must_use]
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.job = self.job.priority(priority);
+        self
+    }
+
+    /// Builds the job application, validating all fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns every `ValidationError` found, including:
+    /// - A month outside 1-12 or a day that doesn't exist in that month
+    /// - Any error reported by the `Validate` implementation for `JobApplication`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::model::{JobApplication, SalaryRange};
+    /// let errors = JobApplication::builder()
+    ///     .company("TechCorp")
+    ///     .position("Engineer")
+    ///     .date(2024, 13, 1)
+    ///     .salary(SalaryRange::new(90_000, 60_000))
+    ///     .build()
+    ///     .unwrap_err();
+    /// assert_eq!(errors.len(), 2);
+    /// ```
+    pub fn build(self) -> Result<JobApplication, Vec<ValidationError>> {
+        let mut job = self.job;
+        let mut errors = Vec::new();
+
+        if let Some((year, month, day)) = self.date {
+            match Month::try_from(month)
+                .map_err(|_| format!("Invalid month: {month}"))
+                .and_then(|month| {
+                    Date::from_calendar_date(year, month, day)
+                        .map_err(|_| format!("Invalid day {day} for {month} {year}"))
+                }) {
+                Ok(date) => job.date = Some(date),
+                Err(message) => errors.push(ValidationError::new("date", &message)),
+            }
+        }
+
+        errors.extend(job.validate());
+
+        if errors.is_empty() {
+            Ok(job)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_job_application() {
+        let job = JobApplication::new();
+        assert_eq!(job.id, None);
+        assert!(job.date.is_some());
+        assert!(job.cv.is_none());
+        assert_eq!(job.company, "");
+        assert_eq!(job.position, "");
+        assert_eq!(job.status, Status::default());
+        assert_eq!(job.location, "");
+        assert_eq!(job.salary, SalaryRange::default());
+        assert!(job.interview_at.is_none());
+        assert!(job.offer_deadline.is_none());
+        assert_eq!(job.priority, Priority::Medium);
+    }
+
+    #[test]
+    fn test_date() {
+        let job = JobApplication::new().date(2023, 1, 1);
+        assert_eq!(
+            job.date.unwrap(),
+            Date::from_calendar_date(2023, Month::January, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_position() {
+        let job = JobApplication::new().position("Software Engineer");
+        assert_eq!(job.position, "Software Engineer");
+    }
+
+    #[test]
     fn test_location() {
         let job = JobApplication::new().location("New York");
         assert_eq!(job.location, "New York");
@@ -348,6 +1358,28 @@ mod tests {
         assert_eq!(job.salary, SalaryRange::new(50_000, 100_000));
     }
 
+    #[test]
+    fn test_salary_display_single_or_range() {
+        assert!(SalaryRange::new(100_000, 100_000).is_single());
+        assert!(!SalaryRange::new(90_000, 100_000).is_single());
+        assert_eq!(SalaryRange::new(100_000, 100_000).to_string(), "100000 USD");
+        assert_eq!(SalaryRange::new(0, 0).to_string(), "0 USD");
+        assert_eq!(
+            SalaryRange::new(90_000, 100_000).to_string(),
+            "90000 - 100000 USD"
+        );
+    }
+
+    #[test]
+    fn test_salary_currency() {
+        assert_eq!(SalaryRange::default().currency, DEFAULT_CURRENCY);
+        assert_eq!(SalaryRange::new(80_000, 120_000).currency, "USD");
+
+        let salary = SalaryRange::with_currency(80_000, 120_000, "GBP");
+        assert_eq!(salary.to_string(), "80000 - 120000 GBP");
+        assert_ne!(salary, SalaryRange::new(80_000, 120_000));
+    }
+
     #[test]
     fn test_company() {
         let job = JobApplication::new().company("ABC Corp");
@@ -361,6 +1393,13 @@ mod tests {
         assert_eq!(job.cv, Some(PathBuf::from(path_str)));
     }
 
+    #[test]
+    fn test_interview_at() {
+        let at = time::macros::datetime!(2024-03-20 14:00 UTC);
+        let job = JobApplication::new().interview_at(at);
+        assert_eq!(job.interview_at, Some(at));
+    }
+
     #[test]
     fn test_complete_builder_chain() {
         let year = 2024;
@@ -396,6 +1435,245 @@ mod tests {
         assert_eq!(job.status, Status::Interview(interview_round));
     }
 
+    #[test]
+    fn test_canonicalize_company() {
+        assert_eq!(canonicalize_company("Google"), "google");
+        assert_eq!(canonicalize_company("Google Inc."), "google");
+        assert_eq!(canonicalize_company("Google, Inc."), "google");
+        assert_eq!(canonicalize_company("Acme Holdings LLC"), "acme holdings");
+        assert_eq!(canonicalize_company("Widgets Ltd. Corp"), "widgets");
+        assert_eq!(canonicalize_company("  Big   Co  "), "big co");
+        assert_eq!(canonicalize_company("Inc."), "");
+    }
+
+    #[test]
+    fn test_canonical_company_override() {
+        let job = JobApplication::new().company("Google Inc.");
+        assert_eq!(job.canonical_company(), "google");
+
+        let job = job.company_canonical("alphabet");
+        assert_eq!(job.canonical_company(), "alphabet");
+        assert_eq!(job.company, "Google Inc.");
+    }
+
+    #[test]
+    fn test_checklist_completion() {
+        let item = |done| ChecklistItem {
+            id: None,
+            job_id: 1,
+            text: "step".to_string(),
+            done,
+        };
+        assert_eq!(checklist_completion(&[]), 0);
+        assert_eq!(checklist_completion(&[item(false), item(false)]), 0);
+        assert_eq!(checklist_completion(&[item(true), item(false)]), 50);
+        assert_eq!(
+            checklist_completion(&[item(true), item(true), item(false)]),
+            66
+        );
+        assert_eq!(checklist_completion(&[item(true), item(true)]), 100);
+    }
+
+    #[test]
+    fn test_duplicate_for_other_company() {
+        let mut source = JobApplication::new()
+            .company("Client A")
+            .position("Engineer")
+            .location("Berlin")
+            .cv("cvs/agency.pdf")
+            .salary(SalaryRange::new(70_000, 90_000))
+            .status(Status::Interview(2))
+            .pinned(true);
+        source.id = Some(12);
+
+        let copy = source.duplicate_for_other_company();
+        assert_eq!(copy.id, None);
+        assert_eq!(copy.company, "");
+
+        // Everything else matches the source.
+        let restored = JobApplication {
+            id: source.id,
+            company: source.company.clone(),
+            ..copy
+        };
+        assert_eq!(restored, source);
+    }
+
+    #[test]
+    fn test_equality_ignoring_id() {
+        let job = JobApplication::new()
+            .company("TechCorp")
+            .position("Engineer");
+        let mut stored = job.clone();
+        stored.id = Some(7);
+        assert_ne!(stored, job);
+
+        let mut cleared = stored;
+        cleared.id = None;
+        assert_eq!(cleared, job);
+        assert_ne!(cleared.position("Manager"), job);
+    }
+
+    #[test]
+    fn test_is_complete() {
+        let complete = JobApplication::new()
+            .company("TechCorp")
+            .position("Engineer")
+            .location("Remote")
+            .salary(SalaryRange::new(0, 90_000));
+        assert!(complete.is_complete());
+
+        assert!(!complete.clone().company("").is_complete());
+        assert!(!complete.clone().position("   ").is_complete());
+        assert!(!complete.clone().location("").is_complete());
+        assert!(
+            !complete
+                .clone()
+                .salary(SalaryRange::new(0, 0))
+                .is_complete()
+        );
+        let mut undated = complete;
+        undated.date = None;
+        assert!(!undated.is_complete());
+        assert!(!JobApplication::new().is_complete());
+    }
+
+    #[test]
+    fn test_is_unsent() {
+        let today = time::macros::date!(2024 - 03 - 10);
+        let mut job = JobApplication::new();
+        job.date = None;
+        assert!(job.is_unsent(today));
+        assert!(job.clone().date(2024, 3, 11).is_unsent(today));
+        assert!(!job.clone().date(2024, 3, 10).is_unsent(today));
+        assert!(!job.date(2024, 1, 1).is_unsent(today));
+    }
+
+    #[test]
+    fn test_is_overdue() {
+        let today = time::macros::date!(2024 - 03 - 10);
+        let job = JobApplication::new();
+        assert!(!job.is_overdue(today));
+        assert!(
+            job.clone()
+                .follow_up(time::macros::date!(2024 - 03 - 09))
+                .is_overdue(today)
+        );
+        assert!(
+            !job.clone()
+                .follow_up(time::macros::date!(2024 - 03 - 10))
+                .is_overdue(today)
+        );
+        assert!(
+            !job.follow_up(time::macros::date!(2024 - 03 - 11))
+                .is_overdue(today)
+        );
+    }
+
+    #[test]
+    fn test_days_in_status() {
+        use time::macros::datetime;
+
+        let changed = datetime!(2024-03-01 12:00 UTC);
+        assert_eq!(days_in_status(changed, changed), 0);
+        assert_eq!(days_in_status(changed, datetime!(2024-03-02 11:59 UTC)), 0);
+        assert_eq!(days_in_status(changed, datetime!(2024-03-02 12:00 UTC)), 1);
+        assert_eq!(days_in_status(changed, datetime!(2024-04-01 12:00 UTC)), 31);
+        assert_eq!(days_in_status(changed, datetime!(2024-02-20 12:00 UTC)), 0);
+    }
+
+    #[test]
+    fn test_job_days_in_status_requires_updated_at() {
+        use time::macros::datetime;
+
+        let now = datetime!(2024-03-10 00:00 UTC);
+        let mut job = JobApplication::new();
+        assert_eq!(job.days_in_status(now), None);
+
+        job.updated_at = Some(datetime!(2024-03-03 00:00 UTC));
+        assert_eq!(job.days_in_status(now), Some(7));
+    }
+
+    #[test]
+    fn test_next_status_transitions() {
+        let job = JobApplication::new()
+            .salary(SalaryRange::new(60_000, 81_000))
+            .status(Status::Applied);
+        assert_eq!(job.next_status(), Some(Status::Interview(1)));
+
+        let job = job.status(Status::Interview(1));
+        assert_eq!(job.next_status(), Some(Status::Offer(70_500)));
+
+        let job = job.status(Status::Interview(4));
+        assert_eq!(job.next_status(), Some(Status::Offer(70_500)));
+
+        let job = job.status(Status::Offer(70_000));
+        assert_eq!(job.next_status(), Some(Status::Accepted));
+    }
+
+    #[test]
+    fn test_next_status_terminal() {
+        let job = JobApplication::new().status(Status::Accepted);
+        assert_eq!(job.next_status(), None);
+
+        let job = JobApplication::new().status(Status::Rejected);
+        assert_eq!(job.next_status(), None);
+
+        let job = JobApplication::new().status(Status::Withdrawn);
+        assert_eq!(job.next_status(), None);
+    }
+
+    #[test]
+    fn test_builder_success() {
+        let job = JobApplication::builder()
+            .company("ABC Corp")
+            .position("Developer")
+            .location("Remote")
+            .salary(SalaryRange::new(50_000, 100_000))
+            .status(Status::Interview(1))
+            .cv("path/to/cv.pdf")
+            .date(2024, 2, 29)
+            .build()
+            .unwrap();
+
+        assert_eq!(job.company, "ABC Corp");
+        assert_eq!(job.position, "Developer");
+        assert_eq!(job.location, "Remote");
+        assert_eq!(job.salary, SalaryRange::new(50_000, 100_000));
+        assert_eq!(job.status, Status::Interview(1));
+        assert_eq!(job.cv, Some(PathBuf::from("path/to/cv.pdf")));
+        assert_eq!(
+            job.date.unwrap(),
+            Date::from_calendar_date(2024, Month::February, 29).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_builder_reports_all_errors() {
+        let errors = JobApplication::builder()
+            .company("ABC Corp")
+            .position("Developer")
+            .date(2023, 2, 29)
+            .salary(SalaryRange::new(100_000, 50_000))
+            .build()
+            .unwrap_err();
+
+        let fields: Vec<&str> = errors.iter().map(ValidationError::field).collect();
+        assert_eq!(fields, vec!["date", "salary"]);
+    }
+
+    #[test]
+    fn test_builder_invalid_month() {
+        let errors = JobApplication::builder()
+            .company("ABC Corp")
+            .position("Developer")
+            .date(2024, 0, 1)
+            .build()
+            .unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message().contains("Invalid month"));
+    }
+
     #[test]
     fn test_status_db_conversion() {
         let applied = Status::Applied;
@@ -410,9 +1688,24 @@ mod tests {
         assert_eq!(offer.to_db_string(), "offer:75000");
         assert_eq!(Status::from_db_string("offer:75000").unwrap(), offer);
 
+        let accepted = Status::Accepted;
+        assert_eq!(accepted.to_db_string(), "accepted");
+        assert_eq!(Status::from_db_string("accepted").unwrap(), accepted);
+
         let rejected = Status::Rejected;
         assert_eq!(rejected.to_db_string(), "rejected");
         assert_eq!(Status::from_db_string("rejected").unwrap(), rejected);
+
+        let withdrawn = Status::Withdrawn;
+        assert_eq!(withdrawn.to_db_string(), "withdrawn");
+        assert_eq!(Status::from_db_string("withdrawn").unwrap(), withdrawn);
+    }
+
+    #[test]
+    fn test_accepted_sort_position() {
+        assert!(Status::Offer(u32::MAX) < Status::Accepted);
+        assert!(Status::Accepted < Status::Rejected);
+        assert!(Status::Accepted.funnel_rank() < Status::Offer(90_000).funnel_rank());
     }
 
     #[test]
@@ -421,4 +1714,147 @@ mod tests {
         assert!(Status::from_db_string("interview:abc").is_err());
         assert!(Status::from_db_string("offer:xyz").is_err());
     }
+
+    #[test]
+    fn test_offer_amount_parsing() {
+        assert_eq!(
+            Status::from_db_string("offer:95000"),
+            Ok(Status::Offer(95_000))
+        );
+        assert_eq!(Status::from_db_string("offer:0"), Ok(Status::Offer(0)));
+        assert_eq!(Status::Offer(0).to_db_string(), "offer:0");
+        assert_eq!(
+            Status::from_db_string("offer:-5000"),
+            Err("Offer amount cannot be negative: -5000".to_string())
+        );
+    }
+
+    #[test]
+    fn test_priority_db_conversion() {
+        for priority in [Priority::Low, Priority::Medium, Priority::High] {
+            assert_eq!(
+                Priority::from_db_string(priority.to_db_string()),
+                Ok(priority)
+            );
+        }
+        assert_eq!(Priority::High.to_string(), "High");
+        assert!(Priority::Low < Priority::High);
+        assert!(Priority::from_db_string("High").is_err());
+    }
+
+    #[test]
+    fn test_content_hash_ignores_database_fields() {
+        let job = JobApplication::new()
+            .company("TechCorp")
+            .position("Engineer")
+            .location("Berlin")
+            .salary(SalaryRange::new(70_000, 90_000))
+            .date(2024, 5, 1);
+        let mut stored = job.clone().status(Status::Interview(2)).pinned(true);
+        stored.id = Some(42);
+        stored.created_at = Some(OffsetDateTime::UNIX_EPOCH);
+        stored.updated_at = Some(OffsetDateTime::UNIX_EPOCH);
+
+        assert_eq!(job.content_hash(), stored.content_hash());
+    }
+
+    #[test]
+    fn test_content_hash_changes_with_content() {
+        let job = JobApplication::new()
+            .company("TechCorp")
+            .position("Engineer")
+            .location("Berlin")
+            .salary(SalaryRange::new(70_000, 90_000))
+            .date(2024, 5, 1);
+        let hash = job.content_hash();
+
+        let mut undated = job.clone();
+        undated.date = None;
+        for changed in [
+            job.clone().company("OtherCorp"),
+            job.clone().position("Manager"),
+            job.clone().location("Munich"),
+            job.clone().salary(SalaryRange::new(70_000, 95_000)),
+            job.date(2024, 5, 2),
+            undated,
+        ] {
+            assert_ne!(changed.content_hash(), hash);
+        }
+
+        // Moving text between fields must not collide.
+        let a = JobApplication::new().company("ab").position("c");
+        let b = JobApplication::new().company("a").position("bc");
+        assert_ne!(a.content_hash(), b.content_hash());
+    }
+
+    #[test]
+    fn test_content_hash_is_stable() {
+        let mut job = JobApplication::new()
+            .company("TechCorp")
+            .position("Engineer");
+        job.date = None;
+        assert_eq!(job.content_hash(), 0x8d00_10a8_51e6_563f);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_status_serde_round_trip() {
+        for (status, json) in [
+            (Status::Applied, r#"{"type":"applied"}"#),
+            (Status::Interview(2), r#"{"type":"interview","round":2}"#),
+            (Status::Offer(90_000), r#"{"type":"offer","amount":90000}"#),
+            (Status::Accepted, r#"{"type":"accepted"}"#),
+            (Status::Rejected, r#"{"type":"rejected"}"#),
+            (Status::Withdrawn, r#"{"type":"withdrawn"}"#),
+        ] {
+            assert_eq!(serde_json::to_string(&status).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Status>(json).unwrap(), status);
+        }
+        assert!(serde_json::from_str::<Status>(r#"{"type":"pending"}"#).is_err());
+        assert!(serde_json::from_str::<Status>(r#"{"type":"interview"}"#).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_job_application_serde_round_trip() {
+        let job = JobApplication::new()
+            .company("TechCorp")
+            .position("Engineer")
+            .location("Berlin")
+            .salary(SalaryRange::new(70_000, 90_000))
+            .status(Status::Interview(3))
+            .date(2024, 5, 1)
+            .cv("cvs/backend_cv.pdf")
+            .notes("Recruiter: Jane");
+
+        let json = serde_json::to_string(&job).unwrap();
+        let restored: JobApplication = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.company, job.company);
+        assert_eq!(restored.position, job.position);
+        assert_eq!(restored.location, job.location);
+        assert_eq!(restored.salary, job.salary);
+        assert_eq!(restored.status, job.status);
+        assert_eq!(restored.date, job.date);
+        assert_eq!(restored.cv, Some(PathBuf::from("cvs/backend_cv.pdf")));
+        assert_eq!(restored.notes, job.notes);
+
+        let mut empty = job;
+        empty.date = None;
+        empty.cv = None;
+        let restored: JobApplication =
+            serde_json::from_str(&serde_json::to_string(&empty).unwrap()).unwrap();
+        assert_eq!(restored.date, None);
+        assert_eq!(restored.cv, None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_job_application_deserialize_fills_defaults() {
+        let job: JobApplication =
+            serde_json::from_str(r#"{"company":"Acme","date":"2024-05-01"}"#).unwrap();
+        assert_eq!(job.company, "Acme");
+        assert_eq!(job.date.unwrap().to_string(), "2024-05-01");
+        assert_eq!(job.status, Status::Applied);
+        assert_eq!(job.id, None);
+    }
 }
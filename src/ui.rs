@@ -1,11 +1,72 @@
+use std::collections::VecDeque;
 use std::fmt;
 
-use crate::db::Database;
-use crate::model::{JobApplication, SalaryRange, Status};
-use iced::widget::{Space, button, column, container, row, scrollable, text, text_input};
-use iced::{Element, Length, Task, Theme};
-use std::path::PathBuf;
-use time::Date;
+use crate::config::{
+    AccentColor, CONFIG_PATH, CSV_EXPORT_PATH, Config, DRAFT_PATH, NumberLocale, WindowGeometry,
+};
+use crate::db::{Database, DbError, SortBy, SortOrder};
+use crate::error::{AppError, AppResult, Validate, ValidationError};
+use crate::model::{
+    ChecklistItem, DEFAULT_CURRENCY, JobApplication, Priority, Reminder, SalaryRange, Status,
+    checklist_completion,
+};
+use iced::widget::{
+    Space, button, checkbox, column, container, pick_list, row, scrollable, slider, stack, text,
+    text_input,
+};
+use iced::{Element, Length, Point, Size, Subscription, Task, Theme, event, keyboard, window};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+use time::{
+    Date, PrimitiveDateTime, format_description::BorrowedFormatItem, macros::format_description,
+};
+
+/// Format used for the interview time and offer deadline inputs, interpreted
+/// as UTC.
+const INTERVIEW_AT_FORMAT: &[BorrowedFormatItem<'static>] =
+    format_description!("[year]-[month]-[day] [hour]:[minute]");
+
+/// How long the edit form must be left alone before its draft is saved.
+const DRAFT_DEBOUNCE: Duration = Duration::from_secs(2);
+
+/// How long an error toast stays on screen before it is dismissed.
+pub const TOAST_DURATION: Duration = Duration::from_secs(5);
+
+/// How many deleted jobs `UndoStack` remembers.
+pub const UNDO_CAPACITY: usize = 10;
+
+/// Accent colors offered as swatches next to the theme toggle.
+pub const ACCENT_PRESETS: [AccentColor; 5] = [
+    AccentColor::new(0x5E, 0x7C, 0xE2),
+    AccentColor::new(0x2E, 0x9E, 0x5B),
+    AccentColor::new(0x8E, 0x44, 0xAD),
+    AccentColor::new(0xE6, 0x7E, 0x22),
+    AccentColor::new(0xC0, 0x39, 0x2B),
+];
+
+/// A command palette entry: its label and a builder for the message it sends.
+pub type Command = (&'static str, fn() -> Message);
+
+/// Actions offered by the command palette.
+pub const COMMANDS: &[Command] = &[
+    ("Add job", || Message::AddNewJob),
+    ("Toggle theme", || Message::ToggleTheme),
+    ("Cancel edit", || Message::CancelEdit),
+    ("Restore draft", || Message::RestoreDraft),
+    ("Discard draft", || Message::DiscardDraft),
+    ("Clear database", || Message::ClearDatabase),
+    ("Export CSV", || Message::ExportCsv),
+];
+
+/// Widget id of the command palette input, focused when the palette opens.
+const COMMAND_PALETTE_ID: &str = "command-palette";
+
+/// How many matching commands the palette lists below its input.
+const COMMAND_PALETTE_RESULTS: usize = 6;
+
+/// Window position restored from the config, read once the monitor size is known.
+static SAVED_WINDOW_POSITION: OnceLock<(f32, f32)> = OnceLock::new();
 
 /// Theme selection for the application.
 ///
@@ -17,7 +78,37 @@ pub enum AppTheme {
     Dark,
 }
 
+/// `app_settings` key under which the selected theme is persisted.
+const THEME_SETTING_KEY: &str = "theme";
+
 impl AppTheme {
+    /// Returns the name the theme is persisted as.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::ui::AppTheme;
+    /// assert_eq!(AppTheme::Dark.setting_value(), "dark");
+    /// assert_eq!(AppTheme::from_setting_value("dark"), Some(AppTheme::Dark));
+    /// ```
+    #[must_use]
+    pub const fn setting_value(self) -> &'static str {
+        match self {
+            Self::Light => "light",
+            Self::Dark => "dark",
+        }
+    }
+
+    /// Parses a name returned by `setting_value`.
+    #[must_use]
+    pub fn from_setting_value(value: &str) -> Option<Self> {
+        match value {
+            "light" => Some(Self::Light),
+            "dark" => Some(Self::Dark),
+            _ => None,
+        }
+    }
+
     /// Converts the app theme to an Iced theme.
     ///
     /// # Examples
@@ -33,6 +124,28 @@ impl AppTheme {
             Self::Dark => Theme::Dark,
         }
     }
+
+    /// Converts the app theme to an Iced theme whose primary color is `accent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::config::AccentColor;
+    /// # use job_tracker::ui::AppTheme;
+    /// let theme = AppTheme::Dark.with_accent(AccentColor::new(0xE6, 0x7E, 0x22));
+    /// assert_eq!(theme.palette().primary, iced::Color::from_rgb8(0xE6, 0x7E, 0x22));
+    /// ```
+    #[must_use]
+    pub fn with_accent(self, accent: AccentColor) -> Theme {
+        let base = self.to_iced_theme();
+        Theme::custom(
+            format!("{base} ({accent})"),
+            iced::theme::Palette {
+                primary: accent_to_color(accent),
+                ..base.palette()
+            },
+        )
+    }
 }
 
 /// Messages that can be sent within the application.
@@ -43,6 +156,10 @@ impl AppTheme {
 pub enum Message {
     /// Database has been initialized with the given jobs.
     DatabaseInitialized(Database, Vec<JobApplication>),
+    /// Opening the database failed at startup.
+    DatabaseInitFailed(String),
+    /// User wants to retry opening the database after a failure.
+    RetryDatabaseInit,
     /// Job data has been loaded from the database.
     JobsLoaded(Result<Vec<JobApplication>, String>),
     /// User wants to add a new job application.
@@ -53,16 +170,111 @@ pub enum Message {
     SaveJob(i64),
     /// User wants to cancel the current edit operation.
     CancelEdit,
+    /// Saving the edit form failed because the job changed elsewhere.
+    ///
+    /// Carries the form with its version refreshed, so saving again
+    /// overwrites the newer data deliberately.
+    SaveConflict(i64, Box<EditForm>),
+    /// User wants a copy of a job in the form, to submit to another company.
+    DuplicateForOtherCompany(i64),
+    /// User wants to store a copy of a job application, reset to `Applied`.
+    DuplicateJob(i64),
+    /// A job from the edit form has been saved, with a warning to show.
+    JobSaved(Option<String>, Result<Vec<JobApplication>, String>),
+    /// User dismissed the warning banner.
+    DismissWarning,
+    /// User wants to pin a job application to the top, or unpin it.
+    SetPinned(i64, bool),
+    /// User wants to record an unsent job application as sent today.
+    MarkAppliedToday(i64),
+    /// User wants to advance a job application to its next funnel status.
+    CycleStatus(i64),
     /// User wants to delete a job application.
     DeleteJob(i64),
+    /// User wants to restore the most recently deleted job application.
+    UndoDelete,
+    /// User wants to export every job application to `CSV_EXPORT_PATH`.
+    ExportCsv,
+    /// The CSV export has finished.
+    CsvExported(Result<(), String>),
     /// User wants to clear all job applications.
     ClearDatabase,
+    /// The number of rows a pending clear would delete has been counted.
+    ClearDatabaseCounted(Result<i64, String>),
+    /// User confirmed the pending bulk delete.
+    ConfirmClearDatabase,
+    /// User backed out of the pending bulk delete.
+    CancelClearDatabase,
     /// Database has been cleared.
     DatabaseCleared(Result<(), String>),
+    /// User opened the command palette (Ctrl+K).
+    OpenCommandPalette,
+    /// User closed the command palette without running a command.
+    CloseCommandPalette,
+    /// A key without a global binding was pressed; resolved by
+    /// `key_to_message` against the current edit state.
+    KeyPressed(keyboard::Key, keyboard::Modifiers),
+    /// User typed into the command palette.
+    CommandPaletteChanged(String),
+    /// User pressed Enter in the command palette to run the best match.
+    CommandPaletteSubmitted,
+    /// User picked the command at this index of `COMMANDS`.
+    RunCommand(usize),
     /// User wants to toggle the application theme.
     ToggleTheme,
+    /// The persisted theme has been read from the database; `None` if the
+    /// user never picked one.
+    ThemeLoaded(Result<Option<AppTheme>, String>),
+    /// The toggled theme has been persisted.
+    ThemeSaved(Result<(), String>),
+    /// User picked an accent color swatch.
+    AccentSelected(AccentColor),
     /// User has selected a job application.
     SelectJob(Option<i64>),
+    /// User clicked a table column header to sort by it, or to reverse the
+    /// order if the table is already sorted by it.
+    SortColumn(SortBy),
+    /// The search box above the table changed.
+    SearchChanged(String),
+    /// User toggled a status chip in the toolbar filter.
+    ToggleStatusFilter(StatusSelection),
+    /// The main window was moved to a new position.
+    WindowMoved(Point),
+    /// The main window was resized.
+    WindowResized(Size),
+    /// User wants to close the window; the config is saved first.
+    WindowCloseRequested(window::Id),
+    /// Time has passed; expire toasts that have been shown long enough.
+    ExpireToasts(Instant),
+    /// User dismissed the toast with the given id.
+    DismissToast(u64),
+    /// The draft debounce interval elapsed; save the edit form if it changed.
+    SaveDraft,
+    /// User accepted restoring the draft found at startup.
+    RestoreDraft,
+    /// User declined restoring the draft found at startup.
+    DiscardDraft,
+
+    /// Due reminders have been loaded from the database.
+    RemindersLoaded(Result<Vec<Reminder>, String>),
+    /// User dismissed the reminder with the given id.
+    DismissReminder(i64),
+    /// A dismissed reminder has been deleted from the database.
+    ReminderDismissed(Result<(), String>),
+    /// The selected job's checklist has been loaded from the database.
+    ChecklistLoaded(i64, Result<Vec<ChecklistItem>, String>),
+    /// The new checklist item input changed.
+    ChecklistInputChanged(String),
+    /// User wants to add the typed item to the selected job's checklist.
+    AddChecklistItem,
+    /// User ticked or unticked the checklist item with the given id.
+    ToggleChecklistItem(i64, bool),
+    /// User wants to remove the checklist item with the given id.
+    DeleteChecklistItem(i64),
+    /// The quick-add bar's text changed.
+    QuickAddChanged(String),
+    /// User pressed Enter in the quick-add bar.
+    QuickAddSubmitted,
 
     /// Form field changes for editing job applications.
     CompanyChanged(String),
@@ -71,22 +283,75 @@ pub enum Message {
     DateChanged(String),
     SalaryMinChanged(String),
     SalaryMaxChanged(String),
+    SalaryCurrencyChanged(String),
+    SalaryMinSlid(u32),
+    SalaryMaxSlid(u32),
     StatusChanged(StatusSelection),
     CvPathChanged(String),
     InterviewRoundChanged(String),
+    InterviewAtChanged(String),
     OfferAmountChanged(String),
+    OfferDeadlineChanged(String),
+    CompanyCanonicalChanged(String),
+    RejectionReasonChanged(String),
+    NotesChanged(String),
+    ContactNameChanged(String),
+    ContactEmailChanged(String),
+    UrlChanged(String),
+    FollowUpChanged(String),
+}
+
+/// Upper bound of the salary sliders in the edit form.
+///
+/// Salaries above this can still be typed into the text inputs.
+pub const SALARY_SLIDER_MAX: u32 = 500_000;
+
+/// Step size of the salary sliders in the edit form.
+const SALARY_SLIDER_STEP: u32 = 1_000;
+
+impl Message {
+    /// Returns whether this message edits a field of the edit form.
+    const fn edits_form(&self) -> bool {
+        matches!(
+            self,
+            Self::CompanyChanged(_)
+                | Self::PositionChanged(_)
+                | Self::LocationChanged(_)
+                | Self::DateChanged(_)
+                | Self::SalaryMinChanged(_)
+                | Self::SalaryMaxChanged(_)
+                | Self::SalaryCurrencyChanged(_)
+                | Self::SalaryMinSlid(_)
+                | Self::SalaryMaxSlid(_)
+                | Self::StatusChanged(_)
+                | Self::CvPathChanged(_)
+                | Self::InterviewRoundChanged(_)
+                | Self::InterviewAtChanged(_)
+                | Self::OfferAmountChanged(_)
+                | Self::OfferDeadlineChanged(_)
+                | Self::CompanyCanonicalChanged(_)
+                | Self::RejectionReasonChanged(_)
+                | Self::NotesChanged(_)
+                | Self::ContactNameChanged(_)
+                | Self::ContactEmailChanged(_)
+                | Self::UrlChanged(_)
+                | Self::FollowUpChanged(_)
+        )
+    }
 }
 
 /// Status selection enum for the UI dropdown.
 ///
 /// Simplified version of the Status enum used in form controls
 /// to avoid dealing with associated data in the UI layer.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum StatusSelection {
     Applied,
     Interview,
     Offer,
+    Accepted,
     Rejected,
+    Withdrawn,
 }
 
 impl fmt::Display for StatusSelection {
@@ -95,12 +360,45 @@ impl fmt::Display for StatusSelection {
             Self::Applied => write!(f, "Applied"),
             Self::Interview => write!(f, "Interview"),
             Self::Offer => write!(f, "Offer"),
+            Self::Accepted => write!(f, "Accepted"),
             Self::Rejected => write!(f, "Rejected"),
+            Self::Withdrawn => write!(f, "Withdrawn"),
         }
     }
 }
 
 impl StatusSelection {
+    /// Every selection, in funnel order. Also the options of the status
+    /// dropdown in the edit form.
+    pub const ALL: [Self; 6] = [
+        Self::Applied,
+        Self::Interview,
+        Self::Offer,
+        Self::Accepted,
+        Self::Rejected,
+        Self::Withdrawn,
+    ];
+
+    /// Returns the status prefix used for this selection in the database.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::ui::StatusSelection;
+    /// assert_eq!(StatusSelection::Interview.db_prefix(), "interview");
+    /// ```
+    #[must_use]
+    pub const fn db_prefix(&self) -> &'static str {
+        match self {
+            Self::Applied => "applied",
+            Self::Interview => "interview",
+            Self::Offer => "offer",
+            Self::Accepted => "accepted",
+            Self::Rejected => "rejected",
+            Self::Withdrawn => "withdrawn",
+        }
+    }
+
     /// Creates a `StatusSelection` from a `Status` enum.
     ///
     /// This function maps the more complex `Status` enum (which may contain
@@ -121,9 +419,134 @@ impl StatusSelection {
             Status::Applied => Self::Applied,
             Status::Interview(_) => Self::Interview,
             Status::Offer(_) => Self::Offer,
+            Status::Accepted => Self::Accepted,
             Status::Rejected => Self::Rejected,
+            Status::Withdrawn => Self::Withdrawn,
+        }
+    }
+}
+
+/// Stages shown in the CV panel's pipeline indicator, in funnel order.
+pub const PIPELINE_STAGES: [&str; 3] = ["Applied", "Interview", "Offer"];
+
+/// How a single stage of the pipeline indicator is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StageState {
+    /// A stage the job has already passed.
+    Completed,
+    /// The stage the job is currently in.
+    Current,
+    /// A stage the job has not reached yet.
+    Pending,
+    /// Any stage of a rejected job.
+    Dimmed,
+}
+
+/// Derives the state of each entry in `PIPELINE_STAGES` from a status.
+///
+/// An accepted job has completed every stage, while a rejected or withdrawn
+/// job has every stage dimmed.
+///
+/// # Examples
+///
+/// ```
+/// # use job_tracker::model::Status;
+/// # use job_tracker::ui::{StageState, pipeline_stages};
+/// assert_eq!(
+///     pipeline_stages(&Status::Interview(2)),
+///     [StageState::Completed, StageState::Current, StageState::Pending]
+/// );
+/// ```
+#[must_use]
+pub fn pipeline_stages(status: &Status) -> [StageState; 3] {
+    let current = match status {
+        Status::Applied => 0,
+        Status::Interview(_) => 1,
+        Status::Offer(_) => 2,
+        Status::Accepted => return [StageState::Completed; 3],
+        Status::Rejected | Status::Withdrawn => return [StageState::Dimmed; 3],
+    };
+
+    std::array::from_fn(|stage| match stage.cmp(&current) {
+        std::cmp::Ordering::Less => StageState::Completed,
+        std::cmp::Ordering::Equal => StageState::Current,
+        std::cmp::Ordering::Greater => StageState::Pending,
+    })
+}
+
+/// Whether a stored CV path still points at a file on disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CvStatus {
+    /// The file exists.
+    Present,
+    /// Nothing exists at the path, e.g. because the CV was moved.
+    Missing,
+}
+
+/// Checks whether the CV at `path` exists, so stale paths can be flagged.
+///
+/// # Examples
+///
+/// ```
+/// # use std::path::Path;
+/// # use job_tracker::ui::{CvStatus, cv_status};
+/// assert_eq!(cv_status(Path::new("no/such/resume.pdf")), CvStatus::Missing);
+/// ```
+#[must_use]
+pub fn cv_status(path: &Path) -> CvStatus {
+    if path.exists() {
+        CvStatus::Present
+    } else {
+        CvStatus::Missing
+    }
+}
+
+/// Active status chips in the toolbar filter.
+///
+/// An empty filter means no filtering, so every job is shown.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatusFilter {
+    active: Vec<StatusSelection>,
+}
+
+impl StatusFilter {
+    /// Turns a status chip on if it is off, or off if it is on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::ui::{StatusFilter, StatusSelection};
+    /// let mut filter = StatusFilter::default();
+    /// filter.toggle(StatusSelection::Offer);
+    /// assert!(filter.is_active(&StatusSelection::Offer));
+    /// filter.toggle(StatusSelection::Offer);
+    /// assert!(filter.is_empty());
+    /// ```
+    pub fn toggle(&mut self, status: StatusSelection) {
+        if let Some(index) = self.active.iter().position(|s| *s == status) {
+            self.active.remove(index);
+        } else {
+            self.active.push(status);
         }
     }
+
+    /// Returns `true` if the chip for `status` is switched on.
+    #[must_use]
+    pub fn is_active(&self, status: &StatusSelection) -> bool {
+        self.active.contains(status)
+    }
+
+    /// Returns `true` if no chips are switched on.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+
+    /// Returns the active statuses in the order they were switched on.
+    #[must_use]
+    pub fn active(&self) -> &[StatusSelection] {
+        &self.active
+    }
 }
 
 /// Edit form state for job applications.
@@ -138,10 +561,39 @@ pub struct EditForm {
     pub date: String,
     pub salary_min: String,
     pub salary_max: String,
+    /// Currency code of the salary; empty means `DEFAULT_CURRENCY`.
+    pub salary_currency: String,
     pub status: StatusSelection,
     pub cv_path: String,
     pub interview_round: String,
+    /// Optional interview time, only kept for the Interview status.
+    pub interview_at: String,
     pub offer_amount: String,
+    /// Optional deadline, only kept for the Offer status.
+    pub offer_deadline: String,
+    /// Optional grouping name; empty derives one from `company`.
+    pub company_canonical: String,
+    /// Optional reason, only kept for the Rejected status.
+    pub rejection_reason: String,
+    /// Free-text notes; empty means none.
+    pub notes: String,
+    /// Recruiter or hiring manager; empty means none.
+    pub contact_name: String,
+    /// Contact's email address; empty means none.
+    pub contact_email: String,
+    /// Link to the job posting; empty means none.
+    pub url: String,
+    /// Follow-up date as `YYYY-MM-DD`; empty means none.
+    pub follow_up: String,
+    /// Carried over from the job so saving the form keeps it pinned.
+    pub pinned: bool,
+    /// Carried over from the job so saving the form keeps its priority.
+    pub priority: Priority,
+    /// The job's `updated_at` when the form was opened, so saving detects
+    /// edits made elsewhere in the meantime. Not saved in drafts.
+    pub updated_at: Option<time::OffsetDateTime>,
+    /// Locale whose thousands separators are accepted in amount fields.
+    pub locale: NumberLocale,
 }
 
 impl Default for EditForm {
@@ -174,10 +626,24 @@ impl EditForm {
             date: String::new(),
             salary_min: String::new(),
             salary_max: String::new(),
+            salary_currency: DEFAULT_CURRENCY.to_string(),
             status: StatusSelection::Applied,
             cv_path: String::new(),
             interview_round: "1".to_string(),
+            interview_at: String::new(),
             offer_amount: String::new(),
+            offer_deadline: String::new(),
+            company_canonical: String::new(),
+            rejection_reason: String::new(),
+            notes: String::new(),
+            contact_name: String::new(),
+            contact_email: String::new(),
+            url: String::new(),
+            follow_up: String::new(),
+            pinned: false,
+            priority: Priority::default(),
+            updated_at: None,
+            locale: NumberLocale::default(),
         }
     }
 
@@ -186,6 +652,9 @@ impl EditForm {
     /// Populates all form fields with data from the provided job application.
     /// Handles the conversion of complex types to strings for UI display.
     ///
+    /// An `Offer(0)` status leaves `offer_amount` empty rather than showing
+    /// "0", so the user is prompted to enter a real amount.
+    ///
     /// # Arguments
     ///
     /// * `job` - The job application to populate the form with
@@ -203,6 +672,7 @@ impl EditForm {
     pub fn from_job(job: &JobApplication) -> Self {
         let (interview_round, offer_amount) = match &job.status {
             Status::Interview(round) => (round.to_string(), String::new()),
+            Status::Offer(0) => (String::new(), String::new()),
             Status::Offer(amount) => (String::new(), amount.to_string()),
             _ => (String::new(), String::new()),
         };
@@ -214,6 +684,7 @@ impl EditForm {
             date: job.date.map(|d| d.to_string()).unwrap_or_default(),
             salary_min: job.salary.min.to_string(),
             salary_max: job.salary.max.to_string(),
+            salary_currency: job.salary.currency.clone(),
             status: StatusSelection::from_status(&job.status),
             cv_path: job
                 .cv
@@ -221,10 +692,102 @@ impl EditForm {
                 .map(|p| p.to_string_lossy().to_string())
                 .unwrap_or_default(),
             interview_round,
+            interview_at: job
+                .interview_at
+                .and_then(|at| at.format(INTERVIEW_AT_FORMAT).ok())
+                .unwrap_or_default(),
             offer_amount,
+            offer_deadline: job
+                .offer_deadline
+                .and_then(|at| at.format(INTERVIEW_AT_FORMAT).ok())
+                .unwrap_or_default(),
+            company_canonical: job.company_canonical.clone().unwrap_or_default(),
+            rejection_reason: job.rejection_reason.clone().unwrap_or_default(),
+            notes: job.notes.clone().unwrap_or_default(),
+            contact_name: job.contact_name.clone().unwrap_or_default(),
+            contact_email: job.contact_email.clone().unwrap_or_default(),
+            url: job.url.clone().unwrap_or_default(),
+            follow_up: job
+                .follow_up
+                .map(|date| date.to_string())
+                .unwrap_or_default(),
+            pinned: job.pinned,
+            priority: job.priority,
+            updated_at: job.updated_at,
+            locale: NumberLocale::default(),
         }
     }
 
+    /// Sets the locale used to parse the salary and offer fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::config::NumberLocale;
+    /// # use job_tracker::ui::EditForm;
+    /// let mut form = EditForm::new().with_locale(NumberLocale::DeDe);
+    /// form.salary_min = "80.000".to_string();
+    /// form.salary_max = "120.000".to_string();
+    /// assert_eq!(form.salary_slider_values(), (80_000, 120_000));
+    /// ```
+    #[must_use]
+    pub const fn with_locale(mut self, locale: NumberLocale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Returns the salary range as shown by the sliders.
+    ///
+    /// Fields that do not parse as a number are treated as zero.
+    #[must_use]
+    pub fn salary_slider_values(&self) -> (u32, u32) {
+        (
+            self.locale.parse_integer(&self.salary_min).unwrap_or(0),
+            self.locale.parse_integer(&self.salary_max).unwrap_or(0),
+        )
+    }
+
+    /// Sets the minimum salary from the slider, clamped so it cannot exceed
+    /// the current maximum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use job_tracker::ui::EditForm;
+    /// let mut form = EditForm::new();
+    /// form.salary_max = "80000".to_string();
+    /// form.slide_salary_min(95_000);
+    /// assert_eq!(form.salary_min, "80000");
+    /// ```
+    pub fn slide_salary_min(&mut self, value: u32) {
+        let (_, max) = self.salary_slider_values();
+        self.salary_min = value.min(max).to_string();
+    }
+
+    /// Sets the maximum salary from the slider, clamped so it cannot fall
+    /// below the current minimum.
+    pub fn slide_salary_max(&mut self, value: u32) {
+        let (min, _) = self.salary_slider_values();
+        self.salary_max = value.max(min).to_string();
+    }
+
+    /// Parses the salary fields, using `DEFAULT_CURRENCY` when the currency
+    /// is blank.
+    fn parse_salary(&self) -> Result<SalaryRange, String> {
+        let min = self
+            .locale
+            .parse_integer::<u32>(&self.salary_min)
+            .map_err(|_| "Invalid minimum salary".to_string())?;
+        let max = self
+            .locale
+            .parse_integer::<u32>(&self.salary_max)
+            .map_err(|_| "Invalid maximum salary".to_string())?;
+        let currency = Some(self.salary_currency.trim())
+            .filter(|currency| !currency.is_empty())
+            .unwrap_or(DEFAULT_CURRENCY);
+        Ok(SalaryRange::with_currency(min, max, currency))
+    }
+
     /// Converts the edit form to a `JobApplication`.
     ///
     /// Validates and parses all form fields, converting them from strings
@@ -241,6 +804,10 @@ impl EditForm {
     /// - The minimum or maximum salary cannot be parsed as a number
     /// - The interview round cannot be parsed as a number (for Interview status)
     /// - The offer amount cannot be parsed as a number (for Offer status)
+    /// - The interview time is not in YYYY-MM-DD HH:MM format
+    /// - The parsed job fails `Validate`, e.g. a blank company or position or
+    ///   a minimum salary above the maximum; every failure is listed, joined
+    ///   by "; "
     ///
     /// # Examples
     ///
@@ -248,32 +815,17 @@ impl EditForm {
     /// # use job_tracker::ui::EditForm;
     /// let mut form = EditForm::new();
     /// form.company = "TechCorp".to_string();
+    /// form.position = "Engineer".to_string();
     /// form.salary_min = "50000".to_string();
     /// form.salary_max = "80000".to_string();
     /// let job = form.to_job(None).unwrap();
     /// assert_eq!(job.company, "TechCorp");
     /// ```
     pub fn to_job(&self, id: Option<i64>) -> Result<JobApplication, String> {
-        let date = if self.date.is_empty() {
-            None
-        } else {
-            Some(
-                Date::parse(
-                    &self.date,
-                    &time::format_description::well_known::Iso8601::DATE,
-                )
-                .map_err(|_| "Invalid date format. Use YYYY-MM-DD".to_string())?,
-            )
-        };
+        let date = parse_form_date(&self.date, "Invalid date format. Use YYYY-MM-DD")?;
+        let follow_up = parse_form_date(&self.follow_up, "Invalid follow-up date. Use YYYY-MM-DD")?;
 
-        let salary_min = self
-            .salary_min
-            .parse::<u32>()
-            .map_err(|_| "Invalid minimum salary".to_string())?;
-        let salary_max = self
-            .salary_max
-            .parse::<u32>()
-            .map_err(|_| "Invalid maximum salary".to_string())?;
+        let salary = self.parse_salary()?;
 
         let status = match self.status {
             StatusSelection::Applied => Status::Applied,
@@ -286,12 +838,14 @@ impl EditForm {
             }
             StatusSelection::Offer => {
                 let amount = self
-                    .offer_amount
-                    .parse::<i32>()
+                    .locale
+                    .parse_integer::<u32>(&self.offer_amount)
                     .map_err(|_| "Invalid offer amount".to_string())?;
                 Status::Offer(amount)
             }
+            StatusSelection::Accepted => Status::Accepted,
             StatusSelection::Rejected => Status::Rejected,
+            StatusSelection::Withdrawn => Status::Withdrawn,
         };
 
         let cv = if self.cv_path.is_empty() {
@@ -300,7 +854,29 @@ impl EditForm {
             Some(PathBuf::from(&self.cv_path))
         };
 
-        Ok(JobApplication {
+        let interview_at =
+            if self.status != StatusSelection::Interview || self.interview_at.is_empty() {
+                None
+            } else {
+                Some(
+                    PrimitiveDateTime::parse(&self.interview_at, INTERVIEW_AT_FORMAT)
+                        .map_err(|_| "Invalid interview time. Use YYYY-MM-DD HH:MM".to_string())?
+                        .assume_utc(),
+                )
+            };
+
+        let offer_deadline =
+            if self.status != StatusSelection::Offer || self.offer_deadline.is_empty() {
+                None
+            } else {
+                Some(
+                    PrimitiveDateTime::parse(&self.offer_deadline, INTERVIEW_AT_FORMAT)
+                        .map_err(|_| "Invalid offer deadline. Use YYYY-MM-DD HH:MM".to_string())?
+                        .assume_utc(),
+                )
+            };
+
+        let job = JobApplication {
             id,
             date,
             cv,
@@ -308,8 +884,281 @@ impl EditForm {
             position: self.position.clone(),
             status,
             location: self.location.clone(),
-            salary: SalaryRange::new(salary_min, salary_max),
-        })
+            salary,
+            interview_at,
+            offer_deadline,
+            follow_up,
+            company_canonical: Some(self.company_canonical.trim())
+                .filter(|canonical| !canonical.is_empty())
+                .map(str::to_string),
+            rejection_reason: Some(self.rejection_reason.trim())
+                .filter(|reason| self.status == StatusSelection::Rejected && !reason.is_empty())
+                .map(str::to_string),
+            notes: Some(self.notes.trim())
+                .filter(|notes| !notes.is_empty())
+                .map(str::to_string),
+            contact_name: Some(self.contact_name.trim())
+                .filter(|name| !name.is_empty())
+                .map(str::to_string),
+            contact_email: Some(self.contact_email.trim())
+                .filter(|email| !email.is_empty())
+                .map(str::to_string),
+            url: Some(self.url.trim())
+                .filter(|url| !url.is_empty())
+                .map(str::to_string),
+            pinned: self.pinned,
+            // Archived jobs are not listed, so the form only edits active ones.
+            archived: false,
+            priority: self.priority,
+            updated_at: self.updated_at,
+            created_at: None,
+        };
+
+        let errors = job.validate();
+        if errors.is_empty() {
+            Ok(job)
+        } else {
+            Err(errors
+                .iter()
+                .map(ValidationError::message)
+                .collect::<Vec<_>>()
+                .join("; "))
+        }
+    }
+}
+
+/// A transient error notification shown over the bottom of the window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Toast {
+    pub id: u64,
+    pub message: String,
+    pub expires_at: Instant,
+}
+
+/// Toasts currently on screen, oldest first.
+#[derive(Debug, Clone, Default)]
+pub struct ToastQueue {
+    toasts: Vec<Toast>,
+    next_id: u64,
+}
+
+impl ToastQueue {
+    /// Shows `message` for `TOAST_DURATION` starting at `now`, returning the
+    /// new toast's id.
+    pub fn push(&mut self, message: impl Into<String>, now: Instant) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.toasts.push(Toast {
+            id,
+            message: message.into(),
+            expires_at: now + TOAST_DURATION,
+        });
+        id
+    }
+
+    /// Removes every toast whose display time has run out by `now`.
+    pub fn expire(&mut self, now: Instant) {
+        self.toasts.retain(|toast| toast.expires_at > now);
+    }
+
+    /// Removes the toast with `id`, if it is still shown.
+    pub fn dismiss(&mut self, id: u64) {
+        self.toasts.retain(|toast| toast.id != id);
+    }
+
+    /// Returns the toasts on screen, oldest first.
+    #[must_use]
+    pub fn toasts(&self) -> &[Toast] {
+        &self.toasts
+    }
+
+    /// Returns whether no toasts are shown.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+}
+
+/// Recently deleted jobs, most recent last, so deletes can be undone.
+///
+/// Holds at most `UNDO_CAPACITY` jobs; pushing more forgets the oldest.
+#[derive(Debug, Clone, Default)]
+pub struct UndoStack {
+    jobs: VecDeque<JobApplication>,
+}
+
+impl UndoStack {
+    /// Remembers a deleted job, forgetting the oldest one when full.
+    pub fn push(&mut self, job: JobApplication) {
+        if self.jobs.len() == UNDO_CAPACITY {
+            self.jobs.pop_front();
+        }
+        self.jobs.push_back(job);
+    }
+
+    /// Takes the most recently deleted job.
+    pub fn pop(&mut self) -> Option<JobApplication> {
+        self.jobs.pop_back()
+    }
+
+    /// Returns whether there is nothing to undo.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.jobs.is_empty()
+    }
+}
+
+/// An in-progress edit form saved to disk so it survives the app closing.
+///
+/// Stored as `key=value` lines; the value is everything after the first `=`,
+/// so form contents are kept verbatim. Notes may span lines, so their
+/// newlines are written as `\n` and backslashes are doubled.
+#[derive(Debug, Clone)]
+pub struct Draft {
+    /// The job being edited, or 0 for a new job.
+    pub job_id: i64,
+    pub form: EditForm,
+}
+
+impl Draft {
+    /// Loads the draft at `path`, returning `None` if there is none.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The file exists but cannot be read (`AppError::FileSystem`)
+    /// - The file is malformed (`AppError::Configuration`)
+    pub fn load(path: impl AsRef<Path>) -> AppResult<Option<Self>> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents).map(Some),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Saves the draft to `path`, creating parent directories as needed.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the directory or file cannot be
+    /// written (`AppError::FileSystem`).
+    pub fn save(&self, path: impl AsRef<Path>) -> AppResult<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, self.to_string())?;
+        Ok(())
+    }
+
+    /// Removes the draft at `path`. A missing draft is not an error.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the file exists but cannot be
+    /// removed (`AppError::FileSystem`).
+    pub fn clear(path: impl AsRef<Path>) -> AppResult<()> {
+        match std::fs::remove_file(path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Parses draft file contents.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AppError::Configuration` if a line has no `=`, the job id is
+    /// not a number, or the status is unknown.
+    pub fn parse(contents: &str) -> AppResult<Self> {
+        let mut draft = Self {
+            job_id: 0,
+            form: EditForm::new(),
+        };
+
+        for (index, line) in contents.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            let (key, value) = line.split_once('=').ok_or_else(|| {
+                AppError::Configuration(format!("draft line {}: expected `key=value`", index + 1))
+            })?;
+            let value = value.to_string();
+            match key {
+                "job_id" => {
+                    draft.job_id = value.parse().map_err(|_| {
+                        AppError::Configuration(format!("draft: invalid job id: {value}"))
+                    })?;
+                }
+                "company" => draft.form.company = value,
+                "position" => draft.form.position = value,
+                "location" => draft.form.location = value,
+                "date" => draft.form.date = value,
+                "salary_min" => draft.form.salary_min = value,
+                "salary_max" => draft.form.salary_max = value,
+                "salary_currency" => draft.form.salary_currency = value,
+                "status" => {
+                    draft.form.status = StatusSelection::ALL
+                        .into_iter()
+                        .find(|status| status.to_string() == value)
+                        .ok_or_else(|| {
+                            AppError::Configuration(format!("draft: unknown status: {value}"))
+                        })?;
+                }
+                "cv_path" => draft.form.cv_path = value,
+                "interview_round" => draft.form.interview_round = value,
+                "interview_at" => draft.form.interview_at = value,
+                "offer_amount" => draft.form.offer_amount = value,
+                "offer_deadline" => draft.form.offer_deadline = value,
+                "company_canonical" => draft.form.company_canonical = value,
+                "rejection_reason" => draft.form.rejection_reason = value,
+                "notes" => draft.form.notes = unescape_draft_value(&value),
+                "contact_name" => draft.form.contact_name = value,
+                "contact_email" => draft.form.contact_email = value,
+                "url" => draft.form.url = value,
+                "follow_up" => draft.form.follow_up = value,
+                "pinned" => draft.form.pinned = value == "true",
+                "priority" => {
+                    if let Ok(priority) = Priority::from_db_string(&value) {
+                        draft.form.priority = priority;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(draft)
+    }
+}
+
+impl fmt::Display for Draft {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let form = &self.form;
+        writeln!(f, "job_id={}", self.job_id)?;
+        writeln!(f, "company={}", form.company)?;
+        writeln!(f, "position={}", form.position)?;
+        writeln!(f, "location={}", form.location)?;
+        writeln!(f, "date={}", form.date)?;
+        writeln!(f, "salary_min={}", form.salary_min)?;
+        writeln!(f, "salary_max={}", form.salary_max)?;
+        writeln!(f, "salary_currency={}", form.salary_currency)?;
+        writeln!(f, "status={}", form.status)?;
+        writeln!(f, "cv_path={}", form.cv_path)?;
+        writeln!(f, "interview_round={}", form.interview_round)?;
+        writeln!(f, "interview_at={}", form.interview_at)?;
+        writeln!(f, "offer_amount={}", form.offer_amount)?;
+        writeln!(f, "offer_deadline={}", form.offer_deadline)?;
+        writeln!(f, "company_canonical={}", form.company_canonical)?;
+        writeln!(f, "rejection_reason={}", form.rejection_reason)?;
+        writeln!(f, "notes={}", escape_draft_value(&form.notes))?;
+        writeln!(f, "contact_name={}", form.contact_name)?;
+        writeln!(f, "contact_email={}", form.contact_email)?;
+        writeln!(f, "url={}", form.url)?;
+        writeln!(f, "follow_up={}", form.follow_up)?;
+        writeln!(f, "pinned={}", form.pinned)?;
+        writeln!(f, "priority={}", form.priority.to_db_string())
     }
 }
 
@@ -324,7 +1173,30 @@ pub struct JobTrackerApp {
     editing_job_id: Option<i64>,
     edit_form: EditForm,
     theme: AppTheme,
-    error_message: Option<String>,
+    toasts: ToastQueue,
+    config: Config,
+    status_filter: StatusFilter,
+    init_error: Option<String>,
+    draft_dirty: bool,
+    pending_draft: Option<Draft>,
+    quick_add: String,
+    due_reminders: Vec<Reminder>,
+    checklist: Vec<ChecklistItem>,
+    checklist_input: String,
+    /// Query typed into the command palette, or `None` while it is closed.
+    command_palette: Option<String>,
+    /// Row count of a bulk delete awaiting confirmation.
+    pending_bulk_delete: Option<usize>,
+    /// Non-blocking notice from the last save, e.g. a duplicate company.
+    warning_message: Option<String>,
+    /// Jobs deleted this session, for `Message::UndoDelete`.
+    undo_stack: UndoStack,
+    /// Column the table is sorted by.
+    sort_by: SortBy,
+    /// Direction of the table sort.
+    sort_order: SortOrder,
+    /// Text typed into the search box; only matching rows are shown.
+    search_query: String,
 }
 
 impl Default for JobTrackerApp {
@@ -358,48 +1230,319 @@ impl JobTrackerApp {
             editing_job_id: None,
             edit_form: EditForm::new(),
             theme: AppTheme::Light,
-            error_message: None,
+            toasts: ToastQueue::default(),
+            config: Config::default(),
+            status_filter: StatusFilter::default(),
+            init_error: None,
+            warning_message: None,
+            undo_stack: UndoStack::default(),
+            sort_by: SortBy::default(),
+            sort_order: SortOrder::default(),
+            search_query: String::new(),
+            draft_dirty: false,
+            pending_draft: None,
+            quick_add: String::new(),
+            due_reminders: Vec::new(),
+            checklist: Vec::new(),
+            checklist_input: String::new(),
+            command_palette: None,
+            pending_bulk_delete: None,
         }
     }
 
-    /// Loads all jobs from the database.
-    ///
-    /// Creates an asynchronous task to fetch all job applications from
-    /// the database. If no database is connected, returns an empty task.
+    /// Loads the reminders that are due now.
     ///
     /// # Returns
     ///
-    /// A Task that will send a `JobsLoaded` message when complete.
-    fn load_jobs(&self) -> Task<Message> {
+    /// A Task that will send a `RemindersLoaded` message when complete.
+    fn load_due_reminders(&self) -> Task<Message> {
         self.database.as_ref().map_or_else(Task::none, |db| {
             let db = db.clone();
             Task::perform(
-                async move { db.get_all_jobs().await.map_err(|e| e.to_string()) },
-                Message::JobsLoaded,
+                async move {
+                    db.get_due_reminders(time::OffsetDateTime::now_utc())
+                        .await
+                        .map_err(|e| e.to_string())
+                },
+                Message::RemindersLoaded,
             )
         })
     }
 
-    fn view_table(&self) -> Element<'_, Message> {
-        let header = row![
-            container(text("Company")).width(Length::FillPortion(2)),
-            container(text("Position")).width(Length::FillPortion(2)),
-            container(text("Location")).width(Length::FillPortion(2)),
-            container(text("Status")).width(Length::FillPortion(2)),
-            container(text("Salary")).width(Length::FillPortion(2)),
-            container(text("Date")).width(Length::FillPortion(1)),
-            container(text("Actions")).width(Length::FillPortion(1)),
-        ]
-        .spacing(10);
-
-        let mut content = column![header].spacing(5);
+    /// Reads the persisted theme, if any.
+    ///
+    /// # Returns
+    ///
+    /// A Task that will send a `ThemeLoaded` message when complete.
+    fn load_theme(&self) -> Task<Message> {
+        self.database.as_ref().map_or_else(Task::none, |db| {
+            let db = db.clone();
+            Task::perform(
+                async move {
+                    db.get_setting(THEME_SETTING_KEY)
+                        .await
+                        .map(|value| value.as_deref().and_then(AppTheme::from_setting_value))
+                        .map_err(|e| e.to_string())
+                },
+                Message::ThemeLoaded,
+            )
+        })
+    }
 
-        if self.editing_job_id == Some(0) {
-            let edit_row = self.view_edit_row();
-            content = content.push(edit_row);
-        }
+    /// Loads the checklist of the selected job, if any.
+    ///
+    /// # Returns
+    ///
+    /// A Task that will send a `ChecklistLoaded` message when complete.
+    fn load_checklist(&self) -> Task<Message> {
+        match (&self.database, self.selected_job_id) {
+            (Some(db), Some(job_id)) => {
+                let db = db.clone();
+                Task::perform(
+                    async move {
+                        db.get_checklist_items(job_id)
+                            .await
+                            .map_err(|e| e.to_string())
+                    },
+                    move |result| Message::ChecklistLoaded(job_id, result),
+                )
+            }
+            _ => Task::none(),
+        }
+    }
+
+    /// Runs a checklist change, then reloads the selected job's checklist.
+    fn update_checklist<F, Fut>(&self, change: F) -> Task<Message>
+    where
+        F: FnOnce(Database) -> Fut,
+        Fut: Future<Output = Result<(), DbError>> + Send + 'static,
+    {
+        match (&self.database, self.selected_job_id) {
+            (Some(db), Some(job_id)) => {
+                let db = db.clone();
+                let change = change(db.clone());
+                Task::perform(
+                    async move {
+                        change.await.map_err(|e| e.to_string())?;
+                        db.get_checklist_items(job_id)
+                            .await
+                            .map_err(|e| e.to_string())
+                    },
+                    move |result| Message::ChecklistLoaded(job_id, result),
+                )
+            }
+            _ => Task::none(),
+        }
+    }
+
+    fn view_checklist(&self) -> Element<'_, Message> {
+        let items = self.checklist.iter().filter_map(|item| {
+            let id = item.id?;
+            Some(
+                row![
+                    checkbox(&item.text, item.done)
+                        .on_toggle(move |done| Message::ToggleChecklistItem(id, done))
+                        .width(Length::Fill),
+                    button(text("✕").size(12))
+                        .style(button::text)
+                        .on_press(Message::DeleteChecklistItem(id)),
+                ]
+                .align_y(iced::Alignment::Center)
+                .into(),
+            )
+        });
+
+        column![
+            text(format!(
+                "Checklist ({}% done)",
+                checklist_completion(&self.checklist)
+            ))
+            .size(16)
+        ]
+        .extend(items)
+        .push(
+            text_input("Add a step", &self.checklist_input)
+                .on_input(Message::ChecklistInputChanged)
+                .on_submit(Message::AddChecklistItem),
+        )
+        .spacing(5)
+        .into()
+    }
+
+    fn view_warning(warning: &str) -> Element<'_, Message> {
+        container(
+            row![
+                text(warning),
+                button(text("Dismiss"))
+                    .style(button::secondary)
+                    .on_press(Message::DismissWarning),
+            ]
+            .spacing(10)
+            .align_y(iced::Alignment::Center),
+        )
+        .padding(10)
+        .style(|_theme| container::Style {
+            background: Some(iced::Background::Color(iced::Color::from_rgb(
+                1.0, 0.95, 0.6,
+            ))),
+            text_color: Some(iced::Color::BLACK),
+            ..Default::default()
+        })
+        .into()
+    }
+
+    fn view_reminders(&self) -> Element<'_, Message> {
+        let reminders = self.due_reminders.iter().map(|reminder| {
+            let company = self
+                .jobs
+                .iter()
+                .find(|job| job.id == Some(reminder.job_id))
+                .map_or("Unknown job", |job| job.company.as_str());
+            row![
+                text(format!("{company}: {}", reminder.message)).width(Length::Fill),
+                button(text("Dismiss"))
+                    .style(button::secondary)
+                    .on_press_maybe(reminder.id.map(Message::DismissReminder)),
+            ]
+            .spacing(10)
+            .align_y(iced::Alignment::Center)
+            .into()
+        });
+
+        container(
+            column![text("Reminders").size(16)]
+                .extend(reminders)
+                .spacing(5),
+        )
+        .padding(10)
+        .width(Length::Fill)
+        .style(container::bordered_box)
+        .into()
+    }
+
+    /// Loads all jobs from the database.
+    ///
+    /// Creates an asynchronous task to fetch the job applications matching
+    /// the status filter from the database. If no database is connected,
+    /// returns an empty task.
+    ///
+    /// # Returns
+    ///
+    /// A Task that will send a `JobsLoaded` message when complete.
+    fn load_jobs(&self) -> Task<Message> {
+        self.database.as_ref().map_or_else(Task::none, |db| {
+            let db = db.clone();
+            let filter = self.status_filter.clone();
+            Task::perform(
+                async move { fetch_jobs(&db, &filter).await },
+                Message::JobsLoaded,
+            )
+        })
+    }
+
+    fn view_accent_swatches(&self) -> Element<'_, Message> {
+        ACCENT_PRESETS
+            .into_iter()
+            .fold(row![].spacing(4), |swatches, accent| {
+                let selected = self.config.accent == accent;
+                let color = accent_to_color(accent);
+                swatches.push(
+                    button(Space::new(Length::Fixed(14.0), Length::Fixed(14.0)))
+                        .padding(0)
+                        .style(move |theme: &Theme, _status| button::Style {
+                            background: Some(iced::Background::Color(color)),
+                            border: iced::Border {
+                                color: if selected {
+                                    theme.palette().text
+                                } else {
+                                    iced::Color::TRANSPARENT
+                                },
+                                width: 2.0,
+                                radius: 3.0.into(),
+                            },
+                            ..button::Style::default()
+                        })
+                        .on_press(Message::AccentSelected(accent)),
+                )
+            })
+            .align_y(iced::Alignment::Center)
+            .into()
+    }
+
+    fn view_status_chips(&self) -> Element<'_, Message> {
+        StatusSelection::ALL
+            .into_iter()
+            .fold(row![text("Filter:")].spacing(5), |chips, status| {
+                let style = if self.status_filter.is_active(&status) {
+                    button::primary
+                } else {
+                    button::secondary
+                };
+                chips.push(
+                    button(text(status.to_string()))
+                        .style(style)
+                        .on_press(Message::ToggleStatusFilter(status)),
+                )
+            })
+            .align_y(iced::Alignment::Center)
+            .into()
+    }
+
+    /// A column header that sorts the table by `by` when clicked, with an
+    /// arrow when the table is currently sorted by it.
+    fn view_sort_header(&self, label: &str, by: SortBy) -> Element<'_, Message> {
+        let label = if self.sort_by == by {
+            let arrow = match self.sort_order {
+                SortOrder::Asc => "▲",
+                SortOrder::Desc => "▼",
+            };
+            format!("{label} {arrow}")
+        } else {
+            label.to_string()
+        };
+        button(text(label))
+            .padding(0)
+            .style(button::text)
+            .on_press(Message::SortColumn(by))
+            .into()
+    }
+
+    fn view_table_header(&self) -> Element<'_, Message> {
+        row![
+            container(self.view_sort_header("Company", SortBy::Company))
+                .width(Length::FillPortion(2)),
+            container(self.view_sort_header("Position", SortBy::Position))
+                .width(Length::FillPortion(2)),
+            container(text("Location")).width(Length::FillPortion(2)),
+            container(text("Status")).width(Length::FillPortion(2)),
+            container(self.view_sort_header("Salary", SortBy::SalaryMax))
+                .width(Length::FillPortion(2)),
+            container(self.view_sort_header("Date", SortBy::Date)).width(Length::FillPortion(1)),
+            container(text("In status")).width(Length::FillPortion(1)),
+            container(text("Actions")).width(Length::FillPortion(1)),
+        ]
+        .spacing(10)
+        .into()
+    }
+
+    fn view_table(&self) -> Element<'_, Message> {
+        let header = self.view_table_header();
+        let search = text_input("Search company, position or location", &self.search_query)
+            .on_input(Message::SearchChanged)
+            .width(Length::Fixed(360.0));
+        let mut content = column![search, header].spacing(5);
+        let now = time::OffsetDateTime::now_utc();
+
+        if self.editing_job_id == Some(0) {
+            let edit_row = self.view_edit_row();
+            content = content.push(edit_row);
+        }
 
-        for job in &self.jobs {
+        let visible = self
+            .jobs
+            .iter()
+            .filter(|job| matches_query(job, &self.search_query));
+        for job in visible {
             let is_selected = self.selected_job_id == job.id;
             let is_editing = self.editing_job_id == job.id;
 
@@ -407,25 +1550,49 @@ impl JobTrackerApp {
                 let edit_row = self.view_edit_row();
                 content = content.push(edit_row);
             } else {
-                let status_text = match &job.status {
-                    Status::Applied => "Applied".to_string(),
-                    Status::Interview(round) => format!("Interview ({round})"),
-                    Status::Offer(amount) => format!("Offer ({amount})"),
-                    Status::Rejected => "Rejected".to_string(),
-                };
+                let locale = self.config.number_locale;
+                let status_text = status_label(&job.status, locale);
 
                 let job_row = row![
-                    container(button(text(&job.company)).on_press(Message::SelectJob(job.id)))
-                        .width(Length::FillPortion(2)),
+                    container(
+                        row![
+                            completeness_dot(job.is_complete()),
+                            button(text(&job.company)).on_press(Message::SelectJob(job.id)),
+                        ]
+                        .spacing(5)
+                        .align_y(iced::Alignment::Center)
+                    )
+                    .width(Length::FillPortion(2)),
                     container(text(&job.position)).width(Length::FillPortion(2)),
                     container(text(&job.location)).width(Length::FillPortion(2)),
                     container(text(status_text)).width(Length::FillPortion(2)),
-                    container(text(job.salary.to_string())).width(Length::FillPortion(2)),
+                    container(text(format_salary(&job.salary, locale)))
+                        .width(Length::FillPortion(2)),
                     container(text(job.date.map(|d| d.to_string()).unwrap_or_default()))
                         .width(Length::FillPortion(1)),
+                    container(text(
+                        job.days_in_status(now)
+                            .map(|days| format!("{days}d"))
+                            .unwrap_or_default()
+                    ))
+                    .width(Length::FillPortion(1)),
                     container(
                         row![
                             button(text("Edit")).on_press(Message::EditJob(job.id.unwrap_or(0))),
+                            button(text("Clone"))
+                                .style(button::secondary)
+                                .on_press(Message::DuplicateJob(job.id.unwrap_or(0))),
+                            button(text(if job.pinned { "Unpin" } else { "Pin" }))
+                                .style(button::secondary)
+                                .on_press(Message::SetPinned(job.id.unwrap_or(0), !job.pinned)),
+                            button(text("Applied today")).on_press_maybe(
+                                job.is_unsent(now.date())
+                                    .then(|| Message::MarkAppliedToday(job.id.unwrap_or(0)))
+                            ),
+                            button(text("Next")).on_press_maybe(
+                                job.next_status()
+                                    .map(|_| Message::CycleStatus(job.id.unwrap_or(0)))
+                            ),
                             button(text("Delete"))
                                 .on_press(Message::DeleteJob(job.id.unwrap_or(0))),
                         ]
@@ -436,9 +1603,15 @@ impl JobTrackerApp {
                 .spacing(10);
 
                 let styled_row = if is_selected {
+                    let accent = accent_to_color(self.config.accent);
+                    container(job_row).style(move |_theme| container::Style {
+                        background: Some(iced::Background::Color(accent.scale_alpha(0.2))),
+                        ..Default::default()
+                    })
+                } else if job.is_overdue(now.date()) {
                     container(job_row).style(|_theme| container::Style {
-                        background: Some(iced::Background::Color(iced::Color::from_rgb(
-                            0.9, 0.9, 1.0,
+                        background: Some(iced::Background::Color(iced::Color::from_rgba(
+                            0.8, 0.1, 0.1, 0.15,
                         ))),
                         ..Default::default()
                     })
@@ -456,6 +1629,7 @@ impl JobTrackerApp {
     #[allow(clippy::too_many_lines)]
     fn view_edit_row(&self) -> Element<'_, Message> {
         let theme = self.theme;
+        let salary_sliders = self.edit_form.salary_slider_values();
 
         let status_controls = match self.edit_form.status {
             StatusSelection::Interview => row![
@@ -471,7 +1645,20 @@ impl JobTrackerApp {
                 }),
                 text_input("Round", &self.edit_form.interview_round)
                     .on_input(Message::InterviewRoundChanged)
-                    .width(Length::Fixed(80.0))
+                    .width(Length::Fixed(80.0)),
+                text("At (UTC):").style(move |_| {
+                    match theme {
+                        AppTheme::Light => iced::widget::text::Style {
+                            color: Some(iced::Color::from_rgb(0.0, 0.0, 0.0)),
+                        },
+                        AppTheme::Dark => iced::widget::text::Style {
+                            color: Some(iced::Color::from_rgb(0.9, 0.9, 0.9)),
+                        },
+                    }
+                }),
+                text_input("YYYY-MM-DD HH:MM", &self.edit_form.interview_at)
+                    .on_input(Message::InterviewAtChanged)
+                    .width(Length::Fixed(150.0))
             ]
             .spacing(5),
             StatusSelection::Offer => row![
@@ -487,10 +1674,41 @@ impl JobTrackerApp {
                 }),
                 text_input("Amount", &self.edit_form.offer_amount)
                     .on_input(Message::OfferAmountChanged)
-                    .width(Length::Fixed(120.0))
+                    .width(Length::Fixed(120.0)),
+                text("Deadline (UTC):").style(move |_| {
+                    match theme {
+                        AppTheme::Light => iced::widget::text::Style {
+                            color: Some(iced::Color::from_rgb(0.0, 0.0, 0.0)),
+                        },
+                        AppTheme::Dark => iced::widget::text::Style {
+                            color: Some(iced::Color::from_rgb(0.9, 0.9, 0.9)),
+                        },
+                    }
+                }),
+                text_input("YYYY-MM-DD HH:MM", &self.edit_form.offer_deadline)
+                    .on_input(Message::OfferDeadlineChanged)
+                    .width(Length::Fixed(150.0))
             ]
             .spacing(5),
-            _ => row![].spacing(5),
+            StatusSelection::Rejected => row![
+                text("Reason:").style(move |_| {
+                    match theme {
+                        AppTheme::Light => iced::widget::text::Style {
+                            color: Some(iced::Color::from_rgb(0.0, 0.0, 0.0)),
+                        },
+                        AppTheme::Dark => iced::widget::text::Style {
+                            color: Some(iced::Color::from_rgb(0.9, 0.9, 0.9)),
+                        },
+                    }
+                }),
+                text_input("Optional", &self.edit_form.rejection_reason)
+                    .on_input(Message::RejectionReasonChanged)
+                    .width(Length::Fixed(200.0))
+            ]
+            .spacing(5),
+            StatusSelection::Applied | StatusSelection::Accepted | StatusSelection::Withdrawn => {
+                row![].spacing(5)
+            }
         };
 
         let edit_form = column![
@@ -508,6 +1726,10 @@ impl JobTrackerApp {
                     }),
                     text_input("Company", &self.edit_form.company)
                         .on_input(Message::CompanyChanged)
+                        .width(Length::Fixed(200.0)),
+                    text_input("Group as (optional)", &self.edit_form.company_canonical)
+                        .on_input(Message::CompanyCanonicalChanged)
+                        .size(12)
                         .width(Length::Fixed(200.0))
                 ]
                 .spacing(2),
@@ -545,6 +1767,27 @@ impl JobTrackerApp {
                 .spacing(2)
             ]
             .spacing(10),
+            text_input("Notes (optional)", &self.edit_form.notes)
+                .on_input(Message::NotesChanged)
+                .width(Length::Fixed(580.0)),
+            row![
+                text_input("Contact name (optional)", &self.edit_form.contact_name)
+                    .on_input(Message::ContactNameChanged)
+                    .width(Length::Fixed(285.0)),
+                text_input("Contact email (optional)", &self.edit_form.contact_email)
+                    .on_input(Message::ContactEmailChanged)
+                    .width(Length::Fixed(285.0))
+            ]
+            .spacing(10),
+            row![
+                text_input("Posting URL (optional)", &self.edit_form.url)
+                    .on_input(Message::UrlChanged)
+                    .width(Length::Fixed(410.0)),
+                text_input("Follow up (YYYY-MM-DD)", &self.edit_form.follow_up)
+                    .on_input(Message::FollowUpChanged)
+                    .width(Length::Fixed(160.0))
+            ]
+            .spacing(10),
             row![
                 column![
                     text("Date (YYYY-MM-DD):").style(move |_| {
@@ -575,7 +1818,14 @@ impl JobTrackerApp {
                     }),
                     text_input("Min", &self.edit_form.salary_min)
                         .on_input(Message::SalaryMinChanged)
-                        .width(Length::Fixed(100.0))
+                        .width(Length::Fixed(100.0)),
+                    slider(
+                        0..=SALARY_SLIDER_MAX,
+                        salary_sliders.0.min(SALARY_SLIDER_MAX),
+                        Message::SalaryMinSlid
+                    )
+                    .step(SALARY_SLIDER_STEP)
+                    .width(Length::Fixed(100.0))
                 ]
                 .spacing(2),
                 column![
@@ -591,7 +1841,30 @@ impl JobTrackerApp {
                     }),
                     text_input("Max", &self.edit_form.salary_max)
                         .on_input(Message::SalaryMaxChanged)
-                        .width(Length::Fixed(100.0))
+                        .width(Length::Fixed(100.0)),
+                    slider(
+                        0..=SALARY_SLIDER_MAX,
+                        salary_sliders.1.min(SALARY_SLIDER_MAX),
+                        Message::SalaryMaxSlid
+                    )
+                    .step(SALARY_SLIDER_STEP)
+                    .width(Length::Fixed(100.0))
+                ]
+                .spacing(2),
+                column![
+                    text("Currency:").style(move |_| {
+                        match theme {
+                            AppTheme::Light => iced::widget::text::Style {
+                                color: Some(iced::Color::from_rgb(0.0, 0.0, 0.0)),
+                            },
+                            AppTheme::Dark => iced::widget::text::Style {
+                                color: Some(iced::Color::from_rgb(0.9, 0.9, 0.9)),
+                            },
+                        }
+                    }),
+                    text_input(DEFAULT_CURRENCY, &self.edit_form.salary_currency)
+                        .on_input(Message::SalaryCurrencyChanged)
+                        .width(Length::Fixed(60.0))
                 ]
                 .spacing(2)
             ]
@@ -608,17 +1881,12 @@ impl JobTrackerApp {
                             },
                         }
                     }),
-                    row![
-                        button(text("Applied"))
-                            .on_press(Message::StatusChanged(StatusSelection::Applied)),
-                        button(text("Interview"))
-                            .on_press(Message::StatusChanged(StatusSelection::Interview)),
-                        button(text("Offer"))
-                            .on_press(Message::StatusChanged(StatusSelection::Offer)),
-                        button(text("Rejected"))
-                            .on_press(Message::StatusChanged(StatusSelection::Rejected)),
-                    ]
-                    .spacing(5)
+                    pick_list(
+                        StatusSelection::ALL,
+                        Some(&self.edit_form.status),
+                        Message::StatusChanged
+                    )
+                    .width(Length::Fixed(150.0))
                 ]
                 .spacing(2),
                 status_controls
@@ -666,31 +1934,54 @@ impl JobTrackerApp {
     }
 
     fn view_cv_panel(&self) -> Element<'_, Message> {
-        let content = self.selected_job_id.map_or_else(
-            || column![text("Select a job to view CV information")],
-            |selected_id| {
-                self.jobs
-                    .iter()
-                    .find(|j| j.id == Some(selected_id))
-                    .map_or_else(
-                        || column![text("Job not found")],
-                        |job| {
-                            column![
-                                text("Selected Job").size(20),
-                                text(format!("Company: {}", job.company)),
-                                text(format!("Position: {}", job.position)),
-                                Space::with_height(Length::Fixed(20.0)),
-                                text("CV Information").size(16),
-                                job.cv.as_ref().map_or_else(
-                                    || text("No CV"),
-                                    |cv_path| text(format!("CV Path: {}", cv_path.display()))
-                                )
-                            ]
-                            .spacing(5)
-                        },
-                    )
-            },
-        );
+        let content =
+            self.selected_job_id.map_or_else(
+                || column![text("Select a job to view CV information")],
+                |selected_id| {
+                    self.jobs
+                        .iter()
+                        .find(|j| j.id == Some(selected_id))
+                        .map_or_else(
+                            || column![text("Job not found")],
+                            |job| {
+                                column![
+                                    text("Selected Job").size(20),
+                                    text(format!("Company: {}", job.company)),
+                                    text(format!("Position: {}", job.position)),
+                                    column![].push_maybe(
+                                        job.url.as_ref().map(|url| text(format!("Posting: {url}")))
+                                    ),
+                                    button(text("Duplicate for another company"))
+                                        .style(button::secondary)
+                                        .on_press_maybe(self.editing_job_id.is_none().then_some(
+                                            Message::DuplicateForOtherCompany(selected_id)
+                                        )),
+                                    Self::view_pipeline_progress(&job.status),
+                                    Space::with_height(Length::Fixed(20.0)),
+                                    text("CV Information").size(16),
+                                    job.cv.as_ref().map_or_else(
+                                        || text("No CV"),
+                                        |cv_path| text(format!("CV Path: {}", cv_path.display()))
+                                    ),
+                                    column![].push_maybe(
+                                        job.cv
+                                            .as_deref()
+                                            .filter(|cv_path| {
+                                                cv_status(cv_path) == CvStatus::Missing
+                                            })
+                                            .map(|_| {
+                                                text("⚠ File missing")
+                                                    .color(iced::Color::from_rgb(0.8, 0.1, 0.1))
+                                            })
+                                    ),
+                                    Space::with_height(Length::Fixed(20.0)),
+                                    self.view_checklist(),
+                                ]
+                                .spacing(5)
+                            },
+                        )
+                },
+            );
 
         container(content)
             .padding(20)
@@ -711,47 +2002,219 @@ impl JobTrackerApp {
             .into()
     }
 
-    async fn initialize_database() -> Result<(Database, Vec<JobApplication>), String> {
+    fn view_pipeline_progress(status: &Status) -> Element<'_, Message> {
+        let stages = pipeline_stages(status);
+        let mut progress = row![].spacing(4);
+
+        for (label, state) in PIPELINE_STAGES.into_iter().zip(stages) {
+            let (background, foreground) = match state {
+                StageState::Completed => (
+                    iced::Color::from_rgb(0.6, 0.8, 0.6),
+                    iced::Color::from_rgb(0.1, 0.1, 0.1),
+                ),
+                StageState::Current => (iced::Color::from_rgb(0.2, 0.6, 0.2), iced::Color::WHITE),
+                StageState::Pending => (
+                    iced::Color::from_rgb(0.85, 0.85, 0.85),
+                    iced::Color::from_rgb(0.3, 0.3, 0.3),
+                ),
+                StageState::Dimmed => (
+                    iced::Color::from_rgb(0.9, 0.9, 0.9),
+                    iced::Color::from_rgb(0.6, 0.6, 0.6),
+                ),
+            };
+            progress = progress.push(
+                container(text(label).size(12).color(foreground))
+                    .padding([2, 6])
+                    .style(move |_| container::Style {
+                        background: Some(iced::Background::Color(background)),
+                        border: iced::Border {
+                            radius: 3.0.into(),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    }),
+            );
+        }
+
+        if *status == Status::Rejected {
+            progress = progress.push(
+                text("✕ Rejected")
+                    .size(12)
+                    .color(iced::Color::from_rgb(0.8, 0.1, 0.1)),
+            );
+        } else if *status == Status::Withdrawn {
+            progress = progress.push(
+                text("↩ Withdrawn")
+                    .size(12)
+                    .color(iced::Color::from_rgb(0.5, 0.5, 0.5)),
+            );
+        } else if *status == Status::Accepted {
+            progress = progress.push(
+                text("✓ Accepted")
+                    .size(12)
+                    .color(iced::Color::from_rgb(0.1, 0.6, 0.2)),
+            );
+        }
+
+        progress.into()
+    }
+
+    fn view_command_palette(&self) -> Element<'_, Message> {
+        let Some(query) = &self.command_palette else {
+            return Space::new(Length::Shrink, Length::Shrink).into();
+        };
+
+        let matches = rank_commands(query)
+            .into_iter()
+            .take(COMMAND_PALETTE_RESULTS)
+            .enumerate()
+            .map(|(rank, index)| {
+                button(text(COMMANDS[index].0))
+                    .width(Length::Fill)
+                    .style(if rank == 0 {
+                        button::primary
+                    } else {
+                        button::text
+                    })
+                    .on_press(Message::RunCommand(index))
+                    .into()
+            });
+
+        container(
+            container(
+                column![
+                    text_input("Type a command...", query)
+                        .id(text_input::Id::new(COMMAND_PALETTE_ID))
+                        .on_input(Message::CommandPaletteChanged)
+                        .on_submit(Message::CommandPaletteSubmitted),
+                    column(matches).spacing(2),
+                ]
+                .spacing(8),
+            )
+            .padding(10)
+            .width(Length::Fixed(400.0))
+            .style(container::bordered_box),
+        )
+        .padding(60)
+        .width(Length::Fill)
+        .align_x(iced::alignment::Horizontal::Center)
+        .into()
+    }
+
+    fn view_toasts(&self) -> Element<'_, Message> {
+        let theme = self.theme;
+        let toasts = self.toasts.toasts().iter().map(|toast| {
+            button(text(&toast.message).style(move |_| match theme {
+                AppTheme::Light => iced::widget::text::Style {
+                    color: Some(iced::Color::from_rgb(0.8, 0.0, 0.0)),
+                },
+                AppTheme::Dark => iced::widget::text::Style {
+                    color: Some(iced::Color::from_rgb(1.0, 0.4, 0.4)),
+                },
+            }))
+            .on_press(Message::DismissToast(toast.id))
+            .padding(10)
+            .style(move |_, _| button::Style {
+                background: Some(iced::Background::Color(match theme {
+                    AppTheme::Light => iced::Color::from_rgb(1.0, 0.9, 0.9),
+                    AppTheme::Dark => iced::Color::from_rgb(0.3, 0.1, 0.1),
+                })),
+                border: iced::Border {
+                    width: 1.0,
+                    color: match theme {
+                        AppTheme::Light => iced::Color::from_rgb(1.0, 0.5, 0.5),
+                        AppTheme::Dark => iced::Color::from_rgb(0.8, 0.3, 0.3),
+                    },
+                    radius: 5.0.into(),
+                },
+                ..Default::default()
+            })
+            .into()
+        });
+
+        container(column(toasts).spacing(8))
+            .padding(20)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Center)
+            .align_y(iced::alignment::Vertical::Bottom)
+            .into()
+    }
+
+    async fn initialize_database() -> AppResult<(Database, Vec<JobApplication>)> {
         if let Err(e) = std::fs::create_dir_all("data") {
             eprintln!("Warning: Could not create data directory: {e}");
         }
 
-        let db = Database::new("sqlite:data/jobs.db")
-            .await
-            .map_err(|e| e.to_string())?;
+        let db = Database::new("sqlite:data/jobs.db").await?.with_cache();
 
-        let jobs = db.get_all_jobs().await.unwrap_or_else(|_| Vec::new());
+        let jobs = db
+            .get_all_jobs_cached()
+            .await
+            .unwrap_or_else(|_| Vec::new());
 
         Ok((db, jobs))
     }
 }
 
 impl JobTrackerApp {
-    fn init() -> (Self, Task<Message>) {
-        let app = Self::new();
-        let task = Task::perform(Self::initialize_database(), |result| match result {
-            Ok((db, _jobs)) => Message::DatabaseInitialized(db, Vec::new()),
-            Err(e) => Message::JobsLoaded(Err(e)),
-        });
+    fn init(config: Config) -> (Self, Task<Message>) {
+        let mut app = Self::new();
+        if config.autosave_draft {
+            app.pending_draft = Draft::load(DRAFT_PATH).unwrap_or_else(|e| {
+                eprintln!("Warning: Could not load draft: {e}");
+                None
+            });
+        }
+        app.config = config;
+        let task = Task::perform(Self::initialize_database(), Self::init_result_to_message);
         (app, task)
     }
 
+    /// Maps the outcome of opening the database to the message that records it.
+    ///
+    /// Failures become `Message::DatabaseInitFailed` so they are kept in the
+    /// application state and shown with a retry option instead of being lost.
+    fn init_result_to_message(result: AppResult<(Database, Vec<JobApplication>)>) -> Message {
+        match result {
+            Ok((db, _jobs)) => Message::DatabaseInitialized(db, Vec::new()),
+            Err(e) => Message::DatabaseInitFailed(e.to_string()),
+        }
+    }
+
     #[allow(clippy::too_many_lines)]
     fn update(&mut self, message: Message) -> Task<Message> {
+        if message.edits_form() && self.config.autosave_draft {
+            self.draft_dirty = true;
+        }
+
         match message {
             Message::DatabaseInitialized(db, _jobs) => {
                 self.database = Some(db);
-                self.error_message = None;
-                self.load_jobs()
+                self.init_error = None;
+                Task::batch([
+                    self.load_jobs(),
+                    self.load_due_reminders(),
+                    self.load_theme(),
+                ])
+            }
+            Message::DatabaseInitFailed(e) => {
+                self.database = None;
+                self.init_error = Some(e);
+                Task::none()
+            }
+            Message::RetryDatabaseInit => {
+                self.init_error = None;
+                Task::perform(Self::initialize_database(), Self::init_result_to_message)
             }
             Message::JobsLoaded(result) => {
                 match result {
-                    Ok(jobs) => {
+                    Ok(mut jobs) => {
+                        sort_jobs(&mut jobs, self.sort_by, self.sort_order);
                         self.jobs = jobs;
-                        self.error_message = None;
                     }
                     Err(e) => {
-                        self.error_message = Some(e);
+                        self.toasts.push(e, Instant::now());
                     }
                 }
                 Task::none()
@@ -759,16 +2222,42 @@ impl JobTrackerApp {
             Message::AddNewJob => {
                 if self.editing_job_id.is_none() {
                     self.editing_job_id = Some(0); // Use 0 for new jobs
-                    self.edit_form = EditForm::new();
+                    self.edit_form = EditForm::new().with_locale(self.config.number_locale);
 
                     self.edit_form.date = "2024-01-01".to_string();
                 }
                 Task::none()
             }
+            Message::DuplicateForOtherCompany(id) => {
+                if self.editing_job_id.is_none()
+                    && let Some(job) = self.jobs.iter().find(|j| j.id == Some(id))
+                {
+                    self.editing_job_id = Some(0);
+                    self.edit_form = EditForm::from_job(&job.duplicate_for_other_company())
+                        .with_locale(self.config.number_locale);
+                }
+                Task::none()
+            }
+            Message::DuplicateJob(id) => {
+                if let Some(db) = &self.database {
+                    let db = db.clone();
+                    let filter = self.status_filter.clone();
+                    return Task::perform(
+                        async move {
+                            match db.duplicate_job(id).await {
+                                Ok(_) => fetch_jobs(&db, &filter).await,
+                                Err(e) => Err(e.to_string()),
+                            }
+                        },
+                        Message::JobsLoaded,
+                    );
+                }
+                Task::none()
+            }
             Message::EditJob(id) => {
                 if let Some(job) = self.jobs.iter().find(|j| j.id == Some(id)) {
                     self.editing_job_id = Some(id);
-                    self.edit_form = EditForm::from_job(job);
+                    self.edit_form = EditForm::from_job(job).with_locale(self.config.number_locale);
                 }
                 Task::none()
             }
@@ -776,63 +2265,329 @@ impl JobTrackerApp {
                 match self.edit_form.to_job(if id == 0 { None } else { Some(id) }) {
                     Ok(job) => {
                         self.editing_job_id = None;
-                        self.error_message = None;
+                        self.discard_draft();
                         if let Some(db) = &self.database {
                             let db = db.clone();
+                            let filter = self.status_filter.clone();
                             let is_new_job = id == 0;
+                            let mut form = self.edit_form.clone();
                             return Task::perform(
                                 async move {
+                                    let mut warning = None;
                                     let result = if is_new_job {
+                                        // A failed lookup only loses the warning.
+                                        if let Ok(existing) =
+                                            db.get_jobs_by_company(&job.company).await
+                                        {
+                                            warning =
+                                                duplicate_company_warning(&existing, &job.company);
+                                        }
                                         db.insert_job(&job).await.map(|_| ())
                                     } else {
                                         db.update_job(&job).await
                                     };
                                     match result {
-                                        Ok(()) => {
-                                            db.get_all_jobs().await.map_err(|e| e.to_string())
+                                        Ok(()) => Message::JobSaved(
+                                            warning,
+                                            fetch_jobs(&db, &filter).await,
+                                        ),
+                                        Err(DbError::Conflict(id)) => {
+                                            match db.get_job_by_id(id).await {
+                                                Ok(current) => {
+                                                    form.updated_at = current.updated_at;
+                                                    Message::SaveConflict(id, Box::new(form))
+                                                }
+                                                Err(e) => Message::JobsLoaded(Err(e.to_string())),
+                                            }
                                         }
-                                        Err(e) => Err(e.to_string()),
+                                        Err(e) => Message::JobsLoaded(Err(e.to_string())),
                                     }
                                 },
-                                Message::JobsLoaded,
+                                std::convert::identity,
                             );
                         }
+                        self.toasts.push(
+                            "Database is not available; the job was not saved",
+                            Instant::now(),
+                        );
                     }
                     Err(e) => {
-                        self.error_message = Some(e);
+                        self.toasts.push(e, Instant::now());
                     }
                 }
                 Task::none()
             }
             Message::CancelEdit => {
                 self.editing_job_id = None;
+                self.discard_draft();
                 Task::none()
             }
-            Message::DeleteJob(id) => {
-                self.selected_job_id = None;
-                if let Some(db) = &self.database {
-                    let db = db.clone();
-                    return Task::perform(
-                        async move {
-                            match db.delete_job(id).await {
-                                Ok(()) => db.get_all_jobs().await.map_err(|e| e.to_string()),
-                                Err(e) => Err(e.to_string()),
-                            }
-                        },
-                        Message::JobsLoaded,
-                    );
+            Message::RemindersLoaded(result) => {
+                match result {
+                    Ok(reminders) => self.due_reminders = reminders,
+                    Err(e) => {
+                        self.toasts.push(e, Instant::now());
+                    }
                 }
                 Task::none()
             }
-            Message::ClearDatabase => {
-                if let Some(db) = &self.database {
+            Message::DismissReminder(id) => {
+                self.due_reminders
+                    .retain(|reminder| reminder.id != Some(id));
+                self.database.as_ref().map_or_else(Task::none, |db| {
                     let db = db.clone();
-                    return Task::perform(
-                        async move {
-                            match db.clear_all().await {
-                                Ok(()) => Ok(Vec::new()),
-                                Err(e) => Err(e.to_string()),
-                            }
+                    Task::perform(
+                        async move { db.delete_reminder(id).await.map_err(|e| e.to_string()) },
+                        Message::ReminderDismissed,
+                    )
+                })
+            }
+            Message::ReminderDismissed(result) | Message::ThemeSaved(result) => {
+                if let Err(e) = result {
+                    self.toasts.push(e, Instant::now());
+                }
+                Task::none()
+            }
+            Message::QuickAddChanged(value) => {
+                self.quick_add = value;
+                Task::none()
+            }
+            Message::QuickAddSubmitted => {
+                match parse_quick_add(&self.quick_add, self.config.number_locale) {
+                    Ok(job) => {
+                        let Some(db) = &self.database else {
+                            self.toasts.push(
+                                "Database is not available; the job was not saved",
+                                Instant::now(),
+                            );
+                            return Task::none();
+                        };
+                        self.quick_add.clear();
+                        let db = db.clone();
+                        let filter = self.status_filter.clone();
+                        Task::perform(
+                            async move {
+                                match db.insert_job(&job).await {
+                                    Ok(_) => fetch_jobs(&db, &filter).await,
+                                    Err(e) => Err(e.to_string()),
+                                }
+                            },
+                            Message::JobsLoaded,
+                        )
+                    }
+                    Err(e) => {
+                        self.toasts.push(e, Instant::now());
+                        Task::none()
+                    }
+                }
+            }
+            Message::ExpireToasts(now) => {
+                self.toasts.expire(now);
+                Task::none()
+            }
+            Message::DismissToast(id) => {
+                self.toasts.dismiss(id);
+                Task::none()
+            }
+            Message::JobSaved(warning, result) => {
+                self.warning_message = warning;
+                self.update(Message::JobsLoaded(result))
+            }
+            Message::DismissWarning => {
+                self.warning_message = None;
+                Task::none()
+            }
+            Message::SaveDraft => {
+                if self.draft_dirty
+                    && let Some(job_id) = self.editing_job_id
+                {
+                    let draft = Draft {
+                        job_id,
+                        form: self.edit_form.clone(),
+                    };
+                    if let Err(e) = draft.save(DRAFT_PATH) {
+                        eprintln!("Warning: Could not save draft: {e}");
+                    }
+                }
+                self.draft_dirty = false;
+                Task::none()
+            }
+            Message::RestoreDraft => {
+                if let Some(draft) = self.pending_draft.take() {
+                    self.editing_job_id = Some(draft.job_id);
+                    self.edit_form = draft.form.with_locale(self.config.number_locale);
+                }
+                Task::none()
+            }
+            Message::DiscardDraft => {
+                self.discard_draft();
+                Task::none()
+            }
+            Message::SaveConflict(id, form) => {
+                self.editing_job_id = Some(id);
+                self.edit_form = *form;
+                self.toasts.push(
+                    "This job was changed elsewhere since you opened it. Your edits are \
+                     still in the form; save again to overwrite the newer version.",
+                    Instant::now(),
+                );
+                self.load_jobs()
+            }
+            Message::SetPinned(id, pinned) => {
+                if let Some(db) = &self.database {
+                    let db = db.clone();
+                    let filter = self.status_filter.clone();
+                    return Task::perform(
+                        async move {
+                            match db.set_pinned(id, pinned).await {
+                                Ok(()) => fetch_jobs(&db, &filter).await,
+                                Err(e) => Err(e.to_string()),
+                            }
+                        },
+                        Message::JobsLoaded,
+                    );
+                }
+                Task::none()
+            }
+            Message::MarkAppliedToday(id) => {
+                if let Some(db) = &self.database {
+                    let db = db.clone();
+                    let filter = self.status_filter.clone();
+                    let today = time::OffsetDateTime::now_utc().date();
+                    return Task::perform(
+                        async move {
+                            match db.mark_applied(id, today).await {
+                                Ok(()) => fetch_jobs(&db, &filter).await,
+                                Err(e) => Err(e.to_string()),
+                            }
+                        },
+                        Message::JobsLoaded,
+                    );
+                }
+                Task::none()
+            }
+            Message::CycleStatus(id) => {
+                let next = self
+                    .jobs
+                    .iter()
+                    .find(|j| j.id == Some(id))
+                    .and_then(JobApplication::next_status);
+                if let (Some(db), Some(status)) = (&self.database, next) {
+                    let db = db.clone();
+                    let filter = self.status_filter.clone();
+                    return Task::perform(
+                        async move {
+                            match db.update_status(id, &status).await {
+                                Ok(()) => fetch_jobs(&db, &filter).await,
+                                Err(e) => Err(e.to_string()),
+                            }
+                        },
+                        Message::JobsLoaded,
+                    );
+                }
+                Task::none()
+            }
+            Message::DeleteJob(id) => {
+                self.selected_job_id = None;
+                if let Some(db) = &self.database {
+                    if let Some(job) = self.jobs.iter().find(|job| job.id == Some(id)) {
+                        self.undo_stack.push(job.clone());
+                    }
+                    let db = db.clone();
+                    let filter = self.status_filter.clone();
+                    return Task::perform(
+                        async move {
+                            match db.delete_job(id).await {
+                                Ok(()) => fetch_jobs(&db, &filter).await,
+                                Err(e) => Err(e.to_string()),
+                            }
+                        },
+                        Message::JobsLoaded,
+                    );
+                }
+                Task::none()
+            }
+            Message::UndoDelete => {
+                if let Some(db) = &self.database
+                    && let Some(job) = self.undo_stack.pop()
+                {
+                    let db = db.clone();
+                    let filter = self.status_filter.clone();
+                    // The job comes back under a new id; its reminders,
+                    // checklist and tags were deleted with it.
+                    return Task::perform(
+                        async move {
+                            match db.insert_job(&job).await {
+                                Ok(_) => fetch_jobs(&db, &filter).await,
+                                Err(e) => Err(e.to_string()),
+                            }
+                        },
+                        Message::JobsLoaded,
+                    );
+                }
+                Task::none()
+            }
+            Message::ExportCsv => {
+                if let Some(db) = &self.database {
+                    let db = db.clone();
+                    return Task::perform(
+                        async move {
+                            db.export_csv(Path::new(CSV_EXPORT_PATH))
+                                .await
+                                .map_err(|e| e.to_string())
+                        },
+                        Message::CsvExported,
+                    );
+                }
+                Task::none()
+            }
+            Message::CsvExported(result) => {
+                let message = match result {
+                    Ok(()) => format!("Exported jobs to {CSV_EXPORT_PATH}"),
+                    Err(e) => e,
+                };
+                self.toasts.push(message, Instant::now());
+                Task::none()
+            }
+            Message::ClearDatabase => {
+                if let Some(db) = &self.database {
+                    let db = db.clone();
+                    return Task::perform(
+                        async move { count_rows_to_clear(&db).await },
+                        Message::ClearDatabaseCounted,
+                    );
+                }
+                Task::none()
+            }
+            Message::ClearDatabaseCounted(result) => match result {
+                Ok(count) => {
+                    let count = usize::try_from(count).unwrap_or(usize::MAX);
+                    if self.config.requires_delete_confirmation(count) {
+                        self.pending_bulk_delete = Some(count);
+                        Task::none()
+                    } else {
+                        Task::done(Message::ConfirmClearDatabase)
+                    }
+                }
+                Err(e) => {
+                    self.toasts.push(e, Instant::now());
+                    Task::none()
+                }
+            },
+            Message::CancelClearDatabase => {
+                self.pending_bulk_delete = None;
+                Task::none()
+            }
+            Message::ConfirmClearDatabase => {
+                self.pending_bulk_delete = None;
+                if let Some(db) = &self.database {
+                    let db = db.clone();
+                    return Task::perform(
+                        async move {
+                            match db.clear_all().await {
+                                Ok(()) => Ok(Vec::new()),
+                                Err(e) => Err(e.to_string()),
+                            }
                         },
                         Message::JobsLoaded,
                     );
@@ -844,25 +2599,161 @@ impl JobTrackerApp {
                     Ok(()) => {
                         self.jobs.clear();
                         self.selected_job_id = None;
-                        self.error_message = None;
                     }
                     Err(e) => {
-                        self.error_message = Some(e);
+                        self.toasts.push(e, Instant::now());
                     }
                 }
                 Task::none()
             }
+            Message::OpenCommandPalette => {
+                self.command_palette = Some(String::new());
+                text_input::focus(text_input::Id::new(COMMAND_PALETTE_ID))
+            }
+            Message::CloseCommandPalette => {
+                self.command_palette = None;
+                Task::none()
+            }
+            Message::KeyPressed(key, modifiers) => {
+                let escape = key == keyboard::Key::Named(keyboard::key::Named::Escape);
+                let message = if escape && self.command_palette.is_some() {
+                    Some(Message::CloseCommandPalette)
+                } else {
+                    key_to_message(modifiers, key.as_ref(), self.editing_job_id)
+                };
+                message.map_or_else(Task::none, |message| self.update(message))
+            }
+            Message::CommandPaletteChanged(value) => {
+                self.command_palette = Some(value);
+                Task::none()
+            }
+            Message::CommandPaletteSubmitted => {
+                let best = self
+                    .command_palette
+                    .as_deref()
+                    .and_then(|query| rank_commands(query).first().copied());
+                best.map_or_else(Task::none, |index| Task::done(Message::RunCommand(index)))
+            }
+            Message::RunCommand(index) => {
+                self.command_palette = None;
+                COMMANDS
+                    .get(index)
+                    .map_or_else(Task::none, |(_, build)| Task::done(build()))
+            }
             Message::ToggleTheme => {
                 self.theme = match self.theme {
                     AppTheme::Light => AppTheme::Dark,
                     AppTheme::Dark => AppTheme::Light,
                 };
+                self.database.as_ref().map_or_else(Task::none, |db| {
+                    let db = db.clone();
+                    let value = self.theme.setting_value();
+                    Task::perform(
+                        async move {
+                            db.set_setting(THEME_SETTING_KEY, value)
+                                .await
+                                .map_err(|e| e.to_string())
+                        },
+                        Message::ThemeSaved,
+                    )
+                })
+            }
+            Message::ThemeLoaded(result) => {
+                match result {
+                    Ok(Some(theme)) => self.theme = theme,
+                    Ok(None) => {}
+                    Err(e) => {
+                        self.toasts.push(e, Instant::now());
+                    }
+                }
+                Task::none()
+            }
+            Message::AccentSelected(accent) => {
+                self.config.accent = accent;
                 Task::none()
             }
             Message::SelectJob(id) => {
                 self.selected_job_id = id;
+                self.checklist.clear();
+                self.checklist_input.clear();
+                self.load_checklist()
+            }
+            Message::ChecklistLoaded(job_id, result) => {
+                match result {
+                    // Ignore results for a job that is no longer selected.
+                    Ok(items) if self.selected_job_id == Some(job_id) => self.checklist = items,
+                    Ok(_) => {}
+                    Err(e) => {
+                        self.toasts.push(e, Instant::now());
+                    }
+                }
+                Task::none()
+            }
+            Message::ChecklistInputChanged(value) => {
+                self.checklist_input = value;
+                Task::none()
+            }
+            Message::AddChecklistItem => {
+                let text = self.checklist_input.trim().to_string();
+                let Some(job_id) = self.selected_job_id.filter(|_| !text.is_empty()) else {
+                    return Task::none();
+                };
+                self.checklist_input.clear();
+                self.update_checklist(move |db| async move {
+                    db.add_checklist_item(job_id, &text).await.map(|_| ())
+                })
+            }
+            Message::ToggleChecklistItem(id, done) => {
+                if let Some(item) = self.checklist.iter_mut().find(|item| item.id == Some(id)) {
+                    item.done = done;
+                }
+                self.update_checklist(move |db| async move {
+                    db.set_checklist_item_done(id, done).await
+                })
+            }
+            Message::DeleteChecklistItem(id) => {
+                self.checklist.retain(|item| item.id != Some(id));
+                self.update_checklist(move |db| async move { db.delete_checklist_item(id).await })
+            }
+            Message::SortColumn(by) => {
+                if self.sort_by == by {
+                    self.sort_order = match self.sort_order {
+                        SortOrder::Asc => SortOrder::Desc,
+                        SortOrder::Desc => SortOrder::Asc,
+                    };
+                } else {
+                    self.sort_by = by;
+                    self.sort_order = SortOrder::Asc;
+                }
+                sort_jobs(&mut self.jobs, self.sort_by, self.sort_order);
+                Task::none()
+            }
+            Message::SearchChanged(query) => {
+                self.search_query = query;
+                Task::none()
+            }
+            Message::ToggleStatusFilter(status) => {
+                self.status_filter.toggle(status);
+                self.load_jobs()
+            }
+            Message::WindowMoved(position) => {
+                self.config.window.position = Some((position.x, position.y));
+                Task::none()
+            }
+            Message::WindowResized(size) => {
+                // Minimizing reports a zero size, which is not worth restoring.
+                if size.width > 0.0 && size.height > 0.0 {
+                    self.config.window.width = size.width;
+                    self.config.window.height = size.height;
+                }
                 Task::none()
             }
+            Message::WindowCloseRequested(id) => {
+                if let Err(e) = self.config.save(CONFIG_PATH) {
+                    eprintln!("Warning: Could not save config: {e}");
+                }
+                window::close(id)
+            }
             Message::CompanyChanged(value) => {
                 self.edit_form.company = value;
                 Task::none()
@@ -887,6 +2778,18 @@ impl JobTrackerApp {
                 self.edit_form.salary_max = value;
                 Task::none()
             }
+            Message::SalaryCurrencyChanged(value) => {
+                self.edit_form.salary_currency = value;
+                Task::none()
+            }
+            Message::SalaryMinSlid(value) => {
+                self.edit_form.slide_salary_min(value);
+                Task::none()
+            }
+            Message::SalaryMaxSlid(value) => {
+                self.edit_form.slide_salary_max(value);
+                Task::none()
+            }
             Message::StatusChanged(status) => {
                 self.edit_form.status = status;
                 Task::none()
@@ -899,22 +2802,70 @@ impl JobTrackerApp {
                 self.edit_form.interview_round = value;
                 Task::none()
             }
+            Message::InterviewAtChanged(value) => {
+                self.edit_form.interview_at = value;
+                Task::none()
+            }
             Message::OfferAmountChanged(value) => {
                 self.edit_form.offer_amount = value;
                 Task::none()
             }
+            Message::OfferDeadlineChanged(value) => {
+                self.edit_form.offer_deadline = value;
+                Task::none()
+            }
+            Message::NotesChanged(value) => {
+                self.edit_form.notes = value;
+                Task::none()
+            }
+            Message::ContactNameChanged(value) => {
+                self.edit_form.contact_name = value;
+                Task::none()
+            }
+            Message::ContactEmailChanged(value) => {
+                self.edit_form.contact_email = value;
+                Task::none()
+            }
+            Message::UrlChanged(value) => {
+                self.edit_form.url = value;
+                Task::none()
+            }
+            Message::FollowUpChanged(value) => {
+                self.edit_form.follow_up = value;
+                Task::none()
+            }
+            Message::CompanyCanonicalChanged(value) => {
+                self.edit_form.company_canonical = value;
+                Task::none()
+            }
+            Message::RejectionReasonChanged(value) => {
+                self.edit_form.rejection_reason = value;
+                Task::none()
+            }
         }
     }
 
     fn view(&self) -> Element<'_, Message> {
         let toolbar = row![
             button(text("Add Job")).on_press(Message::AddNewJob),
+            text_input(
+                "Quick add: Company | Position | Location | 80k-120k",
+                &self.quick_add
+            )
+            .on_input(Message::QuickAddChanged)
+            .on_submit(Message::QuickAddSubmitted)
+            .width(Length::Fixed(360.0)),
+            button(text("Undo"))
+                .on_press_maybe((!self.undo_stack.is_empty()).then_some(Message::UndoDelete)),
             button(text("Clear Database")).on_press(Message::ClearDatabase),
             button(text(match self.theme {
                 AppTheme::Light => "Dark Mode",
                 AppTheme::Dark => "Light Mode",
             }))
             .on_press(Message::ToggleTheme),
+            self.view_accent_swatches(),
+            Space::with_width(Length::Fixed(20.0)),
+            self.view_status_chips(),
         ]
         .spacing(10);
 
@@ -923,63 +2874,571 @@ impl JobTrackerApp {
             self.view_cv_panel()
         ];
 
-        let mut content = column![toolbar, main_content].spacing(20);
+        let mut content = column![toolbar].spacing(20);
 
-        if let Some(error) = &self.error_message {
+        if let Some(error) = &self.init_error {
             let theme = self.theme;
             content = content.push(
                 container(
-                    text(format!("Error: {error}")).style(move |_theme_ref| match theme {
-                        AppTheme::Light => iced::widget::text::Style {
-                            color: Some(iced::Color::from_rgb(0.8, 0.0, 0.0)),
-                        },
-                        AppTheme::Dark => iced::widget::text::Style {
-                            color: Some(iced::Color::from_rgb(1.0, 0.4, 0.4)),
-                        },
-                    }),
+                    row![
+                        text(format!("Could not open the database: {error}")).style(
+                            move |_theme_ref| match theme {
+                                AppTheme::Light => iced::widget::text::Style {
+                                    color: Some(iced::Color::from_rgb(0.8, 0.0, 0.0)),
+                                },
+                                AppTheme::Dark => iced::widget::text::Style {
+                                    color: Some(iced::Color::from_rgb(1.0, 0.4, 0.4)),
+                                },
+                            }
+                        ),
+                        button(text("Retry")).on_press(Message::RetryDatabaseInit),
+                    ]
+                    .spacing(10)
+                    .align_y(iced::Alignment::Center),
                 )
-                .style(move |_theme| container::Style {
-                    background: Some(iced::Background::Color(match theme {
-                        AppTheme::Light => iced::Color::from_rgb(1.0, 0.9, 0.9),
-                        AppTheme::Dark => iced::Color::from_rgb(0.3, 0.1, 0.1),
-                    })),
-                    border: iced::Border {
-                        width: 1.0,
-                        color: match theme {
-                            AppTheme::Light => iced::Color::from_rgb(1.0, 0.5, 0.5),
-                            AppTheme::Dark => iced::Color::from_rgb(0.8, 0.3, 0.3),
-                        },
-                        radius: 5.0.into(),
-                    },
-                    ..Default::default()
-                })
                 .padding(10),
             );
         }
 
-        container(content)
-            .padding(20)
-            .width(Length::Fill)
-            .height(Length::Fill)
-            .into()
+        if let Some(warning) = &self.warning_message {
+            content = content.push(Self::view_warning(warning));
+        }
+
+        if !self.due_reminders.is_empty() {
+            content = content.push(self.view_reminders());
+        }
+
+        if let Some(count) = self.pending_bulk_delete {
+            content = content.push(
+                container(
+                    row![
+                        text(delete_prompt(count)),
+                        button(text("Delete"))
+                            .style(button::danger)
+                            .on_press(Message::ConfirmClearDatabase),
+                        button(text("Cancel"))
+                            .style(button::secondary)
+                            .on_press(Message::CancelClearDatabase),
+                    ]
+                    .spacing(10)
+                    .align_y(iced::Alignment::Center),
+                )
+                .padding(10),
+            );
+        }
+
+        if self.pending_draft.is_some() {
+            content = content.push(
+                container(
+                    row![
+                        text("Restore unsaved draft?"),
+                        button(text("Restore")).on_press(Message::RestoreDraft),
+                        button(text("Discard"))
+                            .style(button::secondary)
+                            .on_press(Message::DiscardDraft),
+                    ]
+                    .spacing(10)
+                    .align_y(iced::Alignment::Center),
+                )
+                .padding(10),
+            );
+        }
+
+        content = content.push(main_content);
+
+        stack![
+            container(content)
+                .padding(20)
+                .width(Length::Fill)
+                .height(Length::Fill),
+            self.view_command_palette(),
+            self.view_toasts(),
+        ]
+        .into()
     }
 
-    const fn theme(&self) -> Theme {
-        self.theme.to_iced_theme()
+    fn theme(&self) -> Theme {
+        self.theme.with_accent(self.config.accent)
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        let mut subscriptions = vec![event::listen_with(Self::handle_event)];
+        if self.draft_dirty {
+            subscriptions.push(iced::time::every(DRAFT_DEBOUNCE).map(|_| Message::SaveDraft));
+        }
+        if !self.toasts.is_empty() {
+            subscriptions
+                .push(iced::time::every(Duration::from_millis(500)).map(Message::ExpireToasts));
+        }
+        Subscription::batch(subscriptions)
     }
+
+    /// Drops any pending or saved draft, e.g. after the form is saved.
+    fn discard_draft(&mut self) {
+        self.pending_draft = None;
+        self.draft_dirty = false;
+        if let Err(e) = Draft::clear(DRAFT_PATH) {
+            eprintln!("Warning: Could not remove draft: {e}");
+        }
+    }
+
+    #[allow(clippy::needless_pass_by_value)]
+    fn handle_event(event: iced::Event, _status: event::Status, id: window::Id) -> Option<Message> {
+        match event {
+            iced::Event::Window(window::Event::Moved(position)) => {
+                Some(Message::WindowMoved(position))
+            }
+            iced::Event::Window(window::Event::Resized(size)) => Some(Message::WindowResized(size)),
+            iced::Event::Window(window::Event::CloseRequested) => {
+                Some(Message::WindowCloseRequested(id))
+            }
+            iced::Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) => {
+                match key.as_ref() {
+                    keyboard::Key::Character("k") if modifiers.command() => {
+                        Some(Message::OpenCommandPalette)
+                    }
+                    _ => Some(Message::KeyPressed(key, modifiers)),
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Scores how well `query` fuzzy-matches `label`, or `None` if it does not.
+///
+/// Every character of the query must appear in the label in order, ignoring
+/// case and whitespace in the query. Higher scores are better: matches at the
+/// start of a word and runs of consecutive characters are rewarded, and
+/// skipped label characters cost a point each. An empty query matches every
+/// label with a score of zero.
+///
+/// # Examples
+///
+/// ```
+/// # use job_tracker::ui::fuzzy_score;
+/// assert!(fuzzy_score("tt", "Toggle theme").is_some());
+/// assert!(fuzzy_score("ajd", "Add job").is_none());
+/// assert!(fuzzy_score("cl", "Clear database") > fuzzy_score("cl", "Cancel edit"));
+/// ```
+#[must_use]
+pub fn fuzzy_score(query: &str, label: &str) -> Option<i32> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    let label: Vec<char> = label
+        .chars()
+        .map(|c| c.to_lowercase().next().unwrap_or(c))
+        .collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let gap = |from: usize, to: usize| i32::try_from(to - from).unwrap_or(i32::MAX);
+    let char_score = |index: usize| {
+        let at_word_start = index == 0 || !label[index - 1].is_alphanumeric();
+        if at_word_start { 9 } else { 1 }
+    };
+
+    // best[j] is the best score with the current query character matched at
+    // label[j]; every alignment is considered so later word starts can win.
+    let mut best: Vec<Option<i32>> = (0..label.len())
+        .map(|j| (label[j] == query[0]).then(|| char_score(j) - gap(0, j)))
+        .collect();
+    for &wanted in &query[1..] {
+        best = (0..label.len())
+            .map(|j| {
+                if label[j] != wanted {
+                    return None;
+                }
+                (0..j)
+                    .filter_map(|k| {
+                        best[k].map(|score| {
+                            let bonus = if k + 1 == j { 4 } else { 0 };
+                            score + bonus - gap(k + 1, j)
+                        })
+                    })
+                    .max()
+                    .map(|score| score + char_score(j))
+            })
+            .collect();
+    }
+
+    best.into_iter().flatten().max()
+}
+
+/// Returns the indices into `COMMANDS` that match `query`, best first.
+///
+/// Commands with equal scores keep their order in `COMMANDS`.
+///
+/// # Examples
+///
+/// ```
+/// # use job_tracker::ui::{COMMANDS, rank_commands};
+/// let best = rank_commands("theme")[0];
+/// assert_eq!(COMMANDS[best].0, "Toggle theme");
+/// ```
+#[must_use]
+pub fn rank_commands(query: &str) -> Vec<usize> {
+    let mut ranked: Vec<(usize, i32)> = COMMANDS
+        .iter()
+        .enumerate()
+        .filter_map(|(index, (label, _))| fuzzy_score(query, label).map(|score| (index, score)))
+        .collect();
+    ranked.sort_by_key(|&(index, score)| (std::cmp::Reverse(score), index));
+    ranked.into_iter().map(|(index, _)| index).collect()
+}
+
+/// Parses a quick-add line of the form `Company | Position | Location | Salary`.
+///
+/// Only the company is required; trailing fields may be omitted and default
+/// like `JobApplication::new`. The salary is either a single amount or a
+/// `min-max` range, and amounts accept a `k` suffix for thousands.
+///
+/// # Errors
+///
+/// Returns a message if the company is empty, there are more than four
+/// fields, or the salary cannot be parsed or has its minimum above its
+/// maximum.
+///
+/// # Examples
+///
+/// ```
+/// # use job_tracker::ui::parse_quick_add;
+/// # use job_tracker::model::SalaryRange;
+/// # use job_tracker::config::NumberLocale;
+/// let job = parse_quick_add("TechCorp | Engineer | Remote | 80k-120k", NumberLocale::EnUs).unwrap();
+/// assert_eq!(job.company, "TechCorp");
+/// assert_eq!(job.salary, SalaryRange::new(80_000, 120_000));
+/// ```
+pub fn parse_quick_add(line: &str, locale: NumberLocale) -> Result<JobApplication, String> {
+    let fields: Vec<&str> = line.split('|').map(str::trim).collect();
+    if fields.len() > 4 {
+        return Err(format!(
+            "Quick add takes at most 4 fields separated by '|', got {}",
+            fields.len()
+        ));
+    }
+
+    let company = fields[0];
+    if company.is_empty() {
+        return Err("Quick add needs a company name".to_string());
+    }
+
+    let mut job = JobApplication::new().company(company);
+    if let Some(position) = fields.get(1) {
+        job = job.position(position);
+    }
+    if let Some(location) = fields.get(2) {
+        job = job.location(location);
+    }
+    if let Some(salary) = fields.get(3).filter(|salary| !salary.is_empty()) {
+        let (min, max) = if let Some((min, max)) = salary.split_once('-') {
+            (
+                parse_quick_amount(min, locale)?,
+                parse_quick_amount(max, locale)?,
+            )
+        } else {
+            let amount = parse_quick_amount(salary, locale)?;
+            (amount, amount)
+        };
+        if min > max {
+            return Err(format!("Salary minimum exceeds maximum: {salary}"));
+        }
+        job = job.salary(SalaryRange::new(min, max));
+    }
+
+    Ok(job)
+}
+
+/// Parses a salary amount such as `80000`, `80,000` (in `en-US`) or `80k`.
+fn parse_quick_amount(amount: &str, locale: NumberLocale) -> Result<u32, String> {
+    let amount = amount.trim();
+    let invalid = || format!("Invalid salary amount: {amount}");
+    amount
+        .strip_suffix(['k', 'K'])
+        .map_or_else(
+            || locale.parse_integer(amount).ok(),
+            |thousands| {
+                locale
+                    .parse_integer::<u32>(thousands)
+                    .ok()
+                    .and_then(|value| value.checked_mul(1_000))
+            },
+        )
+        .ok_or_else(invalid)
+}
+
+/// Escapes backslashes and newlines so `value` fits on one draft line.
+fn escape_draft_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+/// Reverses `escape_draft_value`.
+fn unescape_draft_value(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                unescaped.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                unescaped.push('\\');
+                chars.next();
+            }
+            _ => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+/// Confirmation prompt for a bulk delete of `count` jobs.
+///
+/// # Examples
+///
+/// ```
+/// # use job_tracker::ui::delete_prompt;
+/// assert_eq!(delete_prompt(12), "Delete 12 jobs?");
+/// assert_eq!(delete_prompt(1), "Delete 1 job?");
+/// ```
+#[must_use]
+pub fn delete_prompt(count: usize) -> String {
+    if count == 1 {
+        "Delete 1 job?".to_string()
+    } else {
+        format!("Delete {count} jobs?")
+    }
+}
+
+/// Sorts `jobs` by the column `by` in the direction `order`.
+///
+/// Mirrors `Database::get_jobs_sorted`: pinned jobs stay on top, text columns
+/// ignore ASCII case, jobs without a date sort as the smallest value, and ties
+/// fall back to the most recently added first.
+///
+/// # Examples
+///
+/// ```
+/// # use job_tracker::db::{SortBy, SortOrder};
+/// # use job_tracker::model::JobApplication;
+/// # use job_tracker::ui::sort_jobs;
+/// let mut jobs = vec![
+///     JobApplication::new().company("beta"),
+///     JobApplication::new().company("Alpha"),
+/// ];
+/// sort_jobs(&mut jobs, SortBy::Company, SortOrder::Asc);
+/// assert_eq!(jobs[0].company, "Alpha");
+/// ```
+pub fn sort_jobs(jobs: &mut [JobApplication], by: SortBy, order: SortOrder) {
+    fn cmp_ignore_ascii_case(a: &str, b: &str) -> std::cmp::Ordering {
+        a.bytes()
+            .map(|byte| byte.to_ascii_lowercase())
+            .cmp(b.bytes().map(|byte| byte.to_ascii_lowercase()))
+    }
+
+    jobs.sort_by(|a, b| {
+        let column = match by {
+            SortBy::CreatedAt => (a.created_at, a.id).cmp(&(b.created_at, b.id)),
+            SortBy::Company => cmp_ignore_ascii_case(&a.company, &b.company),
+            SortBy::Position => cmp_ignore_ascii_case(&a.position, &b.position),
+            SortBy::SalaryMax => a.salary.max.cmp(&b.salary.max),
+            SortBy::Date => a.date.cmp(&b.date),
+        };
+        let column = match order {
+            SortOrder::Asc => column,
+            SortOrder::Desc => column.reverse(),
+        };
+        b.pinned
+            .cmp(&a.pinned)
+            .then(column)
+            .then_with(|| (b.created_at, b.id).cmp(&(a.created_at, a.id)))
+    });
+}
+
+/// Returns whether `job` should be shown for the search box text `q`.
+///
+/// Matches when the company, position or location contains `q`, ignoring
+/// case and surrounding whitespace. An empty query matches every job.
+///
+/// # Examples
+///
+/// ```
+/// # use job_tracker::model::JobApplication;
+/// # use job_tracker::ui::matches_query;
+/// let job = JobApplication::new().company("TechCorp").location("Berlin");
+/// assert!(matches_query(&job, "techcorp"));
+/// assert!(matches_query(&job, "BERL"));
+/// assert!(!matches_query(&job, "Munich"));
+/// ```
+#[must_use]
+pub fn matches_query(job: &JobApplication, q: &str) -> bool {
+    let q = q.trim().to_lowercase();
+    q.is_empty()
+        || [&job.company, &job.position, &job.location]
+            .iter()
+            .any(|field| field.to_lowercase().contains(&q))
+}
+
+/// Warning shown when a new job is saved for a company already in `existing`.
+///
+/// Companies match case-insensitively and ignoring surrounding whitespace.
+/// Returns `None` when no existing job is at `company`.
+///
+/// # Examples
+///
+/// ```
+/// # use job_tracker::model::JobApplication;
+/// # use job_tracker::ui::duplicate_company_warning;
+/// let existing = vec![JobApplication::new().company("Acme")];
+/// assert_eq!(
+///     duplicate_company_warning(&existing, "ACME").as_deref(),
+///     Some("You already track 1 application at ACME")
+/// );
+/// assert_eq!(duplicate_company_warning(&existing, "Beta"), None);
+/// ```
+#[must_use]
+pub fn duplicate_company_warning(existing: &[JobApplication], company: &str) -> Option<String> {
+    let company = company.trim();
+    let count = existing
+        .iter()
+        .filter(|job| job.company.trim().to_lowercase() == company.to_lowercase())
+        .count();
+    match count {
+        0 => None,
+        1 => Some(format!("You already track 1 application at {company}")),
+        _ => Some(format!(
+            "You already track {count} applications at {company}"
+        )),
+    }
+}
+
+/// Maps a key press to its edit-form shortcut, if any.
+///
+/// `editing` is the id of the job whose form is open (0 for a new job), or
+/// `None` when no form is open. Ctrl+N (Cmd+N on macOS) adds a job while no
+/// form is open; Ctrl+S saves and Escape cancels the open form.
+///
+/// # Examples
+///
+/// ```
+/// # use iced::keyboard::{Key, Modifiers};
+/// # use job_tracker::ui::{Message, key_to_message};
+/// let save = key_to_message(Modifiers::CTRL, Key::Character("s"), Some(3));
+/// assert!(matches!(save, Some(Message::SaveJob(3))));
+/// assert!(key_to_message(Modifiers::CTRL, Key::Character("s"), None).is_none());
+/// ```
+#[must_use]
+pub fn key_to_message(
+    modifiers: keyboard::Modifiers,
+    key: keyboard::Key<&str>,
+    editing: Option<i64>,
+) -> Option<Message> {
+    match (key, editing) {
+        (keyboard::Key::Character("n"), None) if modifiers.command() => Some(Message::AddNewJob),
+        (keyboard::Key::Character("s"), Some(id)) if modifiers.command() => {
+            Some(Message::SaveJob(id))
+        }
+        (keyboard::Key::Named(keyboard::key::Named::Escape), Some(_)) => Some(Message::CancelEdit),
+        _ => None,
+    }
+}
+
+/// A small dot that is green for complete jobs and amber for jobs missing
+/// important fields.
+fn completeness_dot(complete: bool) -> Element<'static, Message> {
+    let color = if complete {
+        iced::Color::from_rgb(0.2, 0.7, 0.3)
+    } else {
+        iced::Color::from_rgb(0.95, 0.6, 0.1)
+    };
+    text("\u{25CF}")
+        .style(move |_| iced::widget::text::Style { color: Some(color) })
+        .into()
+}
+
+/// Formats a salary range with the locale's thousands separators, or a
+/// single amount when the range is one target salary, followed by its
+/// currency.
+/// Formats a status for the table, e.g. "Interview (2)" or "Offer (90,000)".
+fn status_label(status: &Status, locale: NumberLocale) -> String {
+    match status {
+        Status::Applied => "Applied".to_string(),
+        Status::Interview(round) => format!("Interview ({round})"),
+        Status::Offer(amount) => format!("Offer ({})", locale.format_integer(i64::from(*amount))),
+        Status::Accepted => "Accepted".to_string(),
+        Status::Rejected => "Rejected".to_string(),
+        Status::Withdrawn => "Withdrawn".to_string(),
+    }
+}
+
+/// Parses an optional `YYYY-MM-DD` form field, where blank means `None`.
+fn parse_form_date(value: &str, error: &str) -> Result<Option<Date>, String> {
+    Some(value.trim())
+        .filter(|value| !value.is_empty())
+        .map(|value| {
+            Date::parse(value, &time::format_description::well_known::Iso8601::DATE)
+                .map_err(|_| error.to_string())
+        })
+        .transpose()
+}
+
+fn format_salary(salary: &SalaryRange, locale: NumberLocale) -> String {
+    format!(
+        "{} {}",
+        salary.format_with(|amount| locale.format_integer(i64::from(amount))),
+        salary.currency
+    )
+}
+
+fn accent_to_color(accent: AccentColor) -> iced::Color {
+    iced::Color::from_rgb8(accent.r, accent.g, accent.b)
+}
+
+/// Fetches the jobs matching `filter`, or every job when the filter is empty.
+async fn fetch_jobs(db: &Database, filter: &StatusFilter) -> Result<Vec<JobApplication>, String> {
+    let result = if filter.is_empty() {
+        db.get_all_jobs_cached().await
+    } else {
+        db.get_jobs_by_statuses(filter.active()).await
+    };
+    result.map_err(|e| e.to_string())
+}
+
+/// Counts the rows `Database::clear_all` would delete, archived jobs included.
+async fn count_rows_to_clear(db: &Database) -> Result<i64, String> {
+    db.count_jobs_including_archived()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Places the window at its saved position, clamped to the monitor it opens on.
+fn restore_window_position(window_size: Size, monitor_size: Size) -> Point {
+    let saved = SAVED_WINDOW_POSITION.get().copied().unwrap_or_default();
+    let (x, y) = WindowGeometry::clamp_position(
+        saved,
+        (window_size.width, window_size.height),
+        (monitor_size.width, monitor_size.height),
+    );
+    Point::new(x, y)
 }
 
 /// Runs the job tracker application.
 ///
 /// Initializes and starts the Iced application with the job tracker UI.
+/// The window size and position are restored from the config file and saved
+/// again when the window is closed.
 /// This function blocks until the application is closed by the user.
 ///
 /// # Errors
 ///
-/// This function will return an error if:
+/// Returns `AppError::UserInterface` if:
 /// - The windowing system cannot be initialized
 /// - The graphics context cannot be created
-/// - The application framework encounters a fatal error
+/// - The application framework encounters a fatal error or panics
 ///
 /// # Examples
 ///
@@ -987,15 +3446,179 @@ impl JobTrackerApp {
 /// # use job_tracker::ui;
 /// ui::run().unwrap();
 /// ```
-pub fn run() -> iced::Result {
-    iced::application("Job Tracker", JobTrackerApp::update, JobTrackerApp::view)
-        .theme(JobTrackerApp::theme)
-        .run_with(JobTrackerApp::init)
+pub fn run() -> AppResult<()> {
+    let config = Config::load(CONFIG_PATH).unwrap_or_else(|e| {
+        eprintln!("Warning: Could not load config: {e}");
+        Config::default()
+    });
+
+    let position = config
+        .window
+        .position
+        .map_or(window::Position::Default, |saved| {
+            let _ = SAVED_WINDOW_POSITION.set(saved);
+            window::Position::SpecificWith(restore_window_position)
+        });
+
+    check_display(|name| std::env::var_os(name))?;
+    run_guarded(move || {
+        iced::application("Job Tracker", JobTrackerApp::update, JobTrackerApp::view)
+            .theme(JobTrackerApp::theme)
+            .subscription(JobTrackerApp::subscription)
+            .window_size(Size::new(config.window.width, config.window.height))
+            .position(position)
+            .exit_on_close_request(false)
+            .run_with(move || JobTrackerApp::init(config))
+    })
+}
+
+/// Advice appended to errors raised while iced sets up the window.
+const NO_DISPLAY_HINT: &str = "No usable display or graphics driver was found. Run the job \
+    tracker from a desktop session (check that DISPLAY or WAYLAND_DISPLAY is set), or try \
+    software rendering with ICED_BACKEND=tiny-skia.";
+
+/// Environment variables that tell winit where the display server is.
+const DISPLAY_VARIABLES: [&str; 3] = ["WAYLAND_DISPLAY", "WAYLAND_SOCKET", "DISPLAY"];
+
+/// Fails early when no display server is configured, instead of letting
+/// iced panic while creating its event loop.
+///
+/// Only X11 and Wayland platforms need a display variable; elsewhere this
+/// always succeeds.
+///
+/// # Arguments
+///
+/// * `lookup` - Reads an environment variable, e.g. `std::env::var_os`
+///
+/// # Errors
+///
+/// Returns `AppError::UserInterface` if none of `DISPLAY_VARIABLES` is set
+/// on a platform that needs one.
+pub fn check_display(lookup: impl Fn(&str) -> Option<std::ffi::OsString>) -> AppResult<()> {
+    let needs_display = cfg!(all(
+        unix,
+        not(any(
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "android"
+        ))
+    ));
+    if !needs_display || DISPLAY_VARIABLES.iter().any(|name| lookup(name).is_some()) {
+        return Ok(());
+    }
+    Err(AppError::UserInterface(format!(
+        "cannot open a window: none of {} is set. {NO_DISPLAY_HINT}",
+        DISPLAY_VARIABLES.join(", ")
+    )))
+}
+
+/// Runs `launch`, turning iced's errors and panics into `AppError::UserInterface`.
+///
+/// iced panics instead of returning an error for some windowing failures,
+/// such as a missing display on a headless machine, so the panic is caught
+/// and reported like any other startup error.
+///
+/// # Errors
+///
+/// Returns `AppError::UserInterface` if `launch` returns an error or panics.
+pub fn run_guarded(launch: impl FnOnce() -> iced::Result) -> AppResult<()> {
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(launch)) {
+        Ok(result) => result.map_err(map_iced_error),
+        Err(payload) => {
+            let reason = payload
+                .downcast_ref::<&str>()
+                .map(|s| (*s).to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            Err(AppError::UserInterface(format!(
+                "the user interface stopped unexpectedly: {reason}. {NO_DISPLAY_HINT}"
+            )))
+        }
+    }
+}
+
+/// Converts an iced startup error into `AppError::UserInterface`.
+///
+/// # Examples
+///
+/// ```
+/// # use job_tracker::error::AppError;
+/// # use job_tracker::ui::map_iced_error;
+/// let io = std::io::Error::other("no display");
+/// let error = map_iced_error(iced::Error::WindowCreationFailed(Box::new(io)));
+/// assert!(matches!(error, AppError::UserInterface(msg) if msg.contains("no display")));
+/// ```
+#[must_use]
+pub fn map_iced_error(error: iced::Error) -> AppError {
+    let summary = error.to_string();
+    let (detail, hint) = match error {
+        iced::Error::ExecutorCreationFailed(source) => (source.to_string(), ""),
+        iced::Error::WindowCreationFailed(source) => (source.to_string(), NO_DISPLAY_HINT),
+        iced::Error::GraphicsCreationFailed(source) => (source.to_string(), NO_DISPLAY_HINT),
+    };
+    AppError::UserInterface(
+        format!("{summary} ({detail}). {hint}")
+            .trim_end()
+            .to_string(),
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn test_map_iced_error() {
+        let error = map_iced_error(iced::Error::WindowCreationFailed(Box::new(
+            std::io::Error::other("no compositor"),
+        )));
+        let AppError::UserInterface(msg) = error else {
+            panic!("expected a user interface error");
+        };
+        assert!(msg.contains("window could not be created"));
+        assert!(msg.contains("no compositor"));
+        assert!(msg.contains("WAYLAND_DISPLAY"));
+
+        let error = map_iced_error(iced::Error::ExecutorCreationFailed(std::io::Error::other(
+            "out of threads",
+        )));
+        assert!(
+            matches!(error, AppError::UserInterface(ref msg) if msg.contains("out of threads") && !msg.contains("DISPLAY"))
+        );
+    }
+
+    #[test]
+    fn test_check_display() {
+        assert!(check_display(|name| (name == "DISPLAY").then(|| ":0".into())).is_ok());
+        assert!(
+            check_display(|name| (name == "WAYLAND_DISPLAY").then(|| "wayland-0".into())).is_ok()
+        );
+
+        let headless = check_display(|_| None);
+        if cfg!(target_os = "linux") {
+            assert!(
+                matches!(headless, Err(AppError::UserInterface(ref msg)) if msg.contains("DISPLAY"))
+            );
+        }
+    }
+
+    #[test]
+    fn test_run_guarded() {
+        assert!(run_guarded(|| Ok(())).is_ok());
+        assert!(matches!(
+            run_guarded(|| Err(iced::Error::WindowCreationFailed(Box::new(
+                std::io::Error::other("no display")
+            )))),
+            Err(AppError::UserInterface(_))
+        ));
+
+        let error = run_guarded(|| panic!("Failed to initialize any backend!")).unwrap_err();
+        assert!(
+            matches!(error, AppError::UserInterface(ref msg) if msg.contains("Failed to initialize any backend!")),
+            "unexpected error {error}"
+        );
+    }
 
     #[test]
     fn test_app_theme_conversion() {
@@ -1008,7 +3631,106 @@ mod tests {
         assert_eq!(StatusSelection::Applied.to_string(), "Applied");
         assert_eq!(StatusSelection::Interview.to_string(), "Interview");
         assert_eq!(StatusSelection::Offer.to_string(), "Offer");
+        assert_eq!(StatusSelection::Accepted.to_string(), "Accepted");
         assert_eq!(StatusSelection::Rejected.to_string(), "Rejected");
+        assert_eq!(StatusSelection::Withdrawn.to_string(), "Withdrawn");
+    }
+
+    #[test]
+    fn test_theme_setting_value_round_trip() {
+        for theme in [AppTheme::Light, AppTheme::Dark] {
+            assert_eq!(
+                AppTheme::from_setting_value(theme.setting_value()),
+                Some(theme)
+            );
+        }
+        assert_eq!(AppTheme::from_setting_value("solarized"), None);
+    }
+
+    #[test]
+    fn test_theme_loaded_applies_persisted_theme() {
+        let mut app = JobTrackerApp::new();
+        let _ = app.update(Message::ThemeLoaded(Ok(None)));
+        assert_eq!(app.theme, AppTheme::Light);
+
+        let _ = app.update(Message::ThemeLoaded(Ok(Some(AppTheme::Dark))));
+        assert_eq!(app.theme, AppTheme::Dark);
+    }
+
+    #[test]
+    fn test_key_to_message_shortcuts() {
+        use keyboard::key::Named;
+        use keyboard::{Key, Modifiers};
+
+        assert!(matches!(
+            key_to_message(Modifiers::CTRL, Key::Character("n"), None),
+            Some(Message::AddNewJob)
+        ));
+        assert!(matches!(
+            key_to_message(Modifiers::CTRL, Key::Character("s"), Some(7)),
+            Some(Message::SaveJob(7))
+        ));
+        assert!(matches!(
+            key_to_message(Modifiers::CTRL, Key::Character("s"), Some(0)),
+            Some(Message::SaveJob(0))
+        ));
+        assert!(matches!(
+            key_to_message(Modifiers::empty(), Key::Named(Named::Escape), Some(7)),
+            Some(Message::CancelEdit)
+        ));
+    }
+
+    #[test]
+    fn test_key_to_message_ignored() {
+        use keyboard::key::Named;
+        use keyboard::{Key, Modifiers};
+
+        // Save and cancel need an open form; adding needs none.
+        assert!(key_to_message(Modifiers::CTRL, Key::Character("s"), None).is_none());
+        assert!(key_to_message(Modifiers::empty(), Key::Named(Named::Escape), None).is_none());
+        assert!(key_to_message(Modifiers::CTRL, Key::Character("n"), Some(7)).is_none());
+        // Plain letters are typing, not shortcuts.
+        assert!(key_to_message(Modifiers::empty(), Key::Character("n"), None).is_none());
+        assert!(key_to_message(Modifiers::empty(), Key::Character("s"), Some(7)).is_none());
+    }
+
+    #[test]
+    fn test_escape_closes_palette_before_cancelling_edit() {
+        let mut app = JobTrackerApp::new();
+        let _ = app.update(Message::AddNewJob);
+        let _ = app.update(Message::OpenCommandPalette);
+        let escape = || {
+            Message::KeyPressed(
+                keyboard::Key::Named(keyboard::key::Named::Escape),
+                keyboard::Modifiers::empty(),
+            )
+        };
+
+        let _ = app.update(escape());
+        assert_eq!(app.command_palette, None);
+        assert_eq!(app.editing_job_id, Some(0));
+
+        let _ = app.update(escape());
+        assert_eq!(app.editing_job_id, None);
+    }
+
+    #[test]
+    fn test_status_dropdown_options() {
+        let labels: Vec<String> = StatusSelection::ALL
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            labels,
+            [
+                "Applied",
+                "Interview",
+                "Offer",
+                "Accepted",
+                "Rejected",
+                "Withdrawn"
+            ]
+        );
     }
 
     #[test]
@@ -1025,10 +3747,103 @@ mod tests {
             StatusSelection::from_status(&Status::Offer(50_000)),
             StatusSelection::Offer
         );
+        assert_eq!(
+            StatusSelection::from_status(&Status::Accepted),
+            StatusSelection::Accepted
+        );
         assert_eq!(
             StatusSelection::from_status(&Status::Rejected),
             StatusSelection::Rejected
         );
+        assert_eq!(
+            StatusSelection::from_status(&Status::Withdrawn),
+            StatusSelection::Withdrawn
+        );
+    }
+
+    #[test]
+    fn test_status_filter_toggle() {
+        let mut filter = StatusFilter::default();
+        assert!(filter.is_empty());
+
+        filter.toggle(StatusSelection::Interview);
+        filter.toggle(StatusSelection::Offer);
+        assert!(filter.is_active(&StatusSelection::Interview));
+        assert!(filter.is_active(&StatusSelection::Offer));
+        assert!(!filter.is_active(&StatusSelection::Applied));
+        assert_eq!(
+            filter.active(),
+            &[StatusSelection::Interview, StatusSelection::Offer]
+        );
+
+        filter.toggle(StatusSelection::Interview);
+        assert_eq!(filter.active(), &[StatusSelection::Offer]);
+    }
+
+    #[test]
+    fn test_status_filter_toggle_twice_clears() {
+        let mut filter = StatusFilter::default();
+        filter.toggle(StatusSelection::Rejected);
+        filter.toggle(StatusSelection::Rejected);
+        assert!(filter.is_empty());
+        assert_eq!(filter, StatusFilter::default());
+    }
+
+    #[test]
+    fn test_pipeline_stages_progress() {
+        assert_eq!(
+            pipeline_stages(&Status::Applied),
+            [
+                StageState::Current,
+                StageState::Pending,
+                StageState::Pending
+            ]
+        );
+        assert_eq!(
+            pipeline_stages(&Status::Interview(3)),
+            [
+                StageState::Completed,
+                StageState::Current,
+                StageState::Pending
+            ]
+        );
+        assert_eq!(
+            pipeline_stages(&Status::Offer(100_000)),
+            [
+                StageState::Completed,
+                StageState::Completed,
+                StageState::Current
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pipeline_stages_rejected_dimmed() {
+        assert_eq!(pipeline_stages(&Status::Rejected), [StageState::Dimmed; 3]);
+        assert_eq!(pipeline_stages(&Status::Withdrawn), [StageState::Dimmed; 3]);
+    }
+
+    #[test]
+    fn test_pipeline_stages_accepted_completed() {
+        assert_eq!(
+            pipeline_stages(&Status::Accepted),
+            [StageState::Completed; 3]
+        );
+    }
+
+    #[test]
+    fn test_cv_status() {
+        let path =
+            std::env::temp_dir().join(format!("job_tracker_cv_status_{}.pdf", std::process::id()));
+        std::fs::write(&path, b"resume").unwrap();
+        assert_eq!(cv_status(&path), CvStatus::Present);
+
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(cv_status(&path), CvStatus::Missing);
+        assert_eq!(
+            cv_status(Path::new("no/such/dir/resume.pdf")),
+            CvStatus::Missing
+        );
     }
 
     #[test]
@@ -1043,6 +3858,7 @@ mod tests {
         assert_eq!(form.status, StatusSelection::Applied);
         assert_eq!(form.cv_path, "");
         assert_eq!(form.interview_round, "1");
+        assert_eq!(form.interview_at, "");
         assert_eq!(form.offer_amount, "");
     }
 
@@ -1068,6 +3884,17 @@ mod tests {
         assert_eq!(form.cv_path, "path/to/cv.pdf");
     }
 
+    #[test]
+    fn test_edit_form_from_job_zero_offer() {
+        let job = JobApplication::new()
+            .company("Test Corp")
+            .status(Status::Offer(0));
+
+        let form = EditForm::from_job(&job);
+        assert_eq!(form.status, StatusSelection::Offer);
+        assert_eq!(form.offer_amount, "");
+    }
+
     #[test]
     fn test_edit_form_to_job_applied() {
         let mut form = EditForm::new();
@@ -1104,6 +3931,73 @@ mod tests {
         assert_eq!(job.status, Status::Interview(3));
     }
 
+    #[test]
+    fn test_edit_form_interview_at_round_trip() {
+        let at = time::macros::datetime!(2024-03-20 14:30 UTC);
+        let job = JobApplication::new()
+            .company("TechCorp")
+            .position("Engineer")
+            .salary(SalaryRange::new(50_000, 80_000))
+            .status(Status::Interview(1))
+            .interview_at(at);
+
+        let form = EditForm::from_job(&job);
+        assert_eq!(form.interview_at, "2024-03-20 14:30");
+        assert_eq!(form.to_job(None).unwrap().interview_at, Some(at));
+    }
+
+    #[test]
+    fn test_edit_form_interview_at_only_kept_for_interviews() {
+        let at = time::macros::datetime!(2024-03-20 14:30 UTC);
+        let job = JobApplication::new()
+            .company("TechCorp")
+            .position("Engineer")
+            .salary(SalaryRange::new(50_000, 80_000))
+            .status(Status::Interview(2))
+            .interview_at(at);
+
+        let mut form = EditForm::from_job(&job);
+        form.status = StatusSelection::Rejected;
+        assert_eq!(form.to_job(None).unwrap().interview_at, None);
+
+        form.interview_at = "tomorrow".to_string();
+        assert!(form.to_job(None).is_ok());
+    }
+
+    #[test]
+    fn test_edit_form_offer_deadline_only_kept_for_offers() {
+        let deadline = time::macros::datetime!(2024-04-01 17:00 UTC);
+        let job = JobApplication::new()
+            .company("TechCorp")
+            .position("Engineer")
+            .salary(SalaryRange::new(50_000, 80_000))
+            .status(Status::Offer(70_000))
+            .offer_deadline(deadline);
+
+        let mut form = EditForm::from_job(&job);
+        assert_eq!(form.offer_deadline, "2024-04-01 17:00");
+        assert_eq!(form.to_job(None).unwrap().offer_deadline, Some(deadline));
+
+        form.status = StatusSelection::Rejected;
+        assert_eq!(form.to_job(None).unwrap().offer_deadline, None);
+
+        form.status = StatusSelection::Offer;
+        form.offer_deadline = "next friday".to_string();
+        assert!(form.to_job(None).is_err());
+    }
+
+    #[test]
+    fn test_edit_form_to_job_invalid_interview_at() {
+        let mut form = EditForm::new();
+        form.salary_min = "50000".to_string();
+        form.salary_max = "80000".to_string();
+        form.status = StatusSelection::Interview;
+        form.interview_at = "tomorrow".to_string();
+
+        let result = form.to_job(None);
+        assert!(result.unwrap_err().contains("Invalid interview time"));
+    }
+
     #[test]
     fn test_edit_form_to_job_offer() {
         let mut form = EditForm::new();
@@ -1132,6 +4026,170 @@ mod tests {
         assert!(result.unwrap_err().contains("Invalid minimum salary"));
     }
 
+    #[test]
+    fn test_edit_form_to_job_min_salary_above_max() {
+        let mut form = EditForm::new();
+        form.company = "Test Corp".to_string();
+        form.position = "Developer".to_string();
+        form.salary_min = "90000".to_string();
+        form.salary_max = "60000".to_string();
+
+        assert_eq!(
+            form.to_job(None).unwrap_err(),
+            "Minimum cannot exceed maximum"
+        );
+    }
+
+    #[rstest]
+    #[case::empty_company("", "Developer", "Company is required")]
+    #[case::empty_position("Test Corp", " ", "Position is required")]
+    #[case::both_empty(" ", "", "Company is required; Position is required")]
+    fn test_edit_form_to_job_required_fields(
+        #[case] company: &str,
+        #[case] position: &str,
+        #[case] expected: &str,
+    ) {
+        let mut form = EditForm::new();
+        form.company = company.to_string();
+        form.position = position.to_string();
+        form.salary_min = "50000".to_string();
+        form.salary_max = "80000".to_string();
+
+        assert_eq!(form.to_job(None).unwrap_err(), expected);
+    }
+
+    #[test]
+    fn test_edit_form_keeps_salary_currency() {
+        let job = JobApplication::new()
+            .company("TechCorp")
+            .position("Engineer")
+            .salary(SalaryRange::with_currency(60_000, 75_000, "EUR"));
+        let mut form = EditForm::from_job(&job);
+        assert_eq!(form.salary_currency, "EUR");
+        assert_eq!(form.to_job(None).unwrap().salary, job.salary);
+
+        form.salary_currency = "  ".to_string();
+        assert_eq!(form.to_job(None).unwrap().salary.currency, DEFAULT_CURRENCY);
+    }
+
+    #[test]
+    fn test_edit_form_contact_and_url_fields() {
+        let job = JobApplication::new()
+            .company("TechCorp")
+            .position("Engineer")
+            .contact_name("Jane Doe")
+            .contact_email("jane@corp.com");
+        let mut form = EditForm::from_job(&job);
+        let saved = form.to_job(None).unwrap();
+        assert_eq!(saved.contact_name.as_deref(), Some("Jane Doe"));
+        assert_eq!(saved.contact_email.as_deref(), Some("jane@corp.com"));
+
+        form.contact_email = "jane at corp".to_string();
+        assert_eq!(
+            form.to_job(None).unwrap_err(),
+            "Email address must contain @"
+        );
+
+        form.contact_name = " ".to_string();
+        form.contact_email = String::new();
+        form.url = "www.corp.com".to_string();
+        assert_eq!(
+            form.to_job(None).unwrap_err(),
+            "URL must start with http:// or https://"
+        );
+
+        form.url = String::new();
+        let saved = form.to_job(None).unwrap();
+        assert_eq!(saved.contact_name, None);
+        assert_eq!(saved.contact_email, None);
+    }
+
+    #[test]
+    fn test_edit_form_follow_up() {
+        let follow_up = time::macros::date!(2024 - 04 - 15);
+        let job = JobApplication::new()
+            .company("TechCorp")
+            .position("Engineer")
+            .follow_up(follow_up);
+        let mut form = EditForm::from_job(&job);
+        assert_eq!(form.follow_up, "2024-04-15");
+        assert_eq!(form.to_job(None).unwrap().follow_up, Some(follow_up));
+
+        form.follow_up = "next week".to_string();
+        assert_eq!(
+            form.to_job(None).unwrap_err(),
+            "Invalid follow-up date. Use YYYY-MM-DD"
+        );
+
+        form.follow_up = " ".to_string();
+        assert_eq!(form.to_job(None).unwrap().follow_up, None);
+    }
+
+    #[test]
+    fn test_edit_form_to_job_equal_min_max_salary() {
+        let mut form = EditForm::new();
+        form.company = "Test Corp".to_string();
+        form.position = "Developer".to_string();
+        form.salary_min = "75000".to_string();
+        form.salary_max = "75000".to_string();
+
+        let job = form.to_job(None).unwrap();
+        assert_eq!(job.salary, SalaryRange::new(75_000, 75_000));
+    }
+
+    #[test]
+    fn test_salary_slider_min_clamped_to_max() {
+        let mut form = EditForm::new();
+        form.salary_min = "50000".to_string();
+        form.salary_max = "80000".to_string();
+
+        form.slide_salary_min(60_000);
+        assert_eq!(form.salary_min, "60000");
+
+        form.slide_salary_min(120_000);
+        assert_eq!(form.salary_min, "80000");
+        assert_eq!(form.salary_max, "80000");
+    }
+
+    #[test]
+    fn test_salary_slider_max_clamped_to_min() {
+        let mut form = EditForm::new();
+        form.salary_min = "50000".to_string();
+        form.salary_max = "80000".to_string();
+
+        form.slide_salary_max(30_000);
+        assert_eq!(form.salary_max, "50000");
+        assert_eq!(form.salary_slider_values(), (50_000, 50_000));
+    }
+
+    #[test]
+    fn test_edit_form_keeps_version_for_conflict_check() {
+        let mut job = JobApplication::new()
+            .company("TechCorp")
+            .position("Engineer");
+        job.updated_at = Some(time::macros::datetime!(2024-03-01 12:00:00.250 UTC));
+        let form = EditForm::from_job(&job);
+        assert_eq!(form.to_job(Some(1)).unwrap().updated_at, job.updated_at);
+        assert_eq!(EditForm::new().updated_at, None);
+    }
+
+    #[test]
+    fn test_edit_form_rejection_reason_only_for_rejected() {
+        let mut form = EditForm::new();
+        form.company = "TechCorp".to_string();
+        form.position = "Engineer".to_string();
+        form.salary_min = "1".to_string();
+        form.salary_max = "2".to_string();
+        form.rejection_reason = " Position filled ".to_string();
+        assert_eq!(form.to_job(None).unwrap().rejection_reason, None);
+
+        form.status = StatusSelection::Rejected;
+        assert_eq!(
+            form.to_job(None).unwrap().rejection_reason.as_deref(),
+            Some("Position filled")
+        );
+    }
+
     #[test]
     fn test_edit_form_to_job_invalid_date() {
         let mut form = EditForm::new();
@@ -1176,6 +4234,451 @@ mod tests {
         assert!(result.unwrap_err().contains("Invalid offer amount"));
     }
 
+    #[test]
+    fn test_draft_round_trip() {
+        let mut form = EditForm::new();
+        form.company = "Tech = Corp".to_string();
+        form.position = " Senior Engineer ".to_string();
+        form.date = "2024-05-01".to_string();
+        form.salary_min = "90000".to_string();
+        form.status = StatusSelection::Interview;
+        form.interview_round = "2".to_string();
+        form.interview_at = "2024-05-10 14:30".to_string();
+        form.notes = "Recruiter: Jane\nAsk about C:\\remote\\n policy".to_string();
+        form.pinned = true;
+        form.priority = Priority::High;
+        let draft = Draft { job_id: 7, form };
+
+        let restored = Draft::parse(&draft.to_string()).unwrap();
+        assert_eq!(restored.job_id, 7);
+        assert_eq!(restored.form.company, "Tech = Corp");
+        assert_eq!(restored.form.position, " Senior Engineer ");
+        assert_eq!(restored.form.date, "2024-05-01");
+        assert_eq!(restored.form.salary_min, "90000");
+        assert_eq!(restored.form.salary_max, "");
+        assert_eq!(restored.form.status, StatusSelection::Interview);
+        assert_eq!(restored.form.interview_round, "2");
+        assert_eq!(restored.form.interview_at, "2024-05-10 14:30");
+        assert_eq!(
+            restored.form.notes,
+            "Recruiter: Jane\nAsk about C:\\remote\\n policy"
+        );
+        assert!(restored.form.pinned);
+        assert_eq!(restored.form.priority, Priority::High);
+    }
+
+    #[test]
+    fn test_draft_parse_errors() {
+        assert!(matches!(
+            Draft::parse("status=Pending"),
+            Err(AppError::Configuration(_))
+        ));
+        assert!(matches!(
+            Draft::parse("job_id=abc"),
+            Err(AppError::Configuration(_))
+        ));
+        assert!(matches!(
+            Draft::parse("no separator"),
+            Err(AppError::Configuration(_))
+        ));
+    }
+
+    #[test]
+    fn test_form_edit_marks_draft_dirty() {
+        let mut app = JobTrackerApp::new();
+        let _ = app.update(Message::AddNewJob);
+        assert!(!app.draft_dirty);
+
+        let _ = app.update(Message::CompanyChanged("TechCorp".to_string()));
+        assert!(app.draft_dirty);
+    }
+
+    #[test]
+    fn test_toast_queue_stacks_and_expires() {
+        let start = Instant::now();
+        let mut queue = ToastQueue::default();
+        let first = queue.push("first", start);
+        let second = queue.push("second", start + Duration::from_secs(2));
+        assert_ne!(first, second);
+        assert_eq!(queue.toasts().len(), 2);
+
+        queue.expire(start + TOAST_DURATION.saturating_sub(Duration::from_millis(1)));
+        assert_eq!(queue.toasts().len(), 2);
+
+        queue.expire(start + TOAST_DURATION);
+        assert_eq!(queue.toasts().len(), 1);
+        assert_eq!(queue.toasts()[0].message, "second");
+
+        queue.expire(start + TOAST_DURATION + Duration::from_secs(2));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_undo_stack_is_lifo() {
+        let mut stack = UndoStack::default();
+        assert!(stack.is_empty());
+        assert!(stack.pop().is_none());
+
+        stack.push(JobApplication::new().company("First"));
+        stack.push(JobApplication::new().company("Second"));
+        assert!(!stack.is_empty());
+        assert_eq!(stack.pop().unwrap().company, "Second");
+        assert_eq!(stack.pop().unwrap().company, "First");
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn test_undo_stack_forgets_oldest_when_full() {
+        let mut stack = UndoStack::default();
+        for n in 0..UNDO_CAPACITY + 2 {
+            stack.push(JobApplication::new().company(&format!("Job {n}")));
+        }
+
+        let mut remaining = Vec::new();
+        while let Some(job) = stack.pop() {
+            remaining.push(job.company);
+        }
+        assert_eq!(remaining.len(), UNDO_CAPACITY);
+        assert_eq!(
+            remaining.first().unwrap(),
+            &format!("Job {}", UNDO_CAPACITY + 1)
+        );
+        assert_eq!(remaining.last().unwrap(), "Job 2");
+    }
+
+    #[test]
+    fn test_toast_queue_dismiss() {
+        let now = Instant::now();
+        let mut queue = ToastQueue::default();
+        let first = queue.push("first", now);
+        queue.push("second", now);
+
+        queue.dismiss(first);
+        assert_eq!(queue.toasts().len(), 1);
+        assert_eq!(queue.toasts()[0].message, "second");
+    }
+
+    #[test]
+    fn test_parse_quick_add_full_line() {
+        let job = parse_quick_add(
+            "TechCorp | Engineer | Remote | 80k-120k",
+            NumberLocale::EnUs,
+        )
+        .unwrap();
+        assert_eq!(job.company, "TechCorp");
+        assert_eq!(job.position, "Engineer");
+        assert_eq!(job.location, "Remote");
+        assert_eq!(job.salary, SalaryRange::new(80_000, 120_000));
+        assert_eq!(job.status, Status::Applied);
+        assert!(job.id.is_none());
+    }
+
+    #[test]
+    fn test_parse_quick_add_defaults_and_single_salary() {
+        let job = parse_quick_add("  Acme  ", NumberLocale::EnUs).unwrap();
+        assert_eq!(job.company, "Acme");
+        assert_eq!(job.position, "");
+        assert_eq!(job.salary, SalaryRange::default());
+
+        let job = parse_quick_add("Acme | Dev | | 95000", NumberLocale::EnUs).unwrap();
+        assert_eq!(job.location, "");
+        assert_eq!(job.salary, SalaryRange::new(95_000, 95_000));
+    }
+
+    #[test]
+    fn test_fuzzy_score_matching() {
+        assert_eq!(fuzzy_score("", "Add job"), Some(0));
+        assert!(fuzzy_score("ADD JOB", "Add job").is_some());
+        assert!(fuzzy_score("aj", "Add job").is_some());
+        assert!(fuzzy_score("ja", "Add job").is_none());
+        assert!(fuzzy_score("add jobs", "Add job").is_none());
+    }
+
+    #[test]
+    fn test_fuzzy_score_ranking() {
+        // Word starts beat letters in the middle of a word.
+        assert!(fuzzy_score("tt", "Toggle theme") > fuzzy_score("tt", "Settle"));
+        // Consecutive runs beat scattered letters.
+        assert!(fuzzy_score("the", "Toggle theme") > fuzzy_score("tge", "Toggle theme"));
+        // Earlier matches beat later ones.
+        assert!(fuzzy_score("c", "Clear database") > fuzzy_score("c", "Discard draft"));
+    }
+
+    #[test]
+    fn test_rank_commands() {
+        let labels = |query: &str| {
+            rank_commands(query)
+                .into_iter()
+                .map(|index| COMMANDS[index].0)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(labels("").len(), COMMANDS.len());
+        assert_eq!(labels("theme")[0], "Toggle theme");
+        assert_eq!(labels("add")[0], "Add job");
+        assert_eq!(labels("csv")[0], "Export CSV");
+        // Matching both word starts beats two adjacent letters.
+        assert_eq!(labels("dd"), vec!["Discard draft", "Add job"]);
+        assert!(labels("zzz").is_empty());
+    }
+
+    #[test]
+    fn test_parse_quick_add_uses_locale_separators() {
+        let job = parse_quick_add("Acme | Dev | | 80.000-120.000", NumberLocale::DeDe).unwrap();
+        assert_eq!(job.salary, SalaryRange::new(80_000, 120_000));
+        let job = parse_quick_add("Acme | Dev | | 80,000-120k", NumberLocale::EnUs).unwrap();
+        assert_eq!(job.salary, SalaryRange::new(80_000, 120_000));
+        assert!(parse_quick_add("Acme | Dev | | 80.000", NumberLocale::EnUs).is_err());
+    }
+
+    #[test]
+    fn test_edit_form_parses_locale_amounts() {
+        let mut form = EditForm::new().with_locale(NumberLocale::DeDe);
+        form.company = "Locale Co".to_string();
+        form.position = "Engineer".to_string();
+        form.salary_min = "80.000".to_string();
+        form.salary_max = "120.000".to_string();
+        form.status = StatusSelection::Offer;
+        form.offer_amount = "95.500".to_string();
+        let job = form.to_job(None).unwrap();
+        assert_eq!(job.salary, SalaryRange::new(80_000, 120_000));
+        assert_eq!(job.status, Status::Offer(95_500));
+
+        form.locale = NumberLocale::EnUs;
+        assert!(form.to_job(None).is_err());
+    }
+
+    #[test]
+    fn test_format_salary() {
+        let salary = SalaryRange::new(80_000, 1_200_000);
+        assert_eq!(
+            format_salary(&salary, NumberLocale::EnUs),
+            "80,000 - 1,200,000 USD"
+        );
+        assert_eq!(
+            format_salary(&salary, NumberLocale::DeDe),
+            "80.000 - 1.200.000 USD"
+        );
+        assert_eq!(
+            format_salary(&SalaryRange::new(100_000, 100_000), NumberLocale::EnUs),
+            "100,000 USD"
+        );
+        assert_eq!(
+            format_salary(
+                &SalaryRange::with_currency(60_000, 75_000, "EUR"),
+                NumberLocale::DeDe
+            ),
+            "60.000 - 75.000 EUR"
+        );
+    }
+
+    #[test]
+    fn test_parse_quick_add_malformed() {
+        assert!(parse_quick_add("", NumberLocale::EnUs).is_err());
+        assert!(parse_quick_add(" | Engineer", NumberLocale::EnUs).is_err());
+        assert!(parse_quick_add("A | B | C | 1k | extra", NumberLocale::EnUs).is_err());
+        assert!(parse_quick_add("A | B | C | lots", NumberLocale::EnUs).is_err());
+        assert!(parse_quick_add("A | B | C | 120k-80k", NumberLocale::EnUs).is_err());
+        assert!(parse_quick_add("A | B | C | 80k-", NumberLocale::EnUs).is_err());
+    }
+
+    #[test]
+    fn test_dismiss_reminder_removes_it() {
+        let mut app = JobTrackerApp::new();
+        let reminder = |id| Reminder {
+            id: Some(id),
+            job_id: 1,
+            remind_at: time::OffsetDateTime::UNIX_EPOCH,
+            message: format!("Reminder {id}"),
+        };
+        let _ = app.update(Message::RemindersLoaded(Ok(vec![reminder(1), reminder(2)])));
+        assert_eq!(app.due_reminders.len(), 2);
+
+        let _ = app.update(Message::DismissReminder(1));
+        assert_eq!(app.due_reminders, vec![reminder(2)]);
+    }
+
+    #[tokio::test]
+    async fn test_clear_database_prompt_counts_archived_jobs() {
+        let db = Database::new("sqlite::memory:").await.unwrap();
+        for company in ["A", "B", "C", "D", "E", "F"] {
+            let id = db
+                .insert_job(&JobApplication::new().company(company))
+                .await
+                .unwrap();
+            if company > "C" {
+                db.archive_job(id).await.unwrap();
+            }
+        }
+
+        let mut app = JobTrackerApp::new();
+        let _ = app.update(Message::ClearDatabaseCounted(
+            count_rows_to_clear(&db).await,
+        ));
+        assert_eq!(app.pending_bulk_delete, Some(6));
+    }
+
+    #[test]
+    fn test_checklist_results_for_other_job_ignored() {
+        let mut app = JobTrackerApp::new();
+        let item = ChecklistItem {
+            id: Some(1),
+            job_id: 5,
+            text: "Tailor resume".to_string(),
+            done: false,
+        };
+        let _ = app.update(Message::SelectJob(Some(5)));
+        let _ = app.update(Message::ChecklistLoaded(4, Ok(vec![item.clone()])));
+        assert!(app.checklist.is_empty());
+
+        let _ = app.update(Message::ChecklistLoaded(5, Ok(vec![item.clone()])));
+        assert_eq!(app.checklist, vec![item]);
+
+        let _ = app.update(Message::ToggleChecklistItem(1, true));
+        assert!(app.checklist[0].done);
+    }
+
+    #[test]
+    fn test_init_error_maps_to_state() {
+        let result = Err(crate::error::AppError::Database(
+            crate::db::DbError::NotFound(1),
+        ));
+        let message = JobTrackerApp::init_result_to_message(result);
+        assert!(matches!(&message, Message::DatabaseInitFailed(e) if e.contains("Database error")));
+
+        let mut app = JobTrackerApp::new();
+        let _ = app.update(message);
+        assert!(app.database.is_none());
+        assert!(
+            app.init_error
+                .as_deref()
+                .unwrap()
+                .contains("Database error")
+        );
+
+        let _ = app.update(Message::RetryDatabaseInit);
+        assert_eq!(app.init_error, None);
+    }
+
+    #[test]
+    fn test_duplicate_company_warning() {
+        let existing = vec![
+            JobApplication::new().company("Acme"),
+            JobApplication::new().company("acme "),
+            JobApplication::new().company("Beta"),
+        ];
+        assert_eq!(
+            duplicate_company_warning(&existing, "Acme").as_deref(),
+            Some("You already track 2 applications at Acme")
+        );
+        assert_eq!(
+            duplicate_company_warning(&existing, " Beta").as_deref(),
+            Some("You already track 1 application at Beta")
+        );
+        assert_eq!(duplicate_company_warning(&existing, "Acme Labs"), None);
+        assert_eq!(duplicate_company_warning(&[], "Acme"), None);
+    }
+
+    fn companies(jobs: &[JobApplication]) -> Vec<&str> {
+        jobs.iter().map(|job| job.company.as_str()).collect()
+    }
+
+    #[test]
+    fn test_sort_jobs_by_company_ascending() {
+        let mut jobs = vec![
+            JobApplication::new().company("gamma"),
+            JobApplication::new().company("Alpha"),
+            JobApplication::new().company("beta"),
+        ];
+        sort_jobs(&mut jobs, SortBy::Company, SortOrder::Asc);
+        assert_eq!(companies(&jobs), ["Alpha", "beta", "gamma"]);
+    }
+
+    #[test]
+    fn test_sort_jobs_by_salary_descending() {
+        let mut jobs = vec![
+            JobApplication::new()
+                .company("Low")
+                .salary(SalaryRange::new(0, 50_000)),
+            JobApplication::new()
+                .company("High")
+                .salary(SalaryRange::new(0, 150_000)),
+            JobApplication::new()
+                .company("Mid")
+                .salary(SalaryRange::new(0, 90_000)),
+            JobApplication::new()
+                .company("Pinned")
+                .salary(SalaryRange::new(0, 10_000))
+                .pinned(true),
+        ];
+        sort_jobs(&mut jobs, SortBy::SalaryMax, SortOrder::Desc);
+        assert_eq!(companies(&jobs), ["Pinned", "High", "Mid", "Low"]);
+    }
+
+    #[test]
+    fn test_sort_column_toggles_order() {
+        let mut app = JobTrackerApp::new();
+        let _ = app.update(Message::JobsLoaded(Ok(vec![
+            JobApplication::new().company("Beta"),
+            JobApplication::new().company("Alpha"),
+        ])));
+
+        let _ = app.update(Message::SortColumn(SortBy::Company));
+        assert_eq!(app.sort_order, SortOrder::Asc);
+        assert_eq!(companies(&app.jobs), ["Alpha", "Beta"]);
+
+        let _ = app.update(Message::SortColumn(SortBy::Company));
+        assert_eq!(app.sort_order, SortOrder::Desc);
+        assert_eq!(companies(&app.jobs), ["Beta", "Alpha"]);
+
+        let _ = app.update(Message::SortColumn(SortBy::Date));
+        assert_eq!(
+            (app.sort_by, app.sort_order),
+            (SortBy::Date, SortOrder::Asc)
+        );
+    }
+
+    #[test]
+    fn test_matches_query() {
+        let job = JobApplication::new()
+            .company("TechCorp")
+            .position("Backend Engineer")
+            .location("Remote");
+
+        assert!(matches_query(&job, ""));
+        assert!(matches_query(&job, "  "));
+        assert!(matches_query(&job, "techCORP"));
+        assert!(matches_query(&job, "engineer"));
+        assert!(matches_query(&job, " remo "));
+        assert!(!matches_query(&job, "DataCorp"));
+    }
+
+    #[test]
+    fn test_job_saved_sets_warning() {
+        let mut app = JobTrackerApp::new();
+        let job = JobApplication::new().company("Acme");
+        let warning = duplicate_company_warning(std::slice::from_ref(&job), "Acme");
+        let _ = app.update(Message::JobSaved(warning.clone(), Ok(vec![job])));
+        assert_eq!(app.warning_message, warning);
+        assert_eq!(app.jobs.len(), 1);
+
+        let _ = app.update(Message::DismissWarning);
+        assert_eq!(app.warning_message, None);
+    }
+
+    #[test]
+    fn test_save_without_database_reports_error() {
+        let mut app = JobTrackerApp::new();
+        let _ = app.update(Message::AddNewJob);
+        let _ = app.update(Message::CompanyChanged("TechCorp".to_string()));
+        let _ = app.update(Message::PositionChanged("Engineer".to_string()));
+        let _ = app.update(Message::SalaryMinChanged("1".to_string()));
+        let _ = app.update(Message::SalaryMaxChanged("2".to_string()));
+        let _ = app.update(Message::SaveJob(0));
+        assert_eq!(app.toasts.toasts().len(), 1);
+        assert!(app.toasts.toasts()[0].message.contains("not available"));
+    }
+
     #[test]
     fn test_app_creation() {
         let app = JobTrackerApp::new();
@@ -1184,6 +4687,8 @@ mod tests {
         assert_eq!(app.selected_job_id, None);
         assert_eq!(app.editing_job_id, None);
         assert_eq!(app.theme, AppTheme::Light);
-        assert_eq!(app.error_message, None);
+        assert!(app.toasts.is_empty());
+        assert!(app.status_filter.is_empty());
+        assert_eq!(app.init_error, None);
     }
 }
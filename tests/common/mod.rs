@@ -125,15 +125,16 @@ pub fn multiple_jobs() -> Vec<JobApplication> {
     ]
 }
 
-/// Helper function to assert job equality (ignoring ID)
+/// Helper function to assert job equality (ignoring ID and timestamps)
 pub fn assert_job_equals_ignoring_id(actual: &JobApplication, expected: &JobApplication) {
-    assert_eq!(actual.company, expected.company);
-    assert_eq!(actual.position, expected.position);
-    assert_eq!(actual.location, expected.location);
-    assert_eq!(actual.salary, expected.salary);
-    assert_eq!(actual.status, expected.status);
-    assert_eq!(actual.date, expected.date);
-    assert_eq!(actual.cv, expected.cv);
+    // The id and timestamps are assigned by the database.
+    let clear = |job: &JobApplication| JobApplication {
+        id: None,
+        updated_at: None,
+        created_at: None,
+        ..job.clone()
+    };
+    assert_eq!(clear(actual), clear(expected));
 }
 
 /// Helper function to verify a job exists in a list
@@ -174,12 +175,7 @@ pub fn assert_db_file_exists(test_dir: &str, file_path: &str) {
 
 /// Async helper to insert multiple jobs and return their IDs
 pub async fn insert_multiple_jobs(db: &Database, jobs: &[JobApplication]) -> Vec<i64> {
-    let mut ids = Vec::new();
-    for job in jobs {
-        let id = db.insert_job(job).await.unwrap();
-        ids.push(id);
-    }
-    ids
+    db.insert_jobs(jobs).await.unwrap()
 }
 
 /// Helper to verify all status types persist correctly
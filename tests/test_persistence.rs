@@ -50,6 +50,41 @@ mod persistence_tests {
         test_db.cleanup().await;
     }
 
+    #[tokio::test]
+    async fn test_notes_persistence_across_connections() {
+        let test_db = TestDb::new("persistence_notes").await;
+
+        let with_notes = create_sample_job("Notes Corp", "Engineer")
+            .notes("Recruiter: Jane, next step is a take-home");
+        let without_notes = create_sample_job("Quiet Corp", "Engineer");
+        let noted_id = test_db.db.insert_job(&with_notes).await.unwrap();
+        let quiet_id = test_db.db.insert_job(&without_notes).await.unwrap();
+
+        let db2 = test_db.reconnect().await;
+        assert_eq!(
+            db2.get_job_by_id(noted_id).await.unwrap().notes.as_deref(),
+            Some("Recruiter: Jane, next step is a take-home")
+        );
+        assert_eq!(db2.get_job_by_id(quiet_id).await.unwrap().notes, None);
+
+        test_db.cleanup().await;
+    }
+
+    #[tokio::test]
+    async fn test_withdrawn_status_persistence() {
+        let test_db = TestDb::new("persistence_withdrawn").await;
+
+        let job = create_sample_job("Withdrawn Corp", "Engineer").status(Status::Withdrawn);
+        let job_id = test_db.db.insert_job(&job).await.unwrap();
+
+        let db2 = test_db.reconnect().await;
+        let retrieved = db2.get_job_by_id(job_id).await.unwrap();
+        assert_eq!(retrieved.status, Status::Withdrawn);
+        assert_job_equals_ignoring_id(&retrieved, &job);
+
+        test_db.cleanup().await;
+    }
+
     #[tokio::test]
     async fn test_multiple_jobs_persistence() {
         let test_db = TestDb::new("persistence_multiple").await;